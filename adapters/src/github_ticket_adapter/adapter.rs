@@ -1,11 +1,18 @@
-use std::{sync::{Arc, Mutex}, time::{Instant, Duration, SystemTime}, collections::BTreeMap, thread};
+use std::{sync::{Arc, Mutex}, time::{Instant, Duration, SystemTime}, collections::BTreeMap};
 
 use octocrab::{Octocrab, models};
-use tickets_rs_core::{TicketAdapter, TicketProvider, AppConfig, Config, AdapterError, AdapterErrorType, Filter, Ticket};
+use tickets_rs_core::{TicketAdapter, TicketProvider, AppConfig, Config, AdapterError, AdapterErrorType, AdapterCapabilities, AdapterStatus, AdapterFuture, EventBus, Filter, Ticket, TicketPage, TicketPageRequest, Comment, SearchHit, block_on};
 use tokio::runtime::Handle;
 
 use crate::GithubTicketAdapter;
 
+use super::REFRESH_INTERVAL;
+
+/**
+   The largest page size, the Github API allows
+ */
+const GITHUB_PAGE_SIZE: u64 = 100;
+
 
 impl TicketAdapter for GithubTicketAdapter {
 
@@ -27,9 +34,7 @@ impl TicketAdapter for GithubTicketAdapter {
             .with("repo_owner", "", "string")
     }
 
-    fn from_config(app_config: Arc<Mutex<AppConfig>>, config: &Config, finished: Arc<Mutex<bool>>) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized {
-        
-        let mut auth_token = "".to_string();
+    fn from_config(_app_config: Arc<Mutex<AppConfig>>, config: &Config, events: EventBus) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized {
 
         let octocrab = match config.get("personal_auth_token") {
             Some(config_option) => {
@@ -37,15 +42,11 @@ impl TicketAdapter for GithubTicketAdapter {
                 match config_option.get::<String>() {
                     Some(config_string) => {
                         if !config_string.is_empty() {
-                            auth_token = config_string.clone();
                             match Octocrab::builder().personal_token(config_string).build() {
                                 Ok(instance) => {
                                     Arc::new(instance)
                                 },
-                                Err(err) => {
-                                    println!("{}", err);
-                                    return Err(AdapterError { error_type: AdapterErrorType::Instantiation })
-                                },
+                                Err(err) => return Err(AdapterError::new(AdapterErrorType::Instantiation).with_source(err)),
                             }
                         } else {
                             octocrab::instance()
@@ -91,22 +92,22 @@ impl TicketAdapter for GithubTicketAdapter {
             None => Instant::now(),
         };
 
-        let mut adapter = GithubTicketAdapter{
+        let adapter = GithubTicketAdapter{
             name,
             display_name,
-            config: app_config,
             cached_tickets: Default::default(),
             cached_buckets: Default::default(),
             cached_tags: Default::default(),
             cached_states: Default::default(),
             octocrab,
-            auth_token,
-            last_refresh: instant,
+            last_refresh: Mutex::new(instant),
+            status: Default::default(),
             owner,
-            update_trigger: Arc::new(Mutex::new(false))
+            events,
+            runtime: Handle::current()
         };
 
-        adapter.full_refresh_data(finished);
+        adapter.full_refresh_data();
 
         Ok(Box::new(adapter))
 
@@ -120,11 +121,42 @@ impl TicketAdapter for GithubTicketAdapter {
         self.display_name.clone()
     }
 
-    fn is_read_only(&self) -> bool {
-        true //TODO!
+    /**
+       The adapter only reads from Github so far, every write or deletion
+       fails. Declaring it read only makes the app hide those actions,
+       instead of offering them and failing afterwards
+     */
+    fn get_capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities::read_only()
+    }
+
+    fn get_status(&self) -> AdapterStatus {
+        match self.status.lock() {
+            Ok(lock) => lock.clone().with_max_age(REFRESH_INTERVAL.as_secs() as i64),
+            Err(err) => {
+                let mut status = AdapterStatus::default();
+                status.mark_failed(AdapterError::new(AdapterErrorType::Access).with_reason(err));
+                status
+            },
+        }
+    }
+
+    fn retry(&self) -> Result<(), AdapterError> {
+        match self.last_refresh.lock() {
+            Ok(mut lock) => *lock = Instant::now().checked_sub(REFRESH_INTERVAL).unwrap_or_else(Instant::now),
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err)),
+        }
+
+        self.full_refresh_data();
+        Ok(())
     }
 
     fn bucket_list_all(&self) -> Vec<tickets_rs_core::Bucket> {
+
+        // Once the cached data is older than the refresh interval, it's loaded
+        // again in the background. Until then, the cached buckets are returned
+        self.full_refresh_data();
+
         if let Ok(lock) = self.cached_buckets.lock() {
             return lock.values().cloned().collect();
         };
@@ -171,165 +203,359 @@ impl TicketAdapter for GithubTicketAdapter {
     }
 
     fn ticket_list_unique(&self, id: i64) -> Option<tickets_rs_core::Ticket> {
-        
-        let mut local_ticket_opt = None;
-
-        // Check if the ticket even exists locally
-        if let Ok(tickets_lock) = self.cached_tickets.lock() {
-            local_ticket_opt = tickets_lock.get(&(id as u64)).cloned()
-        }
+        block_on(self.ticket_list_unique_async(id))
+    }
 
-        // If there was some Ticket found, get the bucket name as repo name and query the ticket
-        if let Some(local_ticket) = local_ticket_opt {
+    fn ticket_list_unique_async(&self, id: i64) -> AdapterFuture<'_, Option<tickets_rs_core::Ticket>> {
+        Box::pin(async move {
 
-            if let Some(local_bucket) = self.bucket_list_unique(local_ticket.bucket_id) {
+            let mut local_ticket_opt = None;
 
-                let thread_octocrab = self.octocrab.clone();
-                let thread_owner = self.owner.clone();
-                let thread_id = {
-                    let add_id_str = local_ticket.additional_id.clone();
-                    //let add_id: &str = &add_id_str;
-                    let add_id_opt = add_id_str.split_once("::");
-                    let num = add_id_opt.unwrap().0.to_string();
-                    num
-                };
-                let thread_repo = local_bucket.name.clone();
-                let thread_repo_id = local_bucket.identifier.clone();
-                let thread_ticket_proto = Ticket::default().with_adapter(self);
-                let handle = Handle::current();
-                let thread_result = thread::spawn(move || {
-
-                    if let Ok(issue) = handle.block_on(thread_octocrab.issues(thread_owner, thread_repo.clone()).get(u64::from_str_radix(&thread_id, 10).unwrap())) {
-                        Self::map_issues_to_tickets(vec![issue], thread_ticket_proto, thread_repo_id.id, &thread_repo).pop_first().map(|elem| elem.1)
-                    } else {
-                        println!("wasnt able to get issue by id {}", thread_id);
-                        None
-                    }
+            // Check if the ticket even exists locally
+            if let Ok(tickets_lock) = self.cached_tickets.lock() {
+                local_ticket_opt = tickets_lock.get(id as u64).cloned()
+            }
 
-                }).join();
+            // If there was some Ticket found, get the bucket name as repo name and query the ticket
+            let Some(local_ticket) = local_ticket_opt else {
+                println!("didnt find locally stored ticket");
+                return None;
+            };
 
-                if let Ok(thread_inner_data) = thread_result {
-                    if let Some(ticket) = &thread_inner_data {
+            let Some(local_bucket) = self.bucket_list_unique(local_ticket.bucket_id) else {
+                println!("didnt find bucket");
+                return None;
+            };
 
-                        if let Ok(mut tickets_lock) = self.cached_tickets.lock() {
-                            tickets_lock.insert(ticket.id as u64, ticket.clone());
-                        };
+            let thread_octocrab = self.octocrab.clone();
+            let thread_owner = self.owner.clone();
+            // Issue number and repo name are stored as "number::repo" in the additional id
+            let Some(thread_id) = local_ticket.additional_id.split_once("::").and_then(|(number, _)| number.parse::<u64>().ok()) else {
+                println!("didnt find issue number of ticket {}", id);
+                return None;
+            };
+            let thread_repo = local_bucket.name.clone();
+            let thread_repo_id = local_bucket.identifier.clone();
+            let thread_ticket_proto = Ticket::default().with_adapter(self);
 
-                    };
+            // The request runs on the tokio runtime, so the awaiting thread doesn't need one
+            let request = self.runtime.spawn(async move {
 
-                    thread_inner_data
+                if let Ok(issue) = thread_octocrab.issues(thread_owner, thread_repo.clone()).get(thread_id).await {
+                    Self::map_issues_to_tickets(vec![issue], thread_ticket_proto, thread_repo_id.id, &thread_repo).pop_first().map(|elem| elem.1)
                 } else {
-                    println!("thread didnt exit correctly");
+                    println!("wasnt able to get issue by id {}", thread_id);
                     None
                 }
+
+            });
+
+            if let Ok(request_data) = request.await {
+                if let Some(ticket) = &request_data {
+
+                    let evicted_buckets = match self.cached_tickets.lock() {
+                        Ok(mut tickets_lock) => tickets_lock.insert([(ticket.id as u64, ticket.clone())]),
+                        Err(_) => Default::default(),
+                    };
+
+                    Self::invalidate_buckets(&self.cached_buckets, evicted_buckets);
+
+                };
+
+                request_data
             } else {
-                println!("didnt find bucket");
+                println!("request didnt exit correctly");
                 None
             }
-        } else {
-            println!("didnt find locally stored ticket");
-            None
-        }
-
+        })
     }
 
     fn ticket_list(&self, expression: &str) -> Result<Vec<tickets_rs_core::Ticket>, tickets_rs_core::AdapterError> {
+        block_on(self.ticket_list_async(expression))
+    }
 
-        let split_expression: Vec<&str> = expression.split(" ||| ").collect();
-        let repo = split_expression.get(0).unwrap().to_string();
-        let id = u64::from_str_radix(split_expression.get(1).unwrap(), 10).unwrap();
+    fn ticket_list_async<'a>(&'a self, expression: &'a str) -> AdapterFuture<'a, Result<Vec<tickets_rs_core::Ticket>, tickets_rs_core::AdapterError>> {
+        Box::pin(async move {
 
-        let mut loaded = true;
+            let (repo, id) = Self::bucket_from_filter_expr(expression)?;
 
-        if let Ok(mut lock) = self.cached_buckets.lock() {
-            if let Some(result) = lock.get_mut(&id) {
-                match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-                    Ok(duration) => {
-                        let last_change_ts = result.last_change as u64;
-                        let diff = duration.as_secs() - last_change_ts;
+            let mut loaded = true;
 
-                        if diff > 5 * 60 {
-                            loaded = false;
-                            result.last_change = duration.as_secs() as i64;
-                        }
-                    },
-                    Err(_err) => (),
-                };
+            if let Ok(mut lock) = self.cached_buckets.lock() {
+                if let Some(result) = lock.get_mut(&id) {
+                    match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+                        Ok(duration) => {
+                            let last_change_ts = result.last_change as u64;
+                            let diff = duration.as_secs() - last_change_ts;
+
+                            if diff > 5 * 60 {
+                                loaded = false;
+                                result.last_change = duration.as_secs() as i64;
+                            }
+                        },
+                        Err(_err) => (),
+                    };
+                }
             }
-        }
 
-        if !loaded {
+            if !loaded {
+
+                let thread_octocrab = self.octocrab.clone();
+                let thread_owner = self.owner.clone();
+                let thread_ticket_proto = Ticket::default().with_adapter(self);
+                let thread_cached_tickets = self.cached_tickets.clone();
+                let thread_cached_buckets = self.cached_buckets.clone();
 
-            let handle = Handle::current();
+                // The request runs on the tokio runtime, so the awaiting thread doesn't need one
+                let request = self.runtime.spawn(async move {
+
+                    let found_page = thread_octocrab.issues(thread_owner.clone(), repo.clone()).list().state(octocrab::params::State::All).per_page(100).send().await
+                        .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_entity(format!("Repository \"{}\"", repo)).with_source(err))?;
+
+                    let issues = thread_octocrab.all_pages::<models::issues::Issue>(found_page).await
+                        .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_entity(format!("Repository \"{}\"", repo)).with_source(err))?;
+
+                    let tickets: BTreeMap<u64, Ticket> = Self::map_issues_to_tickets(issues, thread_ticket_proto, id, &repo);
+
+                    let evicted_buckets = match thread_cached_tickets.lock() {
+                        Ok(mut lock) => {
+                            lock.remove_bucket(id);
+                            lock.insert(tickets.clone())
+                        },
+                        Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
+                    };
+
+                    Self::invalidate_buckets(&thread_cached_buckets, evicted_buckets);
+                    Ok(tickets.into_values().collect())
+
+                });
+
+                match request.await {
+                    Ok(tickets_result) => tickets_result,
+                    Err(err) => Err(AdapterError::new(AdapterErrorType::Access).with_source(err)),
+                }
+
+
+            } else {
+                if let Ok(lock) = self.cached_tickets.lock() {
+                    return Ok(lock.bucket_tickets(id))
+                }
+                Err(tickets_rs_core::AdapterError::new(AdapterErrorType::Access))
+            }
+        })
+    }
+
+    fn ticket_list_page(&self, expression: &str, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        block_on(self.ticket_list_page_async(expression, page))
+    }
+
+    fn ticket_list_page_async<'a>(&'a self, expression: &'a str, page: TicketPageRequest) -> AdapterFuture<'a, Result<TicketPage, AdapterError>> {
+        Box::pin(async move {
+
+            let (repo, id) = Self::bucket_from_filter_expr(expression)?;
 
             let thread_octocrab = self.octocrab.clone();
             let thread_owner = self.owner.clone();
             let thread_ticket_proto = Ticket::default().with_adapter(self);
             let thread_cached_tickets = self.cached_tickets.clone();
-            let final_result = thread::spawn(move || {
+            let thread_cached_buckets = self.cached_buckets.clone();
 
-                let result_page = handle.block_on(thread_octocrab.issues(thread_owner.clone(), repo.clone()).list().state(octocrab::params::State::All).per_page(100).send());
-                match result_page {
-                    Ok(found_page) => {
-                        let issues_result = handle.block_on(thread_octocrab.all_pages::<models::issues::Issue>(found_page));
-
-                        if let Ok(issues) = issues_result {
-                            let tickets: BTreeMap<u64, Ticket> = Self::map_issues_to_tickets(issues, thread_ticket_proto, id, &repo);
-
-                            if let Ok(mut lock) = thread_cached_tickets.lock() {
-                                lock.retain(|key, ticket| id.ne(&ticket.bucket_id) );
-                                lock.append(&mut tickets.clone());
-                                let local_tickets: Vec<tickets_rs_core::Ticket> = tickets.into_iter().map(|ticket| ticket.1).collect();
-                                return Some(local_tickets)
-                            } else {
-                                return None
-                            }
+            // The request runs on the tokio runtime, so the awaiting thread doesn't need one
+            let request = self.runtime.spawn(async move {
 
-                        }
-                    },
-                    Err(_) => (),
+                let access_error = |err: octocrab::Error| AdapterError::new(AdapterErrorType::Access).with_entity(format!("Repository \"{}\"", repo)).with_source(err);
+
+                // With a single issue per page, the number of pages is the number of issues
+                let count_page = thread_octocrab.issues(thread_owner.clone(), repo.clone()).list().state(octocrab::params::State::All)
+                    .per_page(1).send().await
+                    .map_err(access_error)?;
+                let total = count_page.number_of_pages().map(u64::from).unwrap_or(count_page.items.len() as u64);
+
+                // Github pages have a fixed size, so every page overlapping the requested one is loaded
+                let mut issues: Vec<models::issues::Issue> = vec![];
+                let end = page.offset.saturating_add(page.limit).min(total);
+                let mut page_number = page.offset / GITHUB_PAGE_SIZE;
+                let first_index = page_number * GITHUB_PAGE_SIZE;
+
+                while page.limit > 0 && page_number * GITHUB_PAGE_SIZE < end {
+                    let mut found_page = thread_octocrab.issues(thread_owner.clone(), repo.clone()).list().state(octocrab::params::State::All)
+                        .per_page(GITHUB_PAGE_SIZE as u8).page((page_number + 1) as u32).send().await
+                        .map_err(access_error)?;
+
+                    issues.append(&mut found_page.items);
+                    page_number += 1;
                 }
 
-                return None
+                let range = TicketPageRequest::new(page.offset - first_index, page.limit).range(issues.len());
+                let issues: Vec<models::issues::Issue> = issues.drain(range).collect();
+                let tickets: BTreeMap<u64, Ticket> = Self::map_issues_to_tickets(issues, thread_ticket_proto, id, &repo);
 
+                // Pages replace the tickets, they already cached, and the cache drops the oldest pages
+                let evicted_buckets = match thread_cached_tickets.lock() {
+                    Ok(mut lock) => lock.insert(tickets.clone()),
+                    Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
+                };
+
+                Self::invalidate_buckets(&thread_cached_buckets, evicted_buckets);
+                Ok(TicketPage::new(tickets.into_values().collect(), page, total))
+
+            });
 
-            }).join();
+            match request.await {
+                Ok(page_result) => page_result,
+                Err(err) => Err(AdapterError::new(AdapterErrorType::Access).with_source(err)),
+            }
+        })
+    }
 
-            match final_result {
-                Ok(tickets_vec) => {
-                    match tickets_vec {
-                        Some(inner_vec) => Ok(inner_vec),
-                        None => Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::Access}),
+    /**
+       Only searches the tickets, that have already been loaded, so searching
+       doesn't load every issue of every repo from Github
+     */
+    fn ticket_search(&self, text: &str) -> Result<Vec<SearchHit>, AdapterError> {
+        let tickets = match self.cached_tickets.lock() {
+            Ok(lock) => lock.tickets(),
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err)),
+        };
+
+        let mut hits: Vec<SearchHit> = tickets.into_iter()
+            .map(|ticket| {
+                let score = SearchHit::score(&ticket, text);
+                SearchHit::new(ticket, score)
+            })
+            .filter(|hit| hit.score > 0.0)
+            .collect();
+
+        SearchHit::sort(&mut hits);
+        Ok(hits)
+    }
+
+    fn ticket_write(&self, ticket: &tickets_rs_core::Ticket) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::TicketWrite))
+    }
+
+    fn ticket_drop(&self, ticket: &tickets_rs_core::Ticket) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::TicketDelete))
+    }
+
+    fn ticket_history(&self, _id: i64) -> Vec<tickets_rs_core::HistoryEntry> {
+        vec![]
+    }
+
+    fn comment_list(&self, ticket: &tickets_rs_core::Ticket) -> Vec<tickets_rs_core::Comment> {
+        block_on(self.comment_list_async(ticket))
+    }
+
+    fn comment_list_async<'a>(&'a self, ticket: &'a tickets_rs_core::Ticket) -> AdapterFuture<'a, Vec<tickets_rs_core::Comment>> {
+        Box::pin(async move {
+
+            // Issue number and repo name are stored as "number::repo" in the additional id
+            let (issue_number, repo) = match ticket.additional_id.split_once("::") {
+                Some((number, repo)) => match number.parse::<u64>() {
+                    Ok(number) => (number, repo.to_string()),
+                    Err(_) => return vec![],
+                },
+                None => return vec![],
+            };
+
+            let thread_octocrab = self.octocrab.clone();
+            let thread_owner = self.owner.clone();
+            let thread_comment_proto = Comment::default().with_ticket(ticket);
+
+            // The request runs on the tokio runtime, so the awaiting thread doesn't need one
+            let request = self.runtime.spawn(async move {
+
+                let issues_handler = thread_octocrab.issues(thread_owner, repo);
+                match issues_handler.list_comments(issue_number).per_page(100).send().await {
+                    Ok(found_page) => {
+                        match thread_octocrab.all_pages::<models::issues::Comment>(found_page).await {
+                            Ok(comments) => Self::map_comments(comments, thread_comment_proto),
+                            Err(err) => {
+                                println!("wasnt able to get all comments of issue {}: {}", issue_number, err);
+                                vec![]
+                            },
+                        }
+                    },
+                    Err(err) => {
+                        println!("wasnt able to get comments of issue {}: {}", issue_number, err);
+                        vec![]
                     }
+                }
+
+            });
+
+            match request.await {
+                Ok(comments) => comments,
+                Err(err) => {
+                    println!("The comment request didn't finish correctly: {}", err);
+                    vec![]
                 },
-                Err(_) => Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::Access}),
             }
+        })
+    }
 
-            
-        } else {
-            if let Ok(lock) = self.cached_tickets.lock() {
+    fn comment_write(&self, _comment: &tickets_rs_core::Comment) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::CommentWrite))
+    }
 
-                let local_tickets: Vec<tickets_rs_core::Ticket> = lock
-                    .iter()
-                    .filter(|ticket| {ticket.1.bucket_id.eq(&id)})
-                    .map(|ticket| ticket.1)
-                    .cloned()
-                    .collect();
+    fn comment_drop(&self, _comment: &tickets_rs_core::Comment) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::CommentDelete))
+    }
 
-                return Ok(local_tickets)
-            }
-            Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::Access})
-        }
+    fn link_list(&self, _ticket: &tickets_rs_core::Ticket) -> Vec<tickets_rs_core::TicketLink> {
+        vec![]
+    }
 
-        
+    fn link_write(&self, _link: &tickets_rs_core::TicketLink) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::LinkWrite))
     }
 
-    fn ticket_write(&self, ticket: &tickets_rs_core::Ticket) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::TicketWrite})
+    fn link_drop(&self, _link: &tickets_rs_core::TicketLink) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::LinkDelete))
     }
 
-    fn ticket_drop(&self, ticket: &tickets_rs_core::Ticket) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::TicketDelete})
+    fn attachment_list(&self, _ticket: &tickets_rs_core::Ticket) -> Vec<tickets_rs_core::Attachment> {
+        vec![]
+    }
+
+    fn attachment_add(&self, _attachment: &tickets_rs_core::Attachment, _data: &[u8]) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::AttachmentWrite))
+    }
+
+    fn attachment_drop(&self, _attachment: &tickets_rs_core::Attachment) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::AttachmentDelete))
+    }
+
+    fn attachment_read(&self, _attachment: &tickets_rs_core::Attachment) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn work_log_list(&self, _ticket: &tickets_rs_core::Ticket) -> Vec<tickets_rs_core::WorkLog> {
+        vec![]
+    }
+
+    fn work_log_list_range(&self, _from: i64, _to: i64) -> Vec<tickets_rs_core::WorkLog> {
+        vec![]
+    }
+
+    fn work_log_write(&self, _work_log: &tickets_rs_core::WorkLog) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::WorkLogWrite))
+    }
+
+    fn work_log_drop(&self, _work_log: &tickets_rs_core::WorkLog) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::WorkLogDelete))
+    }
+
+    fn custom_field_list_all(&self) -> Vec<tickets_rs_core::CustomField> {
+        vec![]
+    }
+
+    fn custom_field_write(&self, _field: &tickets_rs_core::CustomField) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::CustomFieldWrite))
+    }
+
+    fn custom_field_drop(&self, _field: &tickets_rs_core::CustomField) -> Result<(), tickets_rs_core::AdapterError> {
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::CustomFieldDelete))
     }
 
     fn state_list_all(&self) -> Vec<tickets_rs_core::State> {
@@ -341,7 +567,7 @@ impl TicketAdapter for GithubTicketAdapter {
     }
 
     fn state_write(&self, state: &tickets_rs_core::State) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::StateWrite})
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::StateWrite))
     }
 
     fn tag_list_all(&self) -> Vec<tickets_rs_core::Tag> {
@@ -353,11 +579,11 @@ impl TicketAdapter for GithubTicketAdapter {
     }
 
     fn tag_write(&self, state: &tickets_rs_core::Tag) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::TagWrite})
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::TagWrite))
     }
 
     fn tag_drop(&self, state: &tickets_rs_core::Tag) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::TagDelete})
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::TagDelete))
     }
 
     fn filter_list_all(&self) -> Vec<tickets_rs_core::Filter> {
@@ -374,18 +600,18 @@ impl TicketAdapter for GithubTicketAdapter {
     }
 
     fn filter_write(&self, filter: &tickets_rs_core::Filter) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::FilterWrite})
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::FilterWrite))
     }
 
     fn filter_drop(&self, filter: &tickets_rs_core::Filter) -> Result<(), tickets_rs_core::AdapterError> {
-        Err(tickets_rs_core::AdapterError{error_type: AdapterErrorType::FilterDelete})
+        Err(tickets_rs_core::AdapterError::new(AdapterErrorType::FilterDelete))
     }
 
     fn filter_expression_validate(&self, expression: &String) -> Result<(), Vec<(String, String)>> {
         let split_expression: Vec<&str> = expression.split(" ||| ").collect();
         if split_expression.len() == 2 {
 
-            match u64::from_str_radix(split_expression.get(1).unwrap(), 10) {
+            match Self::bucket_from_filter_expr(expression) {
                 Ok(_) => Ok(()),
                 Err(_) => Err(vec![("operation".to_string(), "Expression needs to be in the form of \"repo_name ||| repo_id\". repo_id needs to be a number".to_string())]),
             }
@@ -1,37 +1,54 @@
 mod adapter;
-use std::{collections::BTreeMap, sync::{Arc, Mutex}, time::{Instant, Duration, SystemTime}, thread};
+mod ticket_cache;
+mod ticket_cache_tests;
+use std::{collections::{BTreeMap, BTreeSet}, sync::{Arc, Mutex}, time::{Instant, Duration, SystemTime}};
 
 pub use adapter::*;
 use octocrab::{Octocrab, models, Page};
-use reqwest::header::{HeaderMap, HeaderValue, self};
-use tickets_rs_core::{AppConfig, Ticket, Bucket, BucketIdentifier, Filter, FilterType, TicketAdapter, Tag, State};
+use tickets_rs_core::{Ticket, Comment, ChecklistItem, Bucket, BucketIdentifier, Filter, FilterType, TicketAdapter, Tag, State, EventBus, ProviderEvent, AdapterError, AdapterErrorType, AdapterStatus};
 use tokio::runtime::Handle;
 
+use ticket_cache::TicketCache;
+
+/**
+   How long the buckets, tags and states are kept, before they are
+   loaded from Github again
+ */
+pub(crate) const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
 pub struct GithubTicketAdapter {
     name: String,
     display_name: String,
-    config: Arc<Mutex<AppConfig>>,
-    cached_tickets: Arc<Mutex<BTreeMap<u64, Ticket>>>,
+    cached_tickets: Arc<Mutex<TicketCache>>,
     cached_buckets: Arc<Mutex<BTreeMap<u64, Bucket>>>, // The bool is for seeing, if the corresponding issues need to be loaded
     cached_tags: Arc<Mutex<BTreeMap<String, Tag>>>,
     cached_states: Arc<Mutex<BTreeMap<String, State>>>,
     octocrab: Arc<Octocrab>,
-    auth_token: String,
-    last_refresh: Instant,
+    last_refresh: Mutex<Instant>,
+    status: Arc<Mutex<AdapterStatus>>,
     owner: String,
-    update_trigger: Arc<Mutex<bool>>
+    events: EventBus,
+    runtime: Handle
 }
 
 impl GithubTicketAdapter {
-    pub(crate) fn full_refresh_data(&mut self, update_trigger: Arc<Mutex<bool>>) {
+    pub(crate) fn full_refresh_data(&self) {
 
         // Try to limit updates, so that the API is not getting spammed all the time
-        if self.last_refresh.elapsed() < Duration::from_secs(5 * 60) {
-            return;
+        match self.last_refresh.lock() {
+            Ok(mut lock) => {
+                if lock.elapsed() < REFRESH_INTERVAL {
+                    return;
+                }
+
+                *lock = Instant::now();
+            },
+            Err(_) => return,
         }
 
-        self.last_refresh = Instant::now();
-        self.update_trigger = update_trigger.clone();
+        if let Ok(mut lock) = self.status.lock() {
+            lock.mark_loading();
+        }
 
         let thread_buckets = self.cached_buckets.clone();
         let thread_tags = self.cached_tags.clone();
@@ -41,21 +58,25 @@ impl GithubTicketAdapter {
         let thread_bucket_proto = Bucket::default().with_adapter(self);
         let thread_tag_proto = Tag::default().with_adapter(self);
         let thread_state_proto = State::default().with_adapter(self);
-        let thread_auth_token = self.auth_token.clone();
-        let handle = Handle::current();
-        let _ = thread::spawn(move || {
+        let thread_name = self.name.clone();
+        let thread_events = self.events.clone();
+        let thread_status = self.status.clone();
+
+        // The refresh runs on the tokio runtime in the background
+        self.runtime.spawn(async move {
 
             let users_request = thread_octocrab.users(thread_owner.clone());
 
             // First get all the repos available in the account
-            let repos_page = match handle.block_on(users_request.repos().per_page(100).send()) {
+            let repos_page = match users_request.repos().per_page(100).send().await {
                 Ok(page) => page,
                 Err(err) => {
                     println!("{}", err);
+                    Self::publish_failed(&thread_events, &thread_status, thread_name, AdapterError::new(AdapterErrorType::Access).with_source(err));
                     return;
                 }
             };
-            let repos_result = handle.block_on(thread_octocrab.all_pages::<models::Repository>(repos_page));
+            let repos_result = thread_octocrab.all_pages::<models::Repository>(repos_page).await;
 
             let mut local_cached_buckets: BTreeMap<u64, Bucket> = BTreeMap::default();
             let mut local_cached_tags: BTreeMap<String, Tag> = BTreeMap::default();
@@ -78,6 +99,7 @@ impl GithubTicketAdapter {
                 },
                 Err(err) => {
                     println!("{}", err);
+                    Self::publish_failed(&thread_events, &thread_status, thread_name, AdapterError::new(AdapterErrorType::Access).with_source(err));
                     return;
                 }
             }
@@ -90,40 +112,31 @@ impl GithubTicketAdapter {
                 Err(_) => (),
             }   
 
-            // Now get all labels and map them to tags
+            // Now get all labels and map them to tags. A repo, whose labels can't
+            // be loaded, doesn't stop the others, but the error is reported afterwards
+            let mut label_error: Option<AdapterError> = None;
+
             for buckets in local_cached_buckets {
 
-                let mut headers = HeaderMap::new();
-                headers.insert("X-GitHub-Api-Version", HeaderValue::from_str("2022-11-28").unwrap());
-                headers.insert(header::ACCEPT, HeaderValue::from_str("application/vnd.github+json").unwrap());
-                headers.insert(header::USER_AGENT, HeaderValue::from_static("curl/7.54.1"));
-                
-                let client = reqwest::blocking::Client::new();
-                let request = client
-                    .get(format!("https://api.github.com/repos/{}/{}/labels", thread_owner, buckets.1.name))
-                    .bearer_auth(thread_auth_token.clone())
-                    .headers(headers)
-                    .build().unwrap();
-
-                let response_result = client.execute(request);
-
-                match response_result {
-                    Ok(response) => {
-                        let parsed = response.json::<Vec<models::Label>>();
-                        match parsed {
+                let issues_handler = thread_octocrab.issues(thread_owner.clone(), buckets.1.name.clone());
+
+                match issues_handler.list_labels_for_repo().per_page(100).send().await {
+                    Ok(labels_page) => {
+                        match thread_octocrab.all_pages::<models::Label>(labels_page).await {
                             Ok(parsed_vec) => {
                                 for label in parsed_vec {
-                                    //println!("{}, {}", label.name, label.color);
                                     let next_tag = thread_tag_proto.clone()
                                         .with_name(label.name.clone())
                                         .with_hex_color(label.color.as_str());
                                     local_cached_tags.insert(label.name, next_tag);
                                 }
                             },
-                            Err(err) => println!("parse vec: {}", err),
+                            Err(err) => label_error = Some(AdapterError::new(AdapterErrorType::Access)
+                                .with_entity(format!("Labels of \"{}\"", buckets.1.name)).with_source(err)),
                         }
                     },
-                    Err(err) => println!("parse body: {}", err),
+                    Err(err) => label_error = Some(AdapterError::new(AdapterErrorType::Access)
+                        .with_entity(format!("Labels of \"{}\"", buckets.1.name)).with_source(err)),
                 }
                 
             };
@@ -133,7 +146,6 @@ impl GithubTicketAdapter {
                     lock.clear();
                     lock.insert("open".into(), thread_state_proto.clone().with_name("open".into()).with_description("This issue is still open.".into()));
                     lock.insert("closed".into(), thread_state_proto.clone().with_name("closed".into()).with_description("This issue has been closed.".into()));
-                    println!("appended {} states", lock.len());
                 },
                 Err(_) => (),
             }
@@ -142,21 +154,55 @@ impl GithubTicketAdapter {
                 Ok(mut lock) => {
                     lock.clear();
                     lock.append(&mut local_cached_tags);
-                    println!("appended {} tags", lock.len());
                 },
                 Err(_) => (),
             }
-            
 
+            if let Some(err) = label_error {
+                Self::publish_failed(&thread_events, &thread_status, thread_name, err);
+                return;
+            }
 
-            if let Ok(mut lock) = update_trigger.lock() {
-                *lock = true;
-            };
+            if let Ok(mut lock) = thread_status.lock() {
+                lock.mark_synced();
+            }
+
+            thread_events.publish(ProviderEvent::AdapterLoaded(thread_name));
 
         });
 
     }
 
+    /**
+       Marks the given buckets as not loaded, so all of their tickets are
+       loaded again the next time. This is needed, once the cache dropped
+       some of their tickets
+     */
+    fn invalidate_buckets(cached_buckets: &Mutex<BTreeMap<u64, Bucket>>, bucket_ids: BTreeSet<u64>) {
+        if bucket_ids.is_empty() {
+            return;
+        }
+
+        if let Ok(mut lock) = cached_buckets.lock() {
+            for bucket_id in bucket_ids {
+                if let Some(bucket) = lock.get_mut(&bucket_id) {
+                    bucket.last_change = 0;
+                }
+            }
+        }
+    }
+
+    /**
+       Keeps the error in the status of the adapter and tells everyone else about it
+     */
+    fn publish_failed(events: &EventBus, status: &Mutex<AdapterStatus>, name: String, error: AdapterError) {
+        if let Ok(mut lock) = status.lock() {
+            lock.mark_failed(error.clone());
+        }
+
+        events.publish(ProviderEvent::AdapterFailed(name, error));
+    }
+
     fn map_issues_to_tickets(issues: Vec<models::issues::Issue>, ticket_proto: Ticket, bucket_id: u64, bucket_name: &str) -> BTreeMap<u64, Ticket> {
         issues.into_iter().map(|issue| {
 
@@ -178,8 +224,9 @@ impl GithubTicketAdapter {
             ticket = ticket.with_details(issue.id.0 as i64, issue.title, "".to_string());
 
 
-            // Add Description
+            // Add Description and the task list in it
             if let Some(body) = issue.body {
+                ticket.checklist = GithubTicketAdapter::parse_task_list(&body);
                 ticket.description = body;
             }
 
@@ -199,6 +246,48 @@ impl GithubTicketAdapter {
         }).collect()
     }
 
+    /**
+       Reads all markdown task list entries like "- [ ] task" or "- [x] task"
+       from an issue body and turns them into read only checklist items
+     */
+    pub(crate) fn parse_task_list(body: &str) -> Vec<ChecklistItem> {
+        body.lines().filter_map(|line| {
+            let line = line.trim_start();
+            let entry = line.strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .or_else(|| line.strip_prefix("+ "))?;
+
+            let (done, text) = if let Some(text) = entry.strip_prefix("[ ]") {
+                (false, text)
+            } else if let Some(text) = entry.strip_prefix("[x]").or_else(|| entry.strip_prefix("[X]")) {
+                (true, text)
+            } else {
+                return None;
+            };
+
+            Some(ChecklistItem::new(text.trim().to_string(), done).as_read_only())
+        }).collect()
+    }
+
+    fn map_comments(comments: Vec<models::issues::Comment>, comment_proto: Comment) -> Vec<Comment> {
+        comments.into_iter().map(|comment| {
+
+            let created_at = comment.created_at.timestamp();
+            let edited_at = match comment.updated_at {
+                Some(updated_at) => updated_at.timestamp(),
+                None => created_at,
+            };
+
+            let mut local_comment = comment_proto.clone()
+                .with_details(comment.id.0 as i64, comment.user.login, comment.body.unwrap_or_default());
+
+            local_comment.created_at = created_at;
+            local_comment.edited_at = edited_at;
+            local_comment
+
+        }).collect()
+    }
+
     fn list_builtin_filters(&self) -> Vec<Filter> {
 
         let buckets = self.bucket_list_all();
@@ -216,4 +305,17 @@ impl GithubTicketAdapter {
     pub(crate) fn filter_expr_from_bucket(bucket: &Bucket) -> String {
         format!("{} ||| {}", bucket.name.clone(), bucket.identifier.id.to_string())
     }
+
+    /**
+       Reads the repo name and the repo id back from a filter expression,
+       that has been created by filter_expr_from_bucket
+     */
+    pub(crate) fn bucket_from_filter_expr(expression: &str) -> Result<(String, u64), AdapterError> {
+        let invalid = || AdapterError::new(AdapterErrorType::Expression(expression.to_string()));
+
+        let (repo, id) = expression.split_once(" ||| ").ok_or_else(invalid)?;
+        let id = id.parse::<u64>().map_err(|err| invalid().with_source(err))?;
+
+        Ok((repo.to_string(), id))
+    }
 }
\ No newline at end of file
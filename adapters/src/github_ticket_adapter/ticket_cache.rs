@@ -0,0 +1,95 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use tickets_rs_core::Ticket;
+
+/**
+   The most tickets, that are kept in the cache. Once there are more,
+   the ones, that have been loaded the longest time ago, are dropped
+ */
+pub(crate) const MAX_CACHED_TICKETS: usize = 2000;
+
+/**
+   The tickets, that have been loaded from Github, by their issue id.
+   Each ticket is only kept once, no matter how often it's page has been
+   loaded, and remembers the load it came from, so the cache can be kept
+   at a limited size
+ */
+#[derive(Default)]
+pub(crate) struct TicketCache {
+    tickets: BTreeMap<u64, (Ticket, u64)>,
+    loads: u64
+}
+
+impl TicketCache {
+
+    pub fn get(&self, id: u64) -> Option<&Ticket> {
+        self.tickets.get(&id).map(|(ticket, _)| ticket)
+    }
+
+    #[cfg(test)] // This is only used in tests so far
+    pub fn len(&self) -> usize {
+        self.tickets.len()
+    }
+
+    /**
+       Returns all cached tickets, that belong to the given bucket
+     */
+    pub fn bucket_tickets(&self, bucket_id: u64) -> Vec<Ticket> {
+        self.tickets.values()
+            .filter(|(ticket, _)| ticket.bucket_id == bucket_id)
+            .map(|(ticket, _)| ticket.clone())
+            .collect()
+    }
+
+    /**
+       Returns all cached tickets
+     */
+    pub fn tickets(&self) -> Vec<Ticket> {
+        self.tickets.values().map(|(ticket, _)| ticket.clone()).collect()
+    }
+
+    /**
+       Drops all tickets of the given bucket, for example before all
+       of it's tickets are loaded again
+     */
+    pub fn remove_bucket(&mut self, bucket_id: u64) {
+        self.tickets.retain(|_, (ticket, _)| ticket.bucket_id != bucket_id);
+    }
+
+    /**
+       Adds or replaces the given tickets as a single load. If the cache
+       grows too large, the oldest loads are dropped. Returns the buckets,
+       that lost tickets this way, since they aren't complete anymore
+     */
+    pub fn insert(&mut self, tickets: impl IntoIterator<Item = (u64, Ticket)>) -> BTreeSet<u64> {
+        self.loads += 1;
+
+        for (id, ticket) in tickets {
+            self.tickets.insert(id, (ticket, self.loads));
+        }
+
+        self.evict()
+    }
+
+    fn evict(&mut self) -> BTreeSet<u64> {
+        let mut evicted_buckets: BTreeSet<u64> = BTreeSet::new();
+
+        if self.tickets.len() <= MAX_CACHED_TICKETS {
+            return evicted_buckets;
+        }
+
+        let mut by_age: Vec<(u64, u64)> = self.tickets.iter()
+            .map(|(id, (_, load))| (*load, *id))
+            .collect();
+        by_age.sort_unstable();
+
+        let overflow = self.tickets.len() - MAX_CACHED_TICKETS;
+        for (_, id) in by_age.into_iter().take(overflow) {
+            if let Some((ticket, _)) = self.tickets.remove(&id) {
+                evicted_buckets.insert(ticket.bucket_id);
+            }
+        }
+
+        evicted_buckets
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use tickets_rs_core::Ticket;
+
+    use crate::github_ticket_adapter::ticket_cache::{TicketCache, MAX_CACHED_TICKETS};
+
+    fn page(bucket_id: u64, ids: std::ops::Range<u64>) -> Vec<(u64, Ticket)> {
+        ids.map(|id| (id, Ticket { id: id as i64, bucket_id, ..Default::default() })).collect()
+    }
+
+    #[test]
+    fn test_ticket_cache() {
+        let mut cache = TicketCache::default();
+
+        // Loading the same page again doesn't add the tickets twice
+        assert!(cache.insert(page(1, 0..100)).is_empty());
+        assert!(cache.insert(page(1, 0..100)).is_empty());
+        assert_eq!(cache.len(), 100);
+
+        // Once the cache is full, the oldest load is dropped and it's bucket reported
+        let evicted = cache.insert(page(2, 100..100 + MAX_CACHED_TICKETS as u64));
+        assert_eq!(cache.len(), MAX_CACHED_TICKETS);
+        assert_eq!(evicted.into_iter().collect::<Vec<u64>>(), vec![1]);
+        assert!(cache.get(0).is_none());
+        assert!(cache.get(100).is_some());
+
+        cache.remove_bucket(2);
+        assert!(cache.bucket_tickets(2).is_empty());
+    }
+}
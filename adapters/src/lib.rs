@@ -1,5 +1,7 @@
 mod local_ticket_adapter;
 mod github_ticket_adapter;
+mod process_ticket_adapter;
 
 pub use local_ticket_adapter::LocalTicketAdapter;
-pub use github_ticket_adapter::GithubTicketAdapter;
\ No newline at end of file
+pub use github_ticket_adapter::GithubTicketAdapter;
+pub use process_ticket_adapter::ProcessTicketAdapter;
\ No newline at end of file
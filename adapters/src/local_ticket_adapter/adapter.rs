@@ -1,22 +1,35 @@
 
-use std::{path::Path, sync::{Arc, Mutex}};
+use std::{path::Path, sync::{Arc, Mutex}, collections::BTreeMap};
 
-use rusqlite::types::Value;
+use rusqlite::{params_from_iter, types::Value};
 
 use tickets_rs_core::{
     Bucket,
     Ticket,
+    Comment,
+    HistoryEntry,
+    CustomField,
+    CustomFieldType,
+    TicketLink,
+    TicketLinkType,
+    TicketIdentifier,
+    Attachment,
+    WorkLog,
+    Priority,
+    Recurrence,
     State,
     Filter,
+    FilterQuery,
+    SearchHit,
     Tag,
     TicketAdapter,
     AdapterError,
-    AdapterErrorType, Config, LocalDatabase, AppConfig, TicketProvider, FilterType, StateIdentifier, BucketIdentifier
+    AdapterErrorType, AdapterCapabilities, Config, LocalDatabase, AppConfig, TicketProvider, FilterType, StateIdentifier, BucketIdentifier, EventBus, ProviderEvent, TicketPage, TicketPageRequest
 };
 
 use super::{
     LocalTicketAdapter,
-    interpreter::{AdapterInterpreter}
+    interpreter::{AdapterInterpreter, SqlExpression, TICKET_ORDER}
 };
 
 impl TicketAdapter for LocalTicketAdapter {
@@ -46,10 +59,11 @@ impl TicketAdapter for LocalTicketAdapter {
         TicketProvider::get_default_config::<LocalTicketAdapter>()
             .with("database", "./local.db3", "string")
             .with("include_default_data", true, "bool")
+            .with("done_state", "done", "string")
     }
 
     
-    fn from_config(app_config: Arc<Mutex<AppConfig>>, config: &Config, finished: Arc<Mutex<bool>>) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized {
+    fn from_config(app_config: Arc<Mutex<AppConfig>>, config: &Config, events: EventBus) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized {
 
         let name: String = match config.get("name") {
             Some(option) => match option.get() {
@@ -83,13 +97,15 @@ impl TicketAdapter for LocalTicketAdapter {
             None => return Err(AdapterError::new(AdapterErrorType::Instantiation)),
         };
 
+        // Older configs don't have a done state yet
+        let done_state: String = config.get("done_state")
+            .and_then(|option| option.get())
+            .unwrap_or("done".to_string());
+
         let database = {
-            let database = match LocalDatabase::open(database_name) {
+            let database = match LocalDatabase::open(database_name.clone()) {
                 Ok(success) => success,
-                Err(_) => {
-                    println!("Failed to read Local SQLite Database, exiting!"); 
-                    return Err(AdapterError::new(AdapterErrorType::Access));
-                }
+                Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_entity(database_name).with_source(err))
             };
             Arc::new(Mutex::new(database))
         };
@@ -100,15 +116,17 @@ impl TicketAdapter for LocalTicketAdapter {
             config: app_config,
             name,
             display_name,
+            done_state
         };
 
         local_tickets.prepare_database(create_default_data);
+        events.publish(ProviderEvent::AdapterLoaded(local_tickets.get_name()));
 
         Ok(Box::new(local_tickets))
     }
 
-    fn is_read_only(&self) -> bool {
-        false
+    fn get_capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities::all()
     }
 
     fn bucket_list_all(&self) -> Vec<Bucket> {
@@ -168,9 +186,8 @@ impl TicketAdapter for LocalTicketAdapter {
                                 ":last_change": bucket.last_change
                             }) {
                                 Ok(_) => Ok(()),
-                                Err(_) => {
-                                    println!("There was an error executing this bucket writing operation!");
-                                    Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                                Err(err) => {
+                                    Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_source(err))
                                 }
                             }
                             
@@ -194,39 +211,149 @@ impl TicketAdapter for LocalTicketAdapter {
                                         Ok(row_option) => match row_option {
                                             Some(row_value) => match row_value.get(0) {
                                                 Ok(found_value) => found_value,
-                                                Err(_) => {
-                                                    println!("There was an error executing this bucket writing operation!");
-                                                    return Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                                                Err(err) => {
+                                                    return Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_source(err))
                                                 },
                                             },
                                             None => {
-                                                println!("There was an error executing this bucket writing operation!");
-                                                return Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                                                return Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_reason("The new id wasn't returned"))
                                             },
                                         },
-                                        Err(_) => {
-                                            println!("There was an error executing this bucket writing operation!");
-                                            return Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                                        Err(err) => {
+                                            return Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_source(err))
                                         },
                                     };
                                     Ok(())
                                 },
-                                Err(_) => {
-                                    println!("There was an error executing this bucket writing operation!");
-                                    return Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                                Err(err) => {
+                                    return Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_source(err))
                                 }
                             }
                         }
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for writing Bucket on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                        Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::BucketWrite).with_entity(format!("Bucket \"{}\"", bucket.name)).with_reason(e))
+            }
+        }
+    }
+
+    fn custom_field_list_all(&self) -> Vec<CustomField> {
+        let mut fields: Vec<CustomField> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = "SELECT name, field_type, options FROM custom_fields ORDER BY name";
+                        let mut stmt_select = lock.prepare(expression).unwrap();
+
+                        let iter = stmt_select.query_map([], |row| {
+                            let type_name: String = row.get(1).unwrap();
+                            let options: String = row.get(2).unwrap();
+
+                            Ok(CustomField::default()
+                                .with_adapter(self)
+                                .with_name(row.get(0).unwrap())
+                                .with_type(CustomFieldType::from_type_name(&type_name, &options)))
+                        }).unwrap();
+
+                        for row in iter {
+                            let field = row.unwrap();
+                            fields.push(field)
+                        };
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Custom Fields on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+
+        fields
+    }
+
+    fn custom_field_write(&self, field: &CustomField) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        let expression = [
+                            "REPLACE INTO custom_fields",  
+                            "(name, field_type, options)", 
+                            "VALUES (:name, :field_type, :options);"].join("");
+
+                        let mut stmt_write = lock.prepare(expression.as_str()).unwrap();
+
+                        match stmt_write.execute(rusqlite::named_params! {
+                            ":name": field.name,
+                            ":field_type": field.field_type.get_type_name(),
+                            ":options": field.field_type.get_options()
+                        }) {
+                            Ok(_) => Ok(()),
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::CustomFieldWrite).with_entity(format!("Custom Field \"{}\"", field.name)).with_source(err))
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::CustomFieldWrite).with_entity(format!("Custom Field \"{}\"", field.name)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::CustomFieldWrite).with_entity(format!("Custom Field \"{}\"", field.name)).with_reason(e))
+            }
+        }
+    }
+
+    fn custom_field_drop(&self, field: &CustomField) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        // Delete values of the field on all tickets
+                        {
+                            let mut expression: Vec<&str> = vec![];
+                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                            expression.push("DELETE FROM ticket_custom_fields WHERE field_name = ?; ");
+                            parameters.push(Value::Text(field.name.clone()));
+
+                            let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                                return Err(AdapterError::new(AdapterErrorType::CustomFieldDelete).with_entity(format!("Custom Field \"{}\"", field.name)).with_source(err));
+                            };
+                        }
+
+                        //Delete field definition
+                        {
+                            let mut expression: Vec<&str> = vec![];
+                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                            expression.push("DELETE FROM custom_fields WHERE name = ?; ");
+                            parameters.push(Value::Text(field.name.clone()));
+
+                            let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                                return Err(AdapterError::new(AdapterErrorType::CustomFieldDelete).with_entity(format!("Custom Field \"{}\"", field.name)).with_source(err));
+                            };
+                        }
+
+                        Ok(())
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::CustomFieldDelete).with_entity(format!("Custom Field \"{}\"", field.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for writing Bucket on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::BucketWrite))
+                Err(AdapterError::new(AdapterErrorType::CustomFieldDelete).with_entity(format!("Custom Field \"{}\"", field.name)).with_reason(e))
             }
         }
     }
@@ -286,21 +413,18 @@ impl TicketAdapter for LocalTicketAdapter {
                             ":sorting_order": state.sorting_order
                         }) {
                             Ok(_) => Ok(()),
-                            Err(_) => {
-                                println!("There was an error executing this writing operation!");
-                                Err(AdapterError::new(AdapterErrorType::StateWrite))
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::StateWrite).with_entity(format!("State \"{}\"", state.identifier.name)).with_source(err))
                             }
                         }
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for writing State on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::StateWrite))
+                        Err(AdapterError::new(AdapterErrorType::StateWrite).with_entity(format!("State \"{}\"", state.identifier.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for writing State on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::StateWrite))
+                Err(AdapterError::new(AdapterErrorType::StateWrite).with_entity(format!("State \"{}\"", state.identifier.name)).with_reason(e))
             }
         }
     }
@@ -357,21 +481,18 @@ impl TicketAdapter for LocalTicketAdapter {
                             ":color_text": tag.color_text
                         }) {
                             Ok(_) => Ok(()),
-                            Err(_) => {
-                                println!("There was an error executing this writing operation!");
-                                Err(AdapterError::new(AdapterErrorType::TagWrite))
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::TagWrite).with_entity(format!("Tag \"{}\"", tag.name)).with_source(err))
                             }
                         }
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for writing Tag on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::TagWrite))
+                        Err(AdapterError::new(AdapterErrorType::TagWrite).with_entity(format!("Tag \"{}\"", tag.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for writing Tag on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::TagWrite))
+                Err(AdapterError::new(AdapterErrorType::TagWrite).with_entity(format!("Tag \"{}\"", tag.name)).with_reason(e))
             }
         }
     }
@@ -452,6 +573,8 @@ impl TicketAdapter for LocalTicketAdapter {
         //Try and tokenize the operation
         let mut interpreter = AdapterInterpreter::default();
         interpreter.setup_environment(self.config.clone());
+        interpreter.set_variable("adapter", &self.get_name());
+        interpreter.set_variable("done_state", &self.done_state);
 
         match interpreter.try_tokenize(filter.to_string()) {
             Ok(_) => (),
@@ -470,8 +593,7 @@ impl TicketAdapter for LocalTicketAdapter {
     fn filter_write(&self, filter: &Filter) -> Result<(), AdapterError> {
 
         if !matches!(filter.filter_type, FilterType::User) {
-            println!("{} is a builtin Filter, so it is readonly!", filter.identifier.name);
-            return Err(AdapterError::new(AdapterErrorType::FilterWrite))
+            return Err(AdapterError::new(AdapterErrorType::FilterWrite).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_reason("Builtin Filters are read only"))
         }
 
         match self.database.lock() {
@@ -491,21 +613,18 @@ impl TicketAdapter for LocalTicketAdapter {
                             ":operation": filter.operation
                         }) {
                             Ok(_) => Ok(()),
-                            Err(_) => {
-                                println!("There was an error executing this writing operation!");
-                                Err(AdapterError::new(AdapterErrorType::FilterWrite))
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::FilterWrite).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_source(err))
                             }
                         }
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for writing Filter on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::FilterWrite))
+                        Err(AdapterError::new(AdapterErrorType::FilterWrite).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for writing Filter on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::FilterWrite))
+                Err(AdapterError::new(AdapterErrorType::FilterWrite).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_reason(e))
             }
         }
     }
@@ -533,7 +652,12 @@ impl TicketAdapter for LocalTicketAdapter {
                                 created_at: row.get(5).unwrap(),
                                 due_at: row.get(6).unwrap(),
                                 assigned_to: row.get(7).unwrap(),
+                                estimate: row.get(8).unwrap(),
+                                priority: Priority::from_value(row.get(9).unwrap()),
+                                recurrence: Recurrence::from_text(row.get::<usize, String>(10).unwrap().as_str()),
                                 tags: vec![],
+                                custom_fields: BTreeMap::new(),
+                                checklist: vec![],
                                 additional_id: id.to_string()
                             })
                         }).unwrap();
@@ -570,6 +694,8 @@ impl TicketAdapter for LocalTicketAdapter {
                                 }
     
                                 ticket.tags.append(&mut tags);
+                                ticket.custom_fields = Self::ticket_custom_fields(&lock, ticket.id);
+                                ticket.checklist = Self::ticket_checklist(&lock, ticket.id);
                                 Some(ticket)
                             },
                             None => None,
@@ -584,6 +710,48 @@ impl TicketAdapter for LocalTicketAdapter {
         ticket_option
     }
 
+    fn ticket_history(&self, id: i64) -> Vec<HistoryEntry> {
+        let mut history: Vec<HistoryEntry> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT ticket_id, field, old_value, new_value, changed_by, changed_at ",
+                            "FROM ticket_history WHERE ticket_history.ticket_id = :id ",
+                            "ORDER BY changed_at, id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":id": id
+                        }, |row| {
+                            Ok(HistoryEntry {
+                                adapter: self.get_name(),
+                                ticket_id: row.get(0).unwrap(),
+                                field: row.get(1).unwrap(),
+                                old_value: row.get(2).unwrap(),
+                                new_value: row.get(3).unwrap(),
+                                changed_by: row.get(4).unwrap(),
+                                changed_at: row.get(5).unwrap()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let entry = row.unwrap();
+                            history.push(entry)
+                        };
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Ticket History on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+
+        history
+    }
+
     fn bucket_list_unique(&self, id: u64) -> Option<Bucket> {
         let mut bucket_option: Option<Bucket> = None;
 
@@ -633,8 +801,8 @@ impl TicketAdapter for LocalTicketAdapter {
                     Ok(lock) => {
 
                         // Get tickets first, with empty tags vector
-                        let expression = "SELECT * FROM tickets";
-                        let mut stmt_select = lock.prepare(expression).unwrap();
+                        let expression = ["SELECT * FROM tickets", TICKET_ORDER].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
                 
                         let iter = stmt_select.query_map([], |row| {
                             Ok(Ticket {
@@ -647,7 +815,12 @@ impl TicketAdapter for LocalTicketAdapter {
                                 created_at: row.get(5).unwrap(),
                                 due_at: row.get(6).unwrap(),
                                 assigned_to: row.get(7).unwrap(),
+                                estimate: row.get(8).unwrap(),
+                                priority: Priority::from_value(row.get(9).unwrap()),
+                                recurrence: Recurrence::from_text(row.get::<usize, String>(10).unwrap().as_str()),
                                 tags: vec![],
+                                custom_fields: BTreeMap::new(),
+                                checklist: vec![],
                                 additional_id: row.get::<_, i64>(0).unwrap().to_string()
                             })
                         }).unwrap();
@@ -678,6 +851,8 @@ impl TicketAdapter for LocalTicketAdapter {
                             }
 
                             ticket.tags.append(&mut tags);
+                            ticket.custom_fields = Self::ticket_custom_fields(&lock, ticket.id);
+                            ticket.checklist = Self::ticket_checklist(&lock, ticket.id);
                             tickets.push(ticket)
                         };
 
@@ -692,265 +867,778 @@ impl TicketAdapter for LocalTicketAdapter {
 
     fn ticket_list(&self, expression: &str) -> Result<Vec<Ticket>, AdapterError> {
 
-        let mut tickets: Vec<Ticket> = Vec::new();
         let mut interpreter = AdapterInterpreter::default();
         interpreter.setup_environment(self.config.clone());
+        interpreter.set_variable("adapter", &self.get_name());
+        interpreter.set_variable("done_state", &self.done_state);
+
+        if let Err(error) = interpreter.try_tokenize(expression.to_string()) {
+            println!("TokenizationError: {}", error);
+            return Err(AdapterError::new(AdapterErrorType::Expression(error.to_string())));
+        }
+
+        let sql_expression = match interpreter.construct_sql_expression() {
+            Ok(sql_expression) => sql_expression,
+            Err(err) => {
+                println!("SqlExpressionError: {}", err);
+                return Err(AdapterError::new(AdapterErrorType::Expression(err.to_string())));
+            }
+        };
 
-        let error: Option<AdapterError> = match self.database.lock() {
+        match self.database.lock() {
             Ok(db_lock) => {
                 match db_lock.connection.lock() {
                     Ok(lock) => {
+                        let parameters = sql_expression.get_parameters().to_vec();
+                        self.ticket_query(&lock, &sql_expression.get_ordered(), params_from_iter(parameters))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))
+                    },
+                    Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+                }
+            },
+            Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+        }
+    }
 
-                        // Get tickets first, with empty tags vector
-                        if let Err(error) = interpreter.try_tokenize(expression.to_string()) {
-                            println!("TokenizationError: {}", error);
-                            return Err(AdapterError::new(AdapterErrorType::Expression(error.to_string())));
-                        }
+    fn ticket_list_all_page(&self, page: TicketPageRequest) -> TicketPage {
 
-                        let mut compiled_expr = String::default();
+        let result = match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let params = SqlExpression::page_params(page);
+                        let expression = ["SELECT * FROM tickets", TICKET_ORDER, " LIMIT ?1 OFFSET ?2;"].join("");
+                        let tickets = self.ticket_query(&lock, &expression, params);
+                        let total = Self::ticket_count(&lock, "SELECT COUNT(*) FROM tickets;", []);
 
-                        // Return early on Err(..)
-                        match interpreter.construct_sql() {
-                            Ok(expr) => {
-                                compiled_expr = expr;
-                                Ok(())
-                            },
-                            Err(err) => {
-                                println!("SqlExpressionError: {}", err);
-                                Err(AdapterError::new(AdapterErrorType::Expression(err.to_string())))
-                            }
-                        }?;
+                        tickets.and_then(|tickets| total.map(|total| TicketPage::new(tickets, page, total)))
+                            .map_err(|e| e.to_string())
+                    },
+                    Err(e) => Err(e.to_string())
+                }
+            },
+            Err(e) => Err(e.to_string())
+        };
 
-                        let mut stmt_select = lock.prepare(compiled_expr.as_str()).unwrap();
-                
-                        let iter = stmt_select.query_map([], |row| {
-                            Ok(Ticket {
-                                adapter: self.get_name(),
-                                id: row.get(0).unwrap(),
-                                bucket_id: row.get(1).unwrap(),
-                                title: row.get(2).unwrap(),
-                                state_name: row.get(3).unwrap(),
-                                description: row.get(4).unwrap(),
-                                created_at: row.get(5).unwrap(),
-                                due_at: row.get(6).unwrap(),
-                                assigned_to: row.get(7).unwrap(),
-                                tags: vec![],
-                                additional_id: row.get::<_, i64>(0).unwrap().to_string()
-                            })
-                        }).unwrap();
-                
-                        for row in iter {
-                            let mut ticket = row.unwrap();
+        match result {
+            Ok(ticket_page) => ticket_page,
+            Err(e) => {
+                println!("Wasn't able to list a page of Tickets on local, {}", e);
+                TicketPage::new(vec![], page, 0)
+            }
+        }
+    }
 
-                            // get tags vector content, and fill ticket with it
-                            let mut tags: Vec<String> = Vec::new();
-                            {
-                                let tags_expression = [
-                                    "SELECT tag_name FROM ticket_tags ",
-                                    "WHERE ticket_tags.ticket_id = :id;"
-                                ].join("");
+    fn ticket_list_page(&self, expression: &str, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
 
-                                let mut stmt_tags_join = lock.prepare(tags_expression.as_str()).unwrap();
+        let mut interpreter = AdapterInterpreter::default();
+        interpreter.setup_environment(self.config.clone());
+        interpreter.set_variable("adapter", &self.get_name());
+        interpreter.set_variable("done_state", &self.done_state);
 
-                                let tags_iter = stmt_tags_join.query_map(rusqlite::named_params! {
-                                    ":id": ticket.id
-                                }, |tags_row| {
-                                    Ok(tags_row.get(0).unwrap())
-                                }).unwrap();
+        if let Err(error) = interpreter.try_tokenize(expression.to_string()) {
+            println!("TokenizationError: {}", error);
+            return Err(AdapterError::new(AdapterErrorType::Expression(error.to_string())));
+        }
 
-                                for tags_row in tags_iter {
-                                    let tag = tags_row.unwrap();
-                                    tags.push(tag)
-                                };
-                            }
+        let sql_expression = match interpreter.construct_sql_expression() {
+            Ok(sql_expression) => sql_expression,
+            Err(err) => {
+                println!("SqlExpressionError: {}", err);
+                return Err(AdapterError::new(AdapterErrorType::Expression(err.to_string())));
+            }
+        };
 
-                            ticket.tags.append(&mut tags);
-                            tickets.push(ticket)
-                        };
-                        None
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let tickets = self.ticket_query(&lock, &sql_expression.get_page(), params_from_iter(sql_expression.get_page_parameters(page)))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))?;
+                        let total = Self::ticket_count(&lock, &sql_expression.get_count(), params_from_iter(sql_expression.get_parameters()))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))?;
+
+                        Ok(TicketPage::new(tickets, page, total))
                     },
-                    Err(e) => {
-                        println!("Wasn't able to lock for listing Tickets on local, {}", e);
-                        Some(AdapterError::new(AdapterErrorType::Access))
-                    }
+                    Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
                 }
             },
-            Err(e) => {
-                println!("Wasn't able to lock Database, {}", e);
-                Some(AdapterError::new(AdapterErrorType::Access))
-            }
-        };
-        
-        match error {
-            None => Ok(tickets),
-            Some(err) => Err(err)
+            Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
         }
     }
 
-    fn ticket_write(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+    fn ticket_list_query(&self, query: &FilterQuery) -> Result<Vec<Ticket>, AdapterError> {
+
+        let sql_expression = self.query_sql_expression(query)?;
+
         match self.database.lock() {
             Ok(db_lock) => {
                 match db_lock.connection.lock() {
                     Ok(lock) => {
+                        let parameters = sql_expression.get_parameters().to_vec();
+                        self.ticket_query(&lock, &sql_expression.get_ordered(), params_from_iter(parameters))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))
+                    },
+                    Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+                }
+            },
+            Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+        }
+    }
 
-                        let mut ticket = ticket.clone();
+    fn ticket_list_query_page(&self, query: &FilterQuery, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
 
-                        // Replace Ticket (or add with new id, if id is 0)
-                        if ticket.id != 0 {
+        let sql_expression = self.query_sql_expression(query)?;
 
-                            let mut expression: Vec<&str> = vec![];
-                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let tickets = self.ticket_query(&lock, &sql_expression.get_page(), params_from_iter(sql_expression.get_page_parameters(page)))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))?;
+                        let total = Self::ticket_count(&lock, &sql_expression.get_count(), params_from_iter(sql_expression.get_parameters()))
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))?;
 
-                            expression.push("REPLACE INTO tickets ");
-                            expression.push("(id, bucket_id, title, state_name, description, created_at, due_at, assigned_to) "); 
-                            expression.push("VALUES (?, ?, ?, ?, ?, ?, ?, ?); ");
-                            parameters.push(Value::Integer(ticket.id));
-                            parameters.push(Value::Integer(ticket.bucket_id as i64));
-                            parameters.push(Value::Text(ticket.title.clone()));
-                            parameters.push(Value::Text(ticket.state_name.clone()));
-                            parameters.push(Value::Text(ticket.description.clone()));
-                            parameters.push(Value::Integer(ticket.created_at));
-                            parameters.push(Value::Integer(ticket.due_at));
-                            parameters.push(Value::Text(ticket.assigned_to.clone()));
+                        Ok(TicketPage::new(tickets, page, total))
+                    },
+                    Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+                }
+            },
+            Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+        }
+    }
 
-                            let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
-                            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing this replace ticket operation! Reason: {}", err);
-                            };
+    fn ticket_search(&self, text: &str) -> Result<Vec<SearchHit>, AdapterError> {
 
-                        } else {
-                            let mut expression: Vec<&str> = vec![];
-                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        self.search_hits(&lock, text)
+                            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))
+                    },
+                    Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+                }
+            },
+            Err(e) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(e))
+        }
+    }
 
-                            expression.push("INSERT INTO tickets ");
-                            expression.push("(bucket_id, title, state_name, description, created_at, due_at, assigned_to) "); 
-                            expression.push("VALUES (?, ?, ?, ?, ?, ?, ?) returning id; ");
-                            parameters.push(Value::Integer(ticket.bucket_id as i64));
-                            parameters.push(Value::Text(ticket.title.clone()));
-                            parameters.push(Value::Text(ticket.state_name.clone()));
-                            parameters.push(Value::Text(ticket.description.clone()));
-                            parameters.push(Value::Integer(ticket.created_at));
-                            parameters.push(Value::Integer(ticket.due_at));
-                            parameters.push(Value::Text(ticket.assigned_to.clone()));
+    fn ticket_write(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        self.ticket_write_many(std::slice::from_ref(ticket)).remove(0).map(|_| ())
+    }
 
-                            let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
-                            match stmt_write.query(rusqlite::params_from_iter(parameters)) {
-                                Err(err) => {
-                                    println!("There was an error executing this insert ticket operation! Reason: {}", err);
-                                },
-                                Ok(mut rows) => {
-                                    
-                                    let ticket_ref = ticket.clone();
-                                    let mut id = 0;
+    fn ticket_write_many(&self, tickets: &[Ticket]) -> Vec<Result<Ticket, AdapterError>> {
+        let username = self.current_username();
 
-                                    while let Some(row) = rows.next().unwrap() {
-                                        id = row.get(0).unwrap();
-                                    }
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(mut lock) => {
 
-                                    ticket = ticket.with_details(id, ticket_ref.title, ticket_ref.description);
-                                },
-                            };
-                        }
+                        // Either all tickets are written or none of them
+                        let transaction = match lock.transaction() {
+                            Ok(transaction) => transaction,
+                            Err(err) => return vec![Err(AdapterError::new(AdapterErrorType::TicketWrite).with_source(err)); tickets.len()]
+                        };
 
-                        // Delete old tag References of ticket
-                        {
-                            let mut expression: Vec<&str> = vec![];
-                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+                        let results = tickets.iter()
+                            .map(|ticket| self.write_ticket(&transaction, ticket, &username))
+                            .collect();
 
-                            expression.push("DELETE FROM ticket_tags WHERE ticket_id = ?; ");
-                            parameters.push(Value::Integer(ticket.id));
+                        Self::finish_transaction(transaction, tickets, results, AdapterErrorType::TicketWrite)
+                    },
+                    Err(e) => vec![Err(AdapterError::new(AdapterErrorType::TicketWrite).with_reason(e)); tickets.len()]
+                }
+            },
+            Err(e) => vec![Err(AdapterError::new(AdapterErrorType::TicketWrite).with_reason(e)); tickets.len()]
+        }
+    }
+
+    fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        self.ticket_drop_many(std::slice::from_ref(ticket)).remove(0)
+    }
+
+    fn ticket_drop_many(&self, tickets: &[Ticket]) -> Vec<Result<(), AdapterError>> {
+        let username = self.current_username();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(mut lock) => {
+
+                        // Either all tickets are deleted or none of them
+                        let transaction = match lock.transaction() {
+                            Ok(transaction) => transaction,
+                            Err(err) => return vec![Err(AdapterError::new(AdapterErrorType::TicketDelete).with_source(err)); tickets.len()]
+                        };
+
+                        let results = tickets.iter()
+                            .map(|ticket| self.drop_ticket(&transaction, ticket, &username))
+                            .collect();
+
+                        Self::finish_transaction(transaction, tickets, results, AdapterErrorType::TicketDelete)
+                    },
+                    Err(e) => vec![Err(AdapterError::new(AdapterErrorType::TicketDelete).with_reason(e)); tickets.len()]
+                }
+            },
+            Err(e) => vec![Err(AdapterError::new(AdapterErrorType::TicketDelete).with_reason(e)); tickets.len()]
+        }
+    }
+
+    fn comment_list(&self, ticket: &Ticket) -> Vec<Comment> {
+        let mut comments: Vec<Comment> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT * FROM comments ",
+                            "WHERE comments.ticket_id = :id ",
+                            "ORDER BY created_at, id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":id": ticket.id
+                        }, |row| {
+                            Ok(Comment {
+                                adapter: self.get_name(),
+                                id: row.get(0).unwrap(),
+                                ticket_id: row.get(1).unwrap(),
+                                author: row.get(2).unwrap(),
+                                body: row.get(3).unwrap(),
+                                created_at: row.get(4).unwrap(),
+                                edited_at: row.get(5).unwrap()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let comment = row.unwrap();
+                            comments.push(comment)
+                        };
+
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Comments on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+        comments
+    }
+
+    fn comment_write(&self, comment: &Comment) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        let mut expression: Vec<&str> = vec![];
+                        let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                        // Replace Comment (or add with new id, if id is 0)
+                        if comment.id != 0 {
+                            expression.push("REPLACE INTO comments ");
+                            expression.push("(id, ticket_id, author, body, created_at, edited_at) ");
+                            expression.push("VALUES (?, ?, ?, ?, ?, ?); ");
+                            parameters.push(Value::Integer(comment.id));
+                        } else {
+                            expression.push("INSERT INTO comments ");
+                            expression.push("(ticket_id, author, body, created_at, edited_at) ");
+                            expression.push("VALUES (?, ?, ?, ?, ?); ");
+                        }
+
+                        parameters.push(Value::Integer(comment.ticket_id));
+                        parameters.push(Value::Text(comment.author.clone()));
+                        parameters.push(Value::Text(comment.body.clone()));
+                        parameters.push(Value::Integer(comment.created_at));
+                        parameters.push(Value::Integer(comment.edited_at));
+
+                        let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                        match stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                            Ok(_) => Ok(()),
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::CommentWrite).with_entity(format!("Comment {}", comment.id)).with_source(err))
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::CommentWrite).with_entity(format!("Comment {}", comment.id)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::CommentWrite).with_entity(format!("Comment {}", comment.id)).with_reason(e))
+            }
+        }
+    }
+
+    fn comment_drop(&self, comment: &Comment) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        //Delete comment
+                        {
+                            let mut expression: Vec<&str> = vec![];
+                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                            expression.push("DELETE FROM comments WHERE id = ?; ");
+                            parameters.push(Value::Integer(comment.id));
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete tag refs operation! Reason: {}", err);
+                                return Err(AdapterError::new(AdapterErrorType::CommentDelete).with_entity(format!("Comment {}", comment.id)).with_source(err));
                             };
                         }
 
-                        // Add new tag references
-                        if !ticket.tags.is_empty() {
-                            let mut expression: Vec<&str> = vec![];
-                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+                        Ok(())
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::CommentDelete).with_entity(format!("Comment {}", comment.id)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::CommentDelete).with_entity(format!("Comment {}", comment.id)).with_reason(e))
+            }
+        }
+    }
 
-                            expression.push("INSERT INTO ticket_tags VALUES ");
-                            if let Some((last_tag, tags)) = ticket.tags.split_last() {
-                                for tag in tags {
-                                    expression.push("(?, ?), ");
-                                    parameters.push(Value::Integer(ticket.id));
-                                    parameters.push(Value::Text(tag.to_string()));
-                                }
+    fn link_list(&self, ticket: &Ticket) -> Vec<TicketLink> {
+        let mut links: Vec<TicketLink> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT id, source_id, target_adapter, target_id, link_type FROM ticket_links ",
+                            "WHERE (:adapter = :own_adapter AND ticket_links.source_id = :id) ",
+                            "OR (ticket_links.target_adapter = :adapter AND ticket_links.target_id = :id) ",
+                            "ORDER BY id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":id": ticket.id,
+                            ":adapter": ticket.adapter,
+                            ":own_adapter": self.get_name()
+                        }, |row| {
+                            let link_type: String = row.get(4).unwrap();
+
+                            Ok(TicketLink {
+                                id: row.get(0).unwrap(),
+                                source: TicketIdentifier::new(&self.get_name(), row.get(1).unwrap()),
+                                target: TicketIdentifier::new(&row.get::<_, String>(2).unwrap(), row.get(3).unwrap()),
+                                link_type: TicketLinkType::from_type_name(&link_type).unwrap_or_default()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let link = row.unwrap();
+                            links.push(link)
+                        };
+
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Links on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+        links
+    }
+
+    fn link_write(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        if link.source.adapter != self.get_name() {
+            return Err(AdapterError::new(AdapterErrorType::LinkWrite)
+                .with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id))
+                .with_reason(format!("The source Ticket belongs to {}", link.source.adapter)));
+        }
 
-                                expression.push("(?, ?); ");
-                                parameters.push(Value::Integer(ticket.id));
-                                parameters.push(Value::Text(last_tag.to_string()));
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        let mut expression: Vec<&str> = vec![];
+                        let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                        // Replace Link (or add with new id, if id is 0)
+                        if link.id != 0 {
+                            expression.push("REPLACE INTO ticket_links ");
+                            expression.push("(id, source_id, target_adapter, target_id, link_type) ");
+                            expression.push("VALUES (?, ?, ?, ?, ?); ");
+                            parameters.push(Value::Integer(link.id));
+                        } else {
+                            expression.push("INSERT INTO ticket_links ");
+                            expression.push("(source_id, target_adapter, target_id, link_type) ");
+                            expression.push("VALUES (?, ?, ?, ?); ");
+                        }
+
+                        parameters.push(Value::Integer(link.source.id));
+                        parameters.push(Value::Text(link.target.adapter.clone()));
+                        parameters.push(Value::Integer(link.target.id));
+                        parameters.push(Value::Text(link.link_type.get_type_name()));
+
+                        let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                        match stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                            Ok(_) => Ok(()),
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::LinkWrite).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_source(err))
                             }
+                        }
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::LinkWrite).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::LinkWrite).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_reason(e))
+            }
+        }
+    }
+
+    fn link_drop(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        //Delete link
+                        {
+                            let mut expression: Vec<&str> = vec![];
+                            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                            expression.push("DELETE FROM ticket_links WHERE id = ?; ");
+                            parameters.push(Value::Integer(link.id));
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing insert tags operation! Reason: {}", err);
+                                return Err(AdapterError::new(AdapterErrorType::LinkDelete).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_source(err));
                             };
                         }
 
                         Ok(())
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for writing Ticket on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::TicketWrite))
+                        Err(AdapterError::new(AdapterErrorType::LinkDelete).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for writing Ticket on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::TicketWrite))
+                Err(AdapterError::new(AdapterErrorType::LinkDelete).with_entity(format!("Link from Ticket {} to {}", link.source.id, link.target.id)).with_reason(e))
             }
         }
     }
 
-    fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+    fn attachment_list(&self, ticket: &Ticket) -> Vec<Attachment> {
+        let mut attachments: Vec<Attachment> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT id, ticket_id, name, mime_type, size, created_at FROM attachments ",
+                            "WHERE attachments.ticket_id = :id ",
+                            "ORDER BY created_at, id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":id": ticket.id
+                        }, |row| {
+                            Ok(Attachment {
+                                adapter: self.get_name(),
+                                id: row.get(0).unwrap(),
+                                ticket_id: row.get(1).unwrap(),
+                                name: row.get(2).unwrap(),
+                                mime_type: row.get(3).unwrap(),
+                                size: row.get(4).unwrap(),
+                                created_at: row.get(5).unwrap()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let attachment = row.unwrap();
+                            attachments.push(attachment)
+                        };
+
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Attachments on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+        attachments
+    }
+
+    fn attachment_add(&self, attachment: &Attachment, data: &[u8]) -> Result<(), AdapterError> {
         match self.database.lock() {
             Ok(db_lock) => {
                 match db_lock.connection.lock() {
                     Ok(lock) => {
-                        // Delete old tag References of ticket
+
+                        let mut expression: Vec<&str> = vec![];
+                        let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                        expression.push("INSERT INTO attachments ");
+                        expression.push("(ticket_id, name, mime_type, size, created_at, data) ");
+                        expression.push("VALUES (?, ?, ?, ?, ?, ?); ");
+
+                        parameters.push(Value::Integer(attachment.ticket_id));
+                        parameters.push(Value::Text(attachment.name.clone()));
+                        parameters.push(Value::Text(attachment.mime_type.clone()));
+                        parameters.push(Value::Integer(data.len() as i64));
+                        parameters.push(Value::Integer(attachment.created_at));
+                        parameters.push(Value::Blob(data.to_vec()));
+
+                        let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                        match stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                            Ok(_) => Ok(()),
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::AttachmentWrite).with_entity(format!("Attachment \"{}\"", attachment.name)).with_source(err))
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::AttachmentWrite).with_entity(format!("Attachment \"{}\"", attachment.name)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::AttachmentWrite).with_entity(format!("Attachment \"{}\"", attachment.name)).with_reason(e))
+            }
+        }
+    }
+
+    fn attachment_drop(&self, attachment: &Attachment) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        //Delete attachment
                         {
                             let mut expression: Vec<&str> = vec![];
                             let mut parameters: Vec<rusqlite::types::Value> = vec![];
 
-                            expression.push("DELETE FROM ticket_tags WHERE ticket_id = ?; ");
-                            parameters.push(Value::Integer(ticket.id));
+                            expression.push("DELETE FROM attachments WHERE id = ?; ");
+                            parameters.push(Value::Integer(attachment.id));
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete tag refs operation! Reason: {}", err);
-                                return Err(AdapterError::new(AdapterErrorType::TicketDelete));
+                                return Err(AdapterError::new(AdapterErrorType::AttachmentDelete).with_entity(format!("Attachment \"{}\"", attachment.name)).with_source(err));
                             };
                         }
 
-                        //Delete ticket itself
+                        Ok(())
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::AttachmentDelete).with_entity(format!("Attachment \"{}\"", attachment.name)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::AttachmentDelete).with_entity(format!("Attachment \"{}\"", attachment.name)).with_reason(e))
+            }
+        }
+    }
+
+    fn attachment_read(&self, attachment: &Attachment) -> Option<Vec<u8>> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let mut stmt_select = lock.prepare("SELECT data FROM attachments WHERE id = :id;").unwrap();
+
+                        match stmt_select.query_row(rusqlite::named_params! {
+                            ":id": attachment.id
+                        }, |row| row.get::<_, Vec<u8>>(0)) {
+                            Ok(data) => Some(data),
+                            Err(e) => {
+                                println!("Wasn't able to read Attachment on local, {}", e);
+                                None
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        println!("Wasn't able to lock for reading Attachment on local, {}", e);
+                        None
+                    }
+                }
+            },
+            Err(e) => {
+                println!("Wasn't able to lock Database, {}", e);
+                None
+            }
+        }
+    }
+
+    fn work_log_list(&self, ticket: &Ticket) -> Vec<WorkLog> {
+        let mut work_logs: Vec<WorkLog> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT id, ticket_id, user, note, duration, logged_at FROM work_logs ",
+                            "WHERE work_logs.ticket_id = :id ",
+                            "ORDER BY logged_at, id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":id": ticket.id
+                        }, |row| {
+                            Ok(WorkLog {
+                                adapter: self.get_name(),
+                                id: row.get(0).unwrap(),
+                                ticket_id: row.get(1).unwrap(),
+                                user: row.get(2).unwrap(),
+                                note: row.get::<_, Option<String>>(3).unwrap().unwrap_or_default(),
+                                duration: row.get(4).unwrap(),
+                                logged_at: row.get(5).unwrap()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let work_log = row.unwrap();
+                            work_logs.push(work_log)
+                        };
+
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Work Logs on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+        work_logs
+    }
+
+    fn work_log_list_range(&self, from: i64, to: i64) -> Vec<WorkLog> {
+        let mut work_logs: Vec<WorkLog> = Vec::new();
+
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+                        let expression = [
+                            "SELECT id, ticket_id, user, note, duration, logged_at FROM work_logs ",
+                            "WHERE work_logs.logged_at >= :from AND work_logs.logged_at < :to ",
+                            "ORDER BY logged_at, id;"
+                        ].join("");
+                        let mut stmt_select = lock.prepare(expression.as_str()).unwrap();
+
+                        let iter = stmt_select.query_map(rusqlite::named_params! {
+                            ":from": from,
+                            ":to": to
+                        }, |row| {
+                            Ok(WorkLog {
+                                adapter: self.get_name(),
+                                id: row.get(0).unwrap(),
+                                ticket_id: row.get(1).unwrap(),
+                                user: row.get(2).unwrap(),
+                                note: row.get::<_, Option<String>>(3).unwrap().unwrap_or_default(),
+                                duration: row.get(4).unwrap(),
+                                logged_at: row.get(5).unwrap()
+                            })
+                        }).unwrap();
+
+                        for row in iter {
+                            let work_log = row.unwrap();
+                            work_logs.push(work_log)
+                        };
+
+                    },
+                    Err(e) => println!("Wasn't able to lock for listing Work Logs on local, {}", e)
+                }
+            },
+            Err(e) => println!("Wasn't able to lock Database, {}", e)
+        }
+        work_logs
+    }
+
+    fn work_log_write(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        let mut expression: Vec<&str> = vec![];
+                        let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+                        // Replace Work Log (or add with new id, if id is 0)
+                        if work_log.id != 0 {
+                            expression.push("REPLACE INTO work_logs ");
+                            expression.push("(id, ticket_id, user, note, duration, logged_at) ");
+                            expression.push("VALUES (?, ?, ?, ?, ?, ?); ");
+                            parameters.push(Value::Integer(work_log.id));
+                        } else {
+                            expression.push("INSERT INTO work_logs ");
+                            expression.push("(ticket_id, user, note, duration, logged_at) ");
+                            expression.push("VALUES (?, ?, ?, ?, ?); ");
+                        }
+
+                        parameters.push(Value::Integer(work_log.ticket_id));
+                        parameters.push(Value::Text(work_log.user.clone()));
+                        parameters.push(Value::Text(work_log.note.clone()));
+                        parameters.push(Value::Integer(work_log.duration));
+                        parameters.push(Value::Integer(work_log.logged_at));
+
+                        let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
+                        match stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                            Ok(_) => Ok(()),
+                            Err(err) => {
+                                Err(AdapterError::new(AdapterErrorType::WorkLogWrite).with_entity(format!("Work Log {}", work_log.id)).with_source(err))
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        Err(AdapterError::new(AdapterErrorType::WorkLogWrite).with_entity(format!("Work Log {}", work_log.id)).with_reason(e))
+                    }
+                }
+            },
+            Err(e) => {
+                Err(AdapterError::new(AdapterErrorType::WorkLogWrite).with_entity(format!("Work Log {}", work_log.id)).with_reason(e))
+            }
+        }
+    }
+
+    fn work_log_drop(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => {
+
+                        //Delete work log
                         {
                             let mut expression: Vec<&str> = vec![];
                             let mut parameters: Vec<rusqlite::types::Value> = vec![];
 
-                            expression.push("DELETE FROM tickets WHERE id = ?; ");
-                            parameters.push(Value::Integer(ticket.id));
+                            expression.push("DELETE FROM work_logs WHERE id = ?; ");
+                            parameters.push(Value::Integer(work_log.id));
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete ticket operation! Reason: {}", err);
-                                return Err(AdapterError::new(AdapterErrorType::TicketDelete));
+                                return Err(AdapterError::new(AdapterErrorType::WorkLogDelete).with_entity(format!("Work Log {}", work_log.id)).with_source(err));
                             };
                         }
 
                         Ok(())
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for deleting Ticket on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::TicketDelete))
+                        Err(AdapterError::new(AdapterErrorType::WorkLogDelete).with_entity(format!("Work Log {}", work_log.id)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for deleting Ticket on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::TicketDelete))
+                Err(AdapterError::new(AdapterErrorType::WorkLogDelete).with_entity(format!("Work Log {}", work_log.id)).with_reason(e))
             }
         }
     }
@@ -971,22 +1659,19 @@ impl TicketAdapter for LocalTicketAdapter {
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete Filter operation! Reason: {}", err);
-                                return Err(AdapterError::new(AdapterErrorType::FilterDelete));
+                                return Err(AdapterError::new(AdapterErrorType::FilterDelete).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_source(err));
                             };
                         }
 
                         Ok(())
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for deleting Filter on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::FilterDelete))
+                        Err(AdapterError::new(AdapterErrorType::FilterDelete).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for deleting Filter on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::FilterDelete))
+                Err(AdapterError::new(AdapterErrorType::FilterDelete).with_entity(format!("Filter \"{}\"", filter.identifier.name)).with_reason(e))
             }
         }
     }
@@ -1007,22 +1692,19 @@ impl TicketAdapter for LocalTicketAdapter {
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete Tag operation! Reason: {}", err);
-                                return Err(AdapterError::new(AdapterErrorType::TagDelete));
+                                return Err(AdapterError::new(AdapterErrorType::TagDelete).with_entity(format!("Tag \"{}\"", tag.name)).with_source(err));
                             };
                         }
 
                         Ok(())
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for deleting Tag on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::TagDelete))
+                        Err(AdapterError::new(AdapterErrorType::TagDelete).with_entity(format!("Tag \"{}\"", tag.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for deleting Tag on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::TagDelete))
+                Err(AdapterError::new(AdapterErrorType::TagDelete).with_entity(format!("Tag \"{}\"", tag.name)).with_reason(e))
             }
         }
     }
@@ -1043,22 +1725,19 @@ impl TicketAdapter for LocalTicketAdapter {
 
                             let mut stmt_write = lock.prepare(expression.join("").as_str()).unwrap();
                             if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
-                                println!("There was an error executing delete Bucket operation! Reason: {}", err);
-                                return Err(AdapterError::new(AdapterErrorType::BucketDelete));
+                                return Err(AdapterError::new(AdapterErrorType::BucketDelete).with_entity(format!("Bucket \"{}\"", bucket.name)).with_source(err));
                             };
                         }
 
                         Ok(())
                     },
                     Err(e) => {
-                        println!("Wasn't able to lock Connection for deleting Bucket on local, {}", e);
-                        Err(AdapterError::new(AdapterErrorType::BucketDelete))
+                        Err(AdapterError::new(AdapterErrorType::BucketDelete).with_entity(format!("Bucket \"{}\"", bucket.name)).with_reason(e))
                     }
                 }
             },
             Err(e) => {
-                println!("Wasn't able to lock Database for deleting Bucket on local, {}", e);
-                Err(AdapterError::new(AdapterErrorType::BucketDelete))
+                Err(AdapterError::new(AdapterErrorType::BucketDelete).with_entity(format!("Bucket \"{}\"", bucket.name)).with_reason(e))
             }
         }
     }
@@ -0,0 +1,338 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use rusqlite::{Connection, types::Value};
+    use tickets_rs_core::{AdapterError, AdapterErrorType, AppConfig, ChecklistItem, EventBus, FilterQuery, LocalDatabase, Priority, Ticket, TicketAdapter, TicketPageRequest};
+
+    use crate::local_ticket_adapter::{interpreter::AdapterInterpreter, LocalTicketAdapter};
+
+    /**
+       Creates a local adapter on an empty in memory database
+     */
+    fn memory_adapter() -> Box<dyn TicketAdapter + Send + Sync> {
+        let database = Arc::new(Mutex::new(LocalDatabase::open(":memory:".to_string()).unwrap()));
+        let config = LocalTicketAdapter::create_config()
+            .with("database", ":memory:", "string")
+            .with("include_default_data", false, "bool");
+
+        LocalTicketAdapter::from_config(
+            Arc::new(Mutex::new(AppConfig::new(database))), 
+            &config, 
+            EventBus::default()).unwrap()
+    }
+
+    #[test]
+    fn test_ticket_write_many() {
+        let adapter = memory_adapter();
+
+        let tickets = vec![
+            Ticket::default().with_details(0, "first".to_string(), String::default()),
+            Ticket::default().with_details(0, "second".to_string(), String::default())
+        ];
+
+        // New tickets come back with the id, they got assigned
+        let results = adapter.ticket_write_many(&tickets);
+        assert!(results.iter().all(|result| result.as_ref().is_ok_and(|ticket| ticket.id != 0)));
+
+        let written = adapter.ticket_list_all();
+        assert_eq!(written.len(), 2);
+
+        assert!(adapter.ticket_drop_many(&written).iter().all(Result::is_ok));
+        assert!(adapter.ticket_list_all().is_empty());
+
+        // A single failed ticket rolls back the batch, so every ticket reports an error
+        let mut connection = Connection::open_in_memory().unwrap();
+        connection.execute("CREATE TABLE numbers (value INTEGER);", []).unwrap();
+
+        let transaction = connection.transaction().unwrap();
+        transaction.execute("INSERT INTO numbers VALUES (1);", []).unwrap();
+
+        let results = LocalTicketAdapter::finish_transaction(
+            transaction, 
+            &tickets, 
+            vec![Ok(()), Err(AdapterError::new(AdapterErrorType::TicketWrite))], 
+            AdapterErrorType::TicketWrite);
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(Result::is_err));
+        assert_eq!(connection.query_row("SELECT COUNT(*) FROM numbers;", [], |row| row.get::<_, i64>(0)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_ticket_list_page() {
+        let adapter = memory_adapter();
+
+        let tickets: Vec<Ticket> = (0..5).map(|index| Ticket::default()
+            .with_details(0, format!("page ticket {}", index), String::default())
+            .with_priority(if index == 3 { Priority::Critical } else { Priority::Low }))
+            .collect();
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        let first = adapter.ticket_list_all_page(TicketPageRequest::first(2));
+        assert_eq!(first.total, 5);
+        assert_eq!(first.tickets.len(), 2);
+        assert_eq!(first.tickets[0].title, "page ticket 3");
+        assert_eq!(first.next_request(), Some(TicketPageRequest::new(2, 2)));
+
+        let last = adapter.ticket_list_page("title_contains(ticket)", TicketPageRequest::new(4, 2)).unwrap();
+        assert_eq!(last.total, 5);
+        assert_eq!(last.tickets.len(), 1);
+        assert!(!last.has_more());
+
+        // Paged and unpaged listings return the tickets in the same order
+        let titles = |tickets: Vec<Ticket>| tickets.into_iter().map(|ticket| ticket.title).collect::<Vec<String>>();
+        let paged = titles(adapter.ticket_list_page("title_contains(ticket)", TicketPageRequest::first(5)).unwrap().tickets);
+        assert_eq!(paged[0], "page ticket 3");
+        assert_eq!(titles(adapter.ticket_list("title_contains(ticket)").unwrap()), paged);
+        assert_eq!(titles(adapter.ticket_list_all()), paged);
+    }
+
+    #[test]
+    fn test_ticket_list_query() {
+        let adapter = memory_adapter();
+
+        let ticket = |title: &str, tags: &[&str]| Ticket {
+            title: title.to_string(),
+            state_name: "open".to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+
+        let tickets = vec![
+            ticket("crash on login", &["bug", "urgent"]),
+            ticket("crash on logout", &["bug"]),
+            ticket("it's a feature", &["feature"])
+        ];
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        let query: FilterQuery = "with_state(open) with_tag(bug) with_tag(urgent)".parse().unwrap();
+        let found = adapter.ticket_list_query(&query).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title, "crash on login");
+
+        // Quotes in values are escaped instead of ending the sql string
+        let query: FilterQuery = "text_contains(it's)".parse().unwrap();
+        assert_eq!(adapter.ticket_list_query(&query).unwrap().len(), 1);
+
+        let page = adapter.ticket_list_query_page(&"title_contains(crash)".parse().unwrap(), TicketPageRequest::first(1)).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.tickets.len(), 1);
+
+        // Due dates are set in milliseconds by the ui, like 2024-03-15 at noon
+        let mut ticket = adapter.ticket_list_unique(1).unwrap();
+        ticket.due_at = 1710504000000;
+        assert!(adapter.ticket_write(&ticket).is_ok());
+
+        let query: FilterQuery = "due_after(2024-03-01) due_before(2024-04-01)".parse().unwrap();
+        let found = adapter.ticket_list_query(&query).unwrap();
+        assert_eq!(found.iter().map(|ticket| ticket.id).collect::<Vec<i64>>(), vec![1]);
+        assert!(adapter.ticket_list_query(&"due_before(2024-03-15) title_contains(login)".parse().unwrap()).unwrap().is_empty());
+        assert!(adapter.ticket_list_query(&"due_in_days(0) title_contains(login)".parse().unwrap()).unwrap().len() == 1);
+    }
+
+    #[test]
+    fn test_ticket_list_not_or() {
+        let adapter = memory_adapter();
+
+        let ticket = |title: &str, tags: &[&str]| Ticket {
+            title: title.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+
+        let tickets = vec![
+            ticket("crash", &["bug"]),
+            ticket("typo", &["bug", "wontfix"]),
+            ticket("dark mode", &["feature"])
+        ];
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        let found = adapter.ticket_list("with_tag(bug) not(with_tag(wontfix))").unwrap();
+        assert_eq!(found.iter().map(|ticket| ticket.title.as_str()).collect::<Vec<&str>>(), vec!["crash"]);
+
+        let mut found = adapter.ticket_list("or(with_tag(feature) (with_tag(bug) title_contains(typo)))").unwrap();
+        found.sort_by_key(|ticket| ticket.id);
+        assert_eq!(found.iter().map(|ticket| ticket.title.as_str()).collect::<Vec<&str>>(), vec!["typo", "dark mode"]);
+    }
+
+    #[test]
+    fn test_ticket_list_hostile_input() {
+        let adapter = memory_adapter();
+
+        let ticket = |title: &str, tags: &[&str]| Ticket {
+            title: title.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+
+        let tickets = vec![
+            ticket("it's broken", &["bug"]),
+            ticket("quoted", &["x' OR '1'='1"])
+        ];
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        // Quotes are part of the value, instead of ending the string in the sql
+        let found = adapter.ticket_list("title_contains(it's)").unwrap();
+        assert_eq!(found.iter().map(|ticket| ticket.title.as_str()).collect::<Vec<&str>>(), vec!["it's broken"]);
+
+        let found = adapter.ticket_list("with_tag(x' OR '1'='1)").unwrap();
+        assert_eq!(found.iter().map(|ticket| ticket.title.as_str()).collect::<Vec<&str>>(), vec!["quoted"]);
+
+        assert!(adapter.ticket_list("with_state(open'; DROP TABLE tickets; --)").unwrap().is_empty());
+        assert!(adapter.ticket_list_query(&"with_tag(bug'--) title_contains(%'||')".parse::<FilterQuery>().unwrap()).unwrap().is_empty());
+        assert_eq!(adapter.ticket_list_all().len(), 2);
+
+        // The values end up as parameters, not in the sql itself
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        let _ = interpreter.try_tokenize("with_state(open'; DROP TABLE tickets; --)".to_string());
+        let mut sql_expression = interpreter.construct_sql_expression().unwrap();
+        assert!(!sql_expression.get_final().contains("DROP"));
+        assert_eq!(sql_expression.get_parameters(), &[Value::Text("open'; DROP TABLE tickets; --".to_string())]);
+    }
+
+    #[test]
+    fn test_ticket_list_date_and_metadata() {
+        let adapter = memory_adapter();
+
+        // The creation date is in seconds, the due date in milliseconds like the ui sets it, at noon of the same day
+        let ticket = |title: &str, created_at: i64, assigned_to: &str, tags: &[&str]| Ticket {
+            title: title.to_string(),
+            created_at,
+            due_at: (created_at + 12 * 3600) * 1000,
+            state_name: "open".to_string(),
+            assigned_to: assigned_to.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+
+        // Created and due on the 1st of january, february and march 2024, and far in the future
+        let tickets = vec![
+            ticket("january", 1704067200, "biochemist", &["bug"]),
+            ticket("february", 1706745600, "", &[]),
+            ticket("march", 1709251200, "biochemist", &[]),
+            ticket("future", 4102444800, "", &["feature"])
+        ];
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        let titles = |expression: &str| {
+            let mut found = adapter.ticket_list(expression).unwrap();
+            found.sort_by_key(|ticket| ticket.id);
+            found.into_iter().map(|ticket| ticket.title).collect::<Vec<String>>()
+        };
+
+        assert_eq!(titles("created_after(2024-02-01) created_before(2024-03-01)"), vec!["february"]);
+        assert_eq!(titles("due_between(2024-01-01, 2024-03-01)"), vec!["january", "february"]);
+        assert_eq!(titles("overdue()"), vec!["january", "february", "march"]);
+        assert_eq!(titles("unassigned()"), vec!["february", "future"]);
+        assert_eq!(titles("has_no_tags()"), vec!["february", "march"]);
+        assert_eq!(titles("id_in(1, 4)"), vec!["january", "future"]);
+        assert_eq!(titles("overdue() limit(2)"), vec!["january", "february"]);
+        assert_eq!(titles("with_tag(bug) ;; with_tag(feature) limit(1)"), vec!["january"]);
+
+        // Both compare the due date in milliseconds, so they agree on the same tickets
+        assert_eq!(titles("due_in_days(0) overdue()"), vec!["january", "february", "march"]);
+        assert_eq!(titles("not(due_in_days(0)) overdue()"), Vec::<String>::new());
+
+        // Tickets in the done state aren't overdue anymore
+        let mut march = adapter.ticket_list_unique(3).unwrap();
+        march.state_name = "done".to_string();
+        assert!(adapter.ticket_write(&march).is_ok());
+        assert_eq!(titles("due_in_days(0) overdue()"), vec!["january", "february"]);
+        assert_eq!(titles("due_in_days(0) not(overdue())"), vec!["march"]);
+    }
+
+    #[test]
+    fn test_ticket_search() {
+        let adapter = memory_adapter();
+
+        let ticket = |title: &str, description: &str| Ticket {
+            title: title.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        };
+
+        let tickets = vec![
+            ticket("Login page crashes", "on submit"),
+            ticket("Dark mode", "the login button is too bright"),
+            ticket("Typo", "")
+        ];
+        assert!(adapter.ticket_write_many(&tickets).iter().all(Result::is_ok));
+
+        let titles = |expression: &str| {
+            let mut found = adapter.ticket_list(expression).unwrap();
+            found.sort_by_key(|ticket| ticket.id);
+            found.into_iter().map(|ticket| ticket.title).collect::<Vec<String>>()
+        };
+
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        let _ = interpreter.try_tokenize("matches(\"login page\")".to_string());
+        assert_eq!(interpreter.to_string(), "matches(\"login page\")");
+        assert_eq!(interpreter.construct_sql().unwrap(), 
+            "SELECT tickets.* FROM tickets WHERE tickets.id IN (SELECT rowid FROM tickets_fts WHERE tickets_fts MATCH ?1);");
+
+        assert_eq!(titles("matches(login)"), vec!["Login page crashes", "Dark mode"]);
+        assert_eq!(titles("matches(\"login page\")"), vec!["Login page crashes"]);
+        assert_eq!(titles("matches(crash*) ;; matches(typo)"), vec!["Login page crashes", "Typo"]);
+        assert!(adapter.ticket_list("matches(\"unfinished)").is_err());
+
+        // The search is ranked, the best hit comes first and all scores are between 0 and 1
+        let search = |text: &str| adapter.ticket_search(text).unwrap().into_iter()
+            .map(|hit| (hit.ticket.title, hit.score))
+            .collect::<Vec<(String, f64)>>();
+
+        let hits = search("login OR crashes");
+        assert_eq!(hits.iter().map(|hit| hit.0.as_str()).collect::<Vec<&str>>(), vec!["Login page crashes", "Dark mode"]);
+        assert!(hits[0].1 < 1.0);
+        assert!(hits[1].1 > 0.0 && hits[1].1 < hits[0].1);
+
+        // Words, phrases and prefixes are searched like in matches(...)
+        assert_eq!(search("login crashes").len(), 1);
+        assert_eq!(search("\"login button\"")[0].0, "Dark mode");
+        assert_eq!(search("crash*")[0].0, "Login page crashes");
+        assert!(search("!?").is_empty());
+        assert!(adapter.ticket_search("\"unfinished").is_err());
+
+        // Rewritten and deleted tickets are updated in the index
+        let mut dark_mode = adapter.ticket_list_unique(2).unwrap();
+        dark_mode.description = "too bright".to_string();
+        assert!(adapter.ticket_write(&dark_mode).is_ok());
+        assert_eq!(titles("matches(login)"), vec!["Login page crashes"]);
+        assert_eq!(titles("matches(bright)"), vec!["Dark mode"]);
+
+        assert!(adapter.ticket_drop(&adapter.ticket_list_unique(1).unwrap()).is_ok());
+        assert!(titles("matches(login)").is_empty());
+    }
+
+    #[test]
+    fn test_ticket_history() {
+        let adapter = memory_adapter();
+
+        let mut ticket = adapter.ticket_write_many(&[Ticket::default().with_details(0, "first".to_string(), String::default())])
+            .remove(0)
+            .unwrap();
+
+        ticket.priority = Priority::High;
+        ticket.estimate = 3600;
+        ticket.custom_fields.insert("team".to_string(), "core".to_string());
+        ticket.checklist.push(ChecklistItem::new("review".to_string(), false));
+        assert!(adapter.ticket_write(&ticket).is_ok());
+
+        let history = adapter.ticket_history(ticket.id);
+        assert_eq!(history.iter().map(|entry| entry.field.as_str()).collect::<Vec<&str>>(),
+            vec!["ticket", "priority", "estimate", "custom:team", "checklist"]);
+        assert_eq!(history[4].new_value, "[ ] review");
+
+        // Renaming and checking an item is one change of that item
+        ticket.checklist[0] = ChecklistItem::new("code review".to_string(), true);
+        assert!(adapter.ticket_write(&ticket).is_ok());
+
+        let history = adapter.ticket_history(ticket.id);
+        assert_eq!(history.len(), 6);
+        assert_eq!(history[5].field, "checklist");
+        assert_eq!(history[5].old_value, "[ ] review");
+        assert_eq!(history[5].new_value, "[x] code review");
+
+        assert!(adapter.ticket_drop(&ticket).is_ok());
+        assert_eq!(adapter.ticket_history(ticket.id).len(), 7);
+    }
+}
@@ -4,7 +4,9 @@ use std::fmt::{
 };
 use std::sync::{Mutex, Arc};
 
-use tickets_rs_core::AppConfig;
+use rusqlite::types::Value;
+
+use tickets_rs_core::{AppConfig, TicketPageRequest};
 
 pub use super::interpreter_errors::{
     TokenizationError, 
@@ -17,10 +19,24 @@ use super::interpreter_instructions::{
     VerifiableInstruction
 };
 
+/**
+   The order of the listed tickets, which is the same as in the ticket
+   provider. Paged and unpaged listings both use it, so a filter returns
+   it's tickets in the same order either way
+ */
+pub const TICKET_ORDER: &str = " ORDER BY priority DESC, id";
+
+/**
+   Collects the sql of a filter while it is being parsed. Values from the
+   filter are never written into the sql itself, instead they are bound
+   as numbered parameters, which are kept next to the sql text
+ */
 pub struct SqlExpression {
     finished_expression: String,
     from_expression: Vec<String>,
-    where_expression: Vec<String>
+    where_expression: Vec<String>,
+    parameters: Vec<Value>,
+    limit: Option<String>
 }
 
 impl Default for SqlExpression {
@@ -29,6 +45,8 @@ impl Default for SqlExpression {
             finished_expression: "".to_string(), 
             from_expression: vec![], 
             where_expression: vec![], 
+            parameters: vec![],
+            limit: None
         }
     }
 }
@@ -46,6 +64,43 @@ impl SqlExpression {
         self.where_expression.push(expression);
     }
 
+    /**
+       Binds a value as parameter and returns it's placeholder, which
+       is used in place of the value within the sql
+     */
+    pub fn bind<T: Into<Value>>(&mut self, value: T) -> String {
+        self.parameters.push(value.into());
+        ["?", self.parameters.len().to_string().as_str()].join("")
+    }
+
+    /**
+       Returns the values, that have to be bound to the finished expression
+     */
+    pub fn get_parameters(&self) -> &[Value] {
+        &self.parameters
+    }
+
+    /**
+       Limits the whole expression to the given amount of tickets, even
+       across multiple groups joined by ;;. It's applied with apply_limit,
+       after the last group has been flushed
+     */
+    pub fn set_limit(&mut self, limit: i64) {
+        self.limit = Some(self.bind(limit));
+    }
+
+    /**
+       Orders the finished expression like the ticket provider does and
+       cuts it off after the limit, if there is one
+     */
+    pub fn apply_limit(&mut self) {
+        if let Some(limit) = self.limit.take() {
+            self.finished_expression += TICKET_ORDER;
+            self.finished_expression += " LIMIT ";
+            self.finished_expression += limit.as_str();
+        }
+    }
+
     pub fn _add_directly(&mut self, expression: String) {
         self.finished_expression += expression.as_str();
     }
@@ -57,6 +112,19 @@ impl SqlExpression {
             false => self.finished_expression += " UNION SELECT tickets.* FROM ",
         }
 
+        self.finished_expression += self.join_expression().as_str();
+
+        if !self.where_expression.is_empty() {
+            self.finished_expression += " WHERE ";
+            self.finished_expression += self.where_expression.join(" AND ").as_str();
+        }
+        
+        self.where_expression.clear();
+        self.from_expression.clear();
+
+    }
+
+    fn join_expression(&self) -> String {
         let mut join_expression = "tickets".to_string();
 
         for from in self.from_expression.as_slice() {
@@ -68,16 +136,51 @@ impl SqlExpression {
             ].join("")
         }
 
-        self.finished_expression += join_expression.as_str();
+        join_expression
+    }
 
-        if !self.where_expression.is_empty() {
-            self.finished_expression += " WHERE ";
-            self.finished_expression += self.where_expression.join(" AND ").as_str();
+    /**
+       Starts an empty expression for a nested group, like the content of
+       not(...). It continues the numbering of the bound parameters
+     */
+    pub fn begin_nested(&mut self) -> SqlExpression {
+        SqlExpression {
+            parameters: std::mem::take(&mut self.parameters),
+            ..Default::default()
         }
-        
+    }
+
+    /**
+       Ends a nested group, that has been started with begin_nested, takes
+       back it's bound parameters and returns it's joins and conditions as
+       a single condition. If there are joins, the condition becomes a
+       subquery on the ticket ids, so the joined rows can't leak into a
+       surrounding not(...) or or(...)
+     */
+    pub fn end_nested(&mut self, mut nested: SqlExpression) -> String {
+        let condition = nested.take_condition();
+        self.parameters = nested.parameters;
+        condition
+    }
+
+    fn take_condition(&mut self) -> String {
+        let condition = self.where_expression.join(" AND ");
+
+        let condition = match self.from_expression.is_empty() {
+            true => condition,
+            false => [
+                "tickets.id IN (SELECT tickets.id FROM ",
+                self.join_expression().as_str(),
+                " WHERE ",
+                condition.as_str(),
+                ")"
+            ].join(""),
+        };
+
         self.where_expression.clear();
         self.from_expression.clear();
 
+        condition
     }
 
     pub fn is_buffer_empty(&self) -> bool {
@@ -88,10 +191,66 @@ impl SqlExpression {
         self.from_expression.is_empty() && self.where_expression.is_empty()
     }
 
+    /**
+       Returns the finished expression as it is, without it's order. It's
+       only used to compare the generated sql, the tickets are listed with
+       get_ordered or get_page
+     */
+    #[cfg(test)]
     pub fn get_final(&mut self) -> &String {
         self.finished_expression.push(';');
         &self.finished_expression
     }
+
+    /**
+       Returns the finished expression with the tickets ordered like in
+       the ticket provider
+     */
+    pub fn get_ordered(&self) -> String {
+        ["SELECT * FROM (", self.finished_expression.as_str(), ")", TICKET_ORDER, ";"].join("")
+    }
+
+    /**
+       Returns the finished expression, limited to the requested page. The
+       tickets are ordered like in the ticket provider, so pages are stable.
+       The limit and offset are bound after all other parameters, see
+       get_page_parameters
+     */
+    pub fn get_page(&self) -> String {
+        format!("SELECT * FROM ({}){} LIMIT ?{} OFFSET ?{};",
+            self.finished_expression,
+            TICKET_ORDER,
+            self.parameters.len() + 1,
+            self.parameters.len() + 2)
+    }
+
+    /**
+       Returns the parameters, that need to be bound to the expression
+       from get_page
+     */
+    pub fn get_page_parameters(&self, page: TicketPageRequest) -> Vec<Value> {
+        let mut parameters = self.parameters.clone();
+        parameters.extend(SqlExpression::page_params(page).map(Value::Integer));
+        parameters
+    }
+
+    /**
+       Returns an expression, that counts all tickets of the finished expression
+     */
+    pub fn get_count(&self) -> String {
+        format!("SELECT COUNT(*) FROM ({});", self.finished_expression)
+    }
+
+    /**
+       Returns the limit and offset of a page, like they need to be bound
+       to a paged select
+     */
+    pub fn page_params(page: TicketPageRequest) -> [i64; 2] {
+        [
+            i64::try_from(page.limit).unwrap_or(i64::MAX),
+            i64::try_from(page.offset).unwrap_or(i64::MAX)
+        ]
+    }
 }
 
 pub trait SqlParsable: Sized {
@@ -104,7 +263,14 @@ pub struct AdapterInterpreter {
     last_error: Option<TokenizationError>,
     pub can_have_title_contains: bool,
     pub can_have_descr_contains: bool,
-    pub can_have_due_in_days: bool
+    pub can_have_due_in_days: bool,
+    pub can_have_due_between: bool,
+    pub can_have_created_after: bool,
+    pub can_have_created_before: bool,
+    pub can_have_overdue: bool,
+    pub can_have_unassigned: bool,
+    pub can_have_no_tags: bool,
+    pub can_have_limit: bool
 }
 
 impl AdapterInterpreter {
@@ -121,6 +287,39 @@ impl AdapterInterpreter {
         self.variables.insert(String::from(variable_name), String::from(value));
     }
 
+    /**
+       Runs the tokenization of a nested group, like the content of not(...),
+       with it's own uniqueness rules. This way each group can have for
+       example it's own title_contains. Afterwards the rules of the
+       surrounding group are restored
+     */
+    pub fn with_fresh_uniqueness<T, F: FnOnce(&mut Self) -> T>(&mut self, tokenize: F) -> T {
+        let saved = self.uniqueness().map(|unique| std::mem::replace(unique, true));
+
+        let result = tokenize(self);
+
+        for (unique, saved) in self.uniqueness().into_iter().zip(saved) {
+            *unique = saved;
+        }
+
+        result
+    }
+
+    fn uniqueness(&mut self) -> [&mut bool; 10] {
+        [
+            &mut self.can_have_title_contains,
+            &mut self.can_have_descr_contains,
+            &mut self.can_have_due_in_days,
+            &mut self.can_have_due_between,
+            &mut self.can_have_created_after,
+            &mut self.can_have_created_before,
+            &mut self.can_have_overdue,
+            &mut self.can_have_unassigned,
+            &mut self.can_have_no_tags,
+            &mut self.can_have_limit
+        ]
+    }
+
     #[cfg(test)]
     pub fn get_last_error(&self) -> Option<TokenizationError>{
         self.last_error.clone()
@@ -163,7 +362,20 @@ impl AdapterInterpreter {
         Ok(())
     }
 
+    /**
+       Returns only the sql text without it's parameters, so it can't be
+       executed on it's own anymore, but the generated sql can be compared
+     */
+    #[cfg(test)]
     pub fn construct_sql(&mut self) -> Result<String, SqlParseError> {
+        self.construct_sql_expression().map(|mut expression| expression.get_final().clone())
+    }
+
+    /**
+       Same as construct_sql, but returns the flushed expression itself, so
+       it can still be wrapped, for example into a paged or counting query
+     */
+    pub fn construct_sql_expression(&mut self) -> Result<SqlExpression, SqlParseError> {
         if self.last_error.is_some() {
             return Err(SqlParseError::new("Cannot parse Instructions, because there was an Error when Tokenizing."));
         };
@@ -190,7 +402,8 @@ impl AdapterInterpreter {
         };
 
         expression.flush();
-        Ok(expression.get_final().clone())
+        expression.apply_limit();
+        Ok(expression)
     }
 }
 
@@ -202,6 +415,13 @@ impl Default for AdapterInterpreter {
             can_have_title_contains: true, 
             can_have_descr_contains: true, 
             can_have_due_in_days: true,
+            can_have_due_between: true,
+            can_have_created_after: true,
+            can_have_created_before: true,
+            can_have_overdue: true,
+            can_have_unassigned: true,
+            can_have_no_tags: true,
+            can_have_limit: true,
             last_error: None
         }
     }
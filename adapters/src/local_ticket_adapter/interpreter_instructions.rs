@@ -1,3 +1,4 @@
+use tickets_rs_core::{FilterCondition, Priority};
 
 use super::interpreter::{
     AdapterInterpreter, 
@@ -32,6 +33,23 @@ pub enum Instruction {
     DescriptionContains(DescriptionContainsInstruction),
     AssignedTo(AssignedToInstruction),
     DueInDays(DueInDaysInstruction),
+    WithField(WithFieldInstruction),
+    BlockedBy(BlockedByInstruction),
+    HasOpenBlockers(HasOpenBlockersInstruction),
+    WithPriority(WithPriorityInstruction),
+    PriorityAtLeast(PriorityAtLeastInstruction),
+    DueBetween(DueBetweenInstruction),
+    CreatedAfter(CreatedAfterInstruction),
+    CreatedBefore(CreatedBeforeInstruction),
+    Overdue(OverdueInstruction),
+    Unassigned(UnassignedInstruction),
+    HasNoTags(HasNoTagsInstruction),
+    IdIn(IdInInstruction),
+    Matches(MatchesInstruction),
+    Limit(LimitInstruction),
+    Not(NotInstruction),
+    Or(OrInstruction),
+    Group(GroupInstruction),
     Join(JoinInstruction)
 }
 
@@ -45,6 +63,23 @@ impl VerifiableInstruction for Instruction {
             Instruction::DescriptionContains(instr) => instr.is_valid_after(interpreter, instruction),
             Instruction::AssignedTo(instr) => instr.is_valid_after(interpreter, instruction),
             Instruction::DueInDays(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::WithField(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::BlockedBy(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::HasOpenBlockers(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::WithPriority(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::PriorityAtLeast(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::DueBetween(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::CreatedAfter(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::CreatedBefore(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Overdue(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Unassigned(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::HasNoTags(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::IdIn(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Matches(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Limit(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Not(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Or(instr) => instr.is_valid_after(interpreter, instruction),
+            Instruction::Group(instr) => instr.is_valid_after(interpreter, instruction),
             Instruction::Join(instr) => instr.is_valid_after(interpreter, instruction),
         }
     }
@@ -58,6 +93,23 @@ impl VerifiableInstruction for Instruction {
             Instruction::DescriptionContains(instr) => instr.to_string(),
             Instruction::AssignedTo(instr) => instr.to_string(),
             Instruction::DueInDays(instr) => instr.to_string(),
+            Instruction::WithField(instr) => instr.to_string(),
+            Instruction::BlockedBy(instr) => instr.to_string(),
+            Instruction::HasOpenBlockers(instr) => instr.to_string(),
+            Instruction::WithPriority(instr) => instr.to_string(),
+            Instruction::PriorityAtLeast(instr) => instr.to_string(),
+            Instruction::DueBetween(instr) => instr.to_string(),
+            Instruction::CreatedAfter(instr) => instr.to_string(),
+            Instruction::CreatedBefore(instr) => instr.to_string(),
+            Instruction::Overdue(instr) => instr.to_string(),
+            Instruction::Unassigned(instr) => instr.to_string(),
+            Instruction::HasNoTags(instr) => instr.to_string(),
+            Instruction::IdIn(instr) => instr.to_string(),
+            Instruction::Matches(instr) => instr.to_string(),
+            Instruction::Limit(instr) => instr.to_string(),
+            Instruction::Not(instr) => instr.to_string(),
+            Instruction::Or(instr) => instr.to_string(),
+            Instruction::Group(instr) => instr.to_string(),
             Instruction::Join(instr) => instr.to_string(),
         }
     }
@@ -102,6 +154,91 @@ impl VerifiableInstruction for Instruction {
             Err(err) => results.push(Err(err)),
         };
 
+        match WithFieldInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::WithField(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match BlockedByInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::BlockedBy(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match HasOpenBlockersInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::HasOpenBlockers(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match WithPriorityInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::WithPriority(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match PriorityAtLeastInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::PriorityAtLeast(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match DueBetweenInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::DueBetween(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match CreatedAfterInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::CreatedAfter(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match CreatedBeforeInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::CreatedBefore(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match OverdueInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Overdue(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match UnassignedInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Unassigned(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match HasNoTagsInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::HasNoTags(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match IdInInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::IdIn(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match MatchesInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Matches(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match LimitInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Limit(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match NotInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Not(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match OrInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Or(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
+        match GroupInstruction::try_tokenize(interpreter, code.clone()) {
+            Ok(result) => results.push(Ok((Instruction::Group(result.0), result.1))),
+            Err(err) => results.push(Err(err)),
+        };
+
         match JoinInstruction::try_tokenize(interpreter, code) {
             Ok(result) => results.push(Ok((Instruction::Join(result.0), result.1))),
             Err(err) => results.push(Err(err)),
@@ -133,6 +270,23 @@ impl SqlParsable for Instruction {
             Instruction::DescriptionContains(instr) => instr.to_sql(interpreter, sql_expression),
             Instruction::AssignedTo(instr) => instr.to_sql(interpreter, sql_expression),
             Instruction::DueInDays(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::WithField(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::BlockedBy(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::HasOpenBlockers(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::WithPriority(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::PriorityAtLeast(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::DueBetween(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::CreatedAfter(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::CreatedBefore(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Overdue(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Unassigned(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::HasNoTags(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::IdIn(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Matches(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Limit(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Not(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Or(instr) => instr.to_sql(interpreter, sql_expression),
+            Instruction::Group(instr) => instr.to_sql(interpreter, sql_expression),
             Instruction::Join(instr) => instr.to_sql(interpreter, sql_expression),
         }
     }
@@ -230,8 +384,9 @@ impl SqlParsable for WithStateInstruction {
         let state_option = self.state.get_text(interpreter);
 
         if let Some(state) = state_option {
+            let state = sql_expression.bind(state);
             sql_expression.add_to_where(
-                ["tickets.state_name = '", state.as_str(), "'"]
+                ["tickets.state_name = ", state.as_str()]
                 .join("")
             );
             Ok(sql_expression)
@@ -260,8 +415,9 @@ impl SqlParsable for WithTagInstruction {
 
         if let Some(tag) = tag_option {
 
+            let tag = sql_expression.bind(tag);
             sql_expression.add_to_where(
-                ["ticket_tags.tag_name = '", tag.as_str(), "'"]
+                ["ticket_tags.tag_name = ", tag.as_str()]
                 .join("")
             );
 
@@ -293,8 +449,9 @@ impl SqlParsable for InBucketInstruction {
 
         if let Some(bucket) = bucket_option {
 
+            let bucket = sql_expression.bind(bucket);
             sql_expression.add_to_where(
-                ["buckets.name = '", bucket.as_str(), "'"]
+                ["buckets.name = ", bucket.as_str()]
                 .join("")
             );
 
@@ -330,8 +487,9 @@ impl SqlParsable for TitleContainsInstruction {
 
         if let Some(title) = title_option {
 
+            let title = sql_expression.bind(title);
             sql_expression.add_to_where(
-                ["tickets.title LIKE '%", title.as_str(), "%'"]
+                ["tickets.title LIKE '%' || ", title.as_str(), " || '%'"]
                 .join("")
             );
 
@@ -372,8 +530,9 @@ impl SqlParsable for DescriptionContainsInstruction {
 
         if let Some(desc) = desc_option {
 
+            let desc = sql_expression.bind(desc);
             sql_expression.add_to_where(
-                ["tickets.description LIKE '%", desc.as_str(), "%'"]
+                ["tickets.description LIKE '%' || ", desc.as_str(), " || '%'"]
                 .join("")
             );
 
@@ -403,8 +562,8 @@ impl SqlParsable for AssignedToInstruction {
 
         if let Some(users) = users_option {
 
-            let user_comps: Vec<String> = users.iter()
-                 .map(|username| ["tickets.assigned_to = '", username.as_str(), "'"].join(""))
+            let user_comps: Vec<String> = users.into_iter()
+                 .map(|username| ["tickets.assigned_to = ", sql_expression.bind(username).as_str()].join(""))
                  .collect();
             
             sql_expression.add_to_where(
@@ -441,8 +600,9 @@ impl SqlParsable for DueInDaysInstruction {
         let days_options = self.days.get_number(interpreter);
 
         if let Some(days) = days_options {
+            let days = sql_expression.bind(["+", days.to_string().as_str(), " day"].join(""));
             sql_expression.add_to_where(
-                ["tickets.due_at < (SELECT unixepoch('now','start of day','+", days.to_string().as_str() , " day'))"]
+                ["tickets.due_at < (SELECT unixepoch('now','start of day',", days.as_str(), ") * 1000)"]
                 .join("")
             );
             Ok(sql_expression)
@@ -452,6 +612,712 @@ impl SqlParsable for DueInDaysInstruction {
     }
 }
 
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct WithFieldInstruction {
+    pub field: Parameter
+}
+
+impl FunctionTypeInstruction for WithFieldInstruction {
+    fn get_content(&self) -> &Parameter {&self.field}
+    fn get_function_name() -> String {"with_field".to_string()}
+    fn get_instance(param: Parameter) -> Self {WithFieldInstruction { field: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::TextArray}
+}
+
+impl SqlParsable for WithFieldInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let field_option = self.field.get_text_array(interpreter);
+
+        match field_option.as_deref() {
+            Some([name, value]) => {
+
+                let name = sql_expression.bind(name.clone());
+                let value = sql_expression.bind(value.clone());
+                sql_expression.add_to_where(
+                    ["tickets.id IN (SELECT ticket_id FROM ticket_custom_fields WHERE field_name = ", 
+                    name.as_str(), " AND value = ", value.as_str(), ")"]
+                    .join("")
+                );
+
+                Ok(sql_expression)
+            },
+            Some(_) => Err(SqlParseError::new("with_field requires exactly a field name and a value")),
+            None => Err(SqlParseError::new("Wasn't able to parse with_field because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   The state, that closes tickets, if the adapter didn't configure one
+   with it's done_state option
+ */
+const DEFAULT_DONE_STATE: &str = "done";
+
+/**
+   Binds the state, that closes tickets in this adapter, and returns it's
+   placeholder. It's taken from the done_state variable of the interpreter
+ */
+fn bind_done_state(interpreter: &AdapterInterpreter, sql_expression: &mut SqlExpression) -> String {
+    let done_state = interpreter.get_variable(&"done_state".to_string())
+        .cloned()
+        .unwrap_or(DEFAULT_DONE_STATE.to_string());
+
+    sql_expression.bind(done_state)
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct BlockedByInstruction {
+    pub tickets: Parameter
+}
+
+impl FunctionTypeInstruction for BlockedByInstruction {
+    fn get_content(&self) -> &Parameter {&self.tickets}
+    fn get_function_name() -> String {"blocked_by".to_string()}
+    fn get_instance(param: Parameter) -> Self {BlockedByInstruction { tickets: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::TextArray}
+}
+
+impl SqlParsable for BlockedByInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let tickets_option = self.tickets.get_text_array(interpreter);
+        let adapter_option = interpreter.get_variable(&"adapter".to_string());
+
+        if let (Some(tickets), Some(adapter)) = (tickets_option, adapter_option) {
+
+            let mut ids: Vec<i64> = vec![];
+            for ticket in tickets {
+                match ticket.parse::<i64>() {
+                    Ok(id) => ids.push(id),
+                    Err(_) => return Err(SqlParseError::new("blocked_by requires a list of Ticket ids")),
+                }
+            }
+
+            let adapter = sql_expression.bind(adapter.clone());
+            let ids = ids.into_iter()
+                .map(|id| sql_expression.bind(id))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            sql_expression.add_to_where(
+                ["tickets.id IN (",
+                    "SELECT source_id FROM ticket_links WHERE link_type = 'blocked_by' ",
+                    "AND target_adapter = ", adapter.as_str(), " AND target_id IN (", ids.as_str(), ") ",
+                "UNION ",
+                    "SELECT target_id FROM ticket_links WHERE link_type = 'blocks' ",
+                    "AND target_adapter = ", adapter.as_str(), " AND source_id IN (", ids.as_str(), "))"]
+                .join("")
+            );
+
+            Ok(sql_expression)
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse blocked_by because of wrong Parameter Type"))
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct HasOpenBlockersInstruction {}
+
+impl VerifiableInstruction for HasOpenBlockersInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        "has_open_blockers() ".to_string()
+    }
+
+    fn try_tokenize(_interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        let code_internal = code.trim_start();
+
+        if code_internal.starts_with("has_open_blockers()") {
+            let code_final = code_internal.split_at("has_open_blockers()".len()).1;
+            Ok((HasOpenBlockersInstruction{}, code_final.to_string()))
+        } else {
+            Err(TokenizationError::new("Expected has_open_blockers() for Token"))
+        }
+    }
+}
+
+impl SqlParsable for HasOpenBlockersInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        
+        // Only blockers within the same adapter can be checked for their state
+        if let Some(adapter) = interpreter.get_variable(&"adapter".to_string()) {
+            let adapter = sql_expression.bind(adapter.clone());
+            let done_state = bind_done_state(interpreter, &mut sql_expression);
+
+            sql_expression.add_to_where(
+                ["tickets.id IN (",
+                    "SELECT ticket_links.source_id FROM ticket_links ",
+                    "JOIN tickets AS blockers ON blockers.id = ticket_links.target_id ",
+                    "WHERE ticket_links.link_type = 'blocked_by' AND ticket_links.target_adapter = ", adapter.as_str(), " ",
+                    "AND blockers.state_name <> ", done_state.as_str(), " ",
+                "UNION ",
+                    "SELECT ticket_links.target_id FROM ticket_links ",
+                    "JOIN tickets AS blockers ON blockers.id = ticket_links.source_id ",
+                    "WHERE ticket_links.link_type = 'blocks' AND ticket_links.target_adapter = ", adapter.as_str(), " ",
+                    "AND blockers.state_name <> ", done_state.as_str(), ")"]
+                .join("")
+            );
+
+            Ok(sql_expression)
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse has_open_blockers because the adapter is not known"))
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct WithPriorityInstruction {
+    pub priorities: Parameter
+}
+
+impl FunctionTypeInstruction for WithPriorityInstruction {
+    fn get_content(&self) -> &Parameter {&self.priorities}
+    fn get_function_name() -> String {"with_priority".to_string()}
+    fn get_instance(param: Parameter) -> Self {WithPriorityInstruction { priorities: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::TextArray}
+}
+
+impl SqlParsable for WithPriorityInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let priorities_option = self.priorities.get_text_array(interpreter);
+
+        if let Some(priorities) = priorities_option {
+
+            let mut values: Vec<String> = vec![];
+            for priority in priorities {
+                match Priority::from_name(priority.as_str()) {
+                    Some(priority) => values.push(sql_expression.bind(priority.get_value())),
+                    None => return Err(SqlParseError::new("with_priority requires priorities of low, normal, high or critical")),
+                }
+            }
+
+            sql_expression.add_to_where(
+                ["tickets.priority IN (", values.join(", ").as_str(), ")"]
+                .join("")
+            );
+
+            Ok(sql_expression)
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse with_priority because of wrong Parameter Type"))
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct PriorityAtLeastInstruction {
+    pub priority: Parameter
+}
+
+impl FunctionTypeInstruction for PriorityAtLeastInstruction {
+    fn get_content(&self) -> &Parameter {&self.priority}
+    fn get_function_name() -> String {"priority_at_least".to_string()}
+    fn get_instance(param: Parameter) -> Self {PriorityAtLeastInstruction { priority: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::Text}
+}
+
+impl SqlParsable for PriorityAtLeastInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let priority_option = self.priority.get_text(interpreter);
+
+        if let Some(priority) = priority_option {
+            match Priority::from_name(priority.trim()) {
+                Some(priority) => {
+                    let priority = sql_expression.bind(priority.get_value());
+                    sql_expression.add_to_where(
+                        ["tickets.priority >= ", priority.as_str()]
+                        .join("")
+                    );
+                    Ok(sql_expression)
+                },
+                None => Err(SqlParseError::new("priority_at_least requires a priority of low, normal, high or critical"))
+            }
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse priority_at_least because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   Turns a date like 2024-01-31 or a timestamp into a condition on the
+   given column, the same way the adapter neutral filters do it
+ */
+fn date_to_sql(interpreter: &AdapterInterpreter, condition_name: &str, date: &str, sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+    match FilterCondition::from_parts(condition_name, date.trim()) {
+        Ok(condition) => condition.to_sql(interpreter, sql_expression),
+        Err(err) => Err(SqlParseError::new(err.get_text())),
+    }
+}
+
+/**
+   Tokenizes an instruction without parameters, like overdue(), and
+   returns the code after it. Each of them can only be used once
+ */
+fn try_tokenize_unique(code: &str, function_name: &str, unique: &mut bool) -> Result<String, TokenizationError> {
+    match code.trim_start().strip_prefix(function_name) {
+        Some(code_final) if *unique => {
+            *unique = false;
+            Ok(code_final.to_string())
+        },
+        Some(_) => Err(TokenizationError::new(["Can't have more than one ", function_name].join(""))),
+        None => Err(TokenizationError::new(["Expected ", function_name, " for Token"].join(""))),
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct DueBetweenInstruction {
+    pub dates: Parameter
+}
+
+impl FunctionTypeInstruction for DueBetweenInstruction {
+    fn get_content(&self) -> &Parameter {&self.dates}
+    fn get_function_name() -> String {"due_between".to_string()}
+    fn get_instance(param: Parameter) -> Self {DueBetweenInstruction { dates: param }}
+    fn initiate_unique(interpreter: &mut AdapterInterpreter) -> bool {
+        let unique = interpreter.can_have_due_between;
+        interpreter.can_have_due_between = false;
+        unique
+    }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::TextArray}
+}
+
+impl SqlParsable for DueBetweenInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let dates_option = self.dates.get_text_array(interpreter);
+
+        // The first date is included, the second one isn't, so due_between(2024-01-01, 2024-02-01) is all of january
+        match dates_option.as_deref() {
+            Some([from, to]) => {
+                let sql_expression = date_to_sql(interpreter, "due_after", from, sql_expression)?;
+                date_to_sql(interpreter, "due_before", to, sql_expression)
+            },
+            Some(_) => Err(SqlParseError::new("due_between requires exactly two dates")),
+            None => Err(SqlParseError::new("Wasn't able to parse due_between because of wrong Parameter Type"))
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct CreatedAfterInstruction {
+    pub date: Parameter
+}
+
+impl FunctionTypeInstruction for CreatedAfterInstruction {
+    fn get_content(&self) -> &Parameter {&self.date}
+    fn get_function_name() -> String {"created_after".to_string()}
+    fn get_instance(param: Parameter) -> Self {CreatedAfterInstruction { date: param }}
+    fn initiate_unique(interpreter: &mut AdapterInterpreter) -> bool {
+        let unique = interpreter.can_have_created_after;
+        interpreter.can_have_created_after = false;
+        unique
+    }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::Text}
+}
+
+impl SqlParsable for CreatedAfterInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        match self.date.get_text(interpreter) {
+            Some(date) => date_to_sql(interpreter, "created_after", &date, sql_expression),
+            None => Err(SqlParseError::new("Wasn't able to parse created_after because of wrong Parameter Type"))
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct CreatedBeforeInstruction {
+    pub date: Parameter
+}
+
+impl FunctionTypeInstruction for CreatedBeforeInstruction {
+    fn get_content(&self) -> &Parameter {&self.date}
+    fn get_function_name() -> String {"created_before".to_string()}
+    fn get_instance(param: Parameter) -> Self {CreatedBeforeInstruction { date: param }}
+    fn initiate_unique(interpreter: &mut AdapterInterpreter) -> bool {
+        let unique = interpreter.can_have_created_before;
+        interpreter.can_have_created_before = false;
+        unique
+    }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::Text}
+}
+
+impl SqlParsable for CreatedBeforeInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        match self.date.get_text(interpreter) {
+            Some(date) => date_to_sql(interpreter, "created_before", &date, sql_expression),
+            None => Err(SqlParseError::new("Wasn't able to parse created_before because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   Matches tickets, that are due already and aren't closed yet. Due
+   dates are stored in milliseconds
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct OverdueInstruction {}
+
+impl VerifiableInstruction for OverdueInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        "overdue() ".to_string()
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        try_tokenize_unique(&code, "overdue()", &mut interpreter.can_have_overdue)
+            .map(|code_final| (OverdueInstruction{}, code_final))
+    }
+}
+
+impl SqlParsable for OverdueInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let done_state = bind_done_state(interpreter, &mut sql_expression);
+
+        sql_expression.add_to_where(
+            ["tickets.due_at < unixepoch('now') * 1000 AND tickets.state_name <> ", done_state.as_str()]
+            .join("")
+        );
+
+        Ok(sql_expression)
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct UnassignedInstruction {}
+
+impl VerifiableInstruction for UnassignedInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        "unassigned() ".to_string()
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        try_tokenize_unique(&code, "unassigned()", &mut interpreter.can_have_unassigned)
+            .map(|code_final| (UnassignedInstruction{}, code_final))
+    }
+}
+
+impl SqlParsable for UnassignedInstruction {
+    fn to_sql(&self, _interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        sql_expression.add_to_where("(tickets.assigned_to IS NULL OR tickets.assigned_to = '')".to_string());
+        Ok(sql_expression)
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct HasNoTagsInstruction {}
+
+impl VerifiableInstruction for HasNoTagsInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        "has_no_tags() ".to_string()
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        try_tokenize_unique(&code, "has_no_tags()", &mut interpreter.can_have_no_tags)
+            .map(|code_final| (HasNoTagsInstruction{}, code_final))
+    }
+}
+
+impl SqlParsable for HasNoTagsInstruction {
+    fn to_sql(&self, _interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        sql_expression.add_to_where("tickets.id NOT IN (SELECT ticket_id FROM ticket_tags)".to_string());
+        Ok(sql_expression)
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct IdInInstruction {
+    pub ids: Parameter
+}
+
+impl FunctionTypeInstruction for IdInInstruction {
+    fn get_content(&self) -> &Parameter {&self.ids}
+    fn get_function_name() -> String {"id_in".to_string()}
+    fn get_instance(param: Parameter) -> Self {IdInInstruction { ids: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::TextArray}
+}
+
+impl SqlParsable for IdInInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let ids_option = self.ids.get_text_array(interpreter);
+
+        if let Some(ids) = ids_option {
+
+            let mut values: Vec<String> = vec![];
+            for id in ids {
+                match id.parse::<i64>() {
+                    Ok(id) => values.push(sql_expression.bind(id)),
+                    Err(_) => return Err(SqlParseError::new("id_in requires a list of Ticket ids")),
+                }
+            }
+
+            sql_expression.add_to_where(
+                ["tickets.id IN (", values.join(", ").as_str(), ")"]
+                .join("")
+            );
+
+            Ok(sql_expression)
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse id_in because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   Searches the full text index of the titles and descriptions with the
+   fts5 query syntax, like matches("login page" OR crash*)
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct MatchesInstruction {
+    pub query: Parameter
+}
+
+impl FunctionTypeInstruction for MatchesInstruction {
+    fn get_content(&self) -> &Parameter {&self.query}
+    fn get_function_name() -> String {"matches".to_string()}
+    fn get_instance(param: Parameter) -> Self {MatchesInstruction { query: param }}
+    fn initiate_unique(_interpreter: &mut AdapterInterpreter) -> bool { true }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::Text}
+}
+
+impl SqlParsable for MatchesInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let query_option = self.query.get_text(interpreter);
+
+        if let Some(query) = query_option {
+            let query = sql_expression.bind(query);
+            sql_expression.add_to_where(
+                ["tickets.id IN (SELECT rowid FROM tickets_fts WHERE tickets_fts MATCH ", query.as_str(), ")"]
+                .join("")
+            );
+            Ok(sql_expression)
+        } else {
+            Err(SqlParseError::new("Wasn't able to parse matches because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   Limits the whole filter to the first tickets, ordered like in the
+   ticket provider. It can't be used inside of groups
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
+pub struct LimitInstruction {
+    pub amount: Parameter
+}
+
+impl FunctionTypeInstruction for LimitInstruction {
+    fn get_content(&self) -> &Parameter {&self.amount}
+    fn get_function_name() -> String {"limit".to_string()}
+    fn get_instance(param: Parameter) -> Self {LimitInstruction { amount: param }}
+    fn initiate_unique(interpreter: &mut AdapterInterpreter) -> bool {
+        let unique = interpreter.can_have_limit;
+        interpreter.can_have_limit = false;
+        unique
+    }
+    fn required_parameter_type() -> VerifiableDataType {VerifiableDataType::Number}
+}
+
+impl SqlParsable for LimitInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        match self.amount.get_number(interpreter) {
+            Some(amount) if amount >= 0 => {
+                sql_expression.set_limit(amount as i64);
+                Ok(sql_expression)
+            },
+            Some(_) => Err(SqlParseError::new("limit can't be negative")),
+            None => Err(SqlParseError::new("Wasn't able to parse limit because of wrong Parameter Type"))
+        }
+    }
+}
+
+/**
+   Tokenizes the instructions of a nested group up to it's closing
+   parenthesis. The code starts right after the opening one. Groups
+   need at least one instruction and can't contain ;;
+ */
+fn try_tokenize_group(interpreter: &mut AdapterInterpreter, code: &str, name: &str) -> Result<(Vec<Instruction>, String), TokenizationError> {
+    interpreter.with_fresh_uniqueness(|interpreter| {
+        let mut instructions: Vec<Instruction> = vec![];
+        let mut code_internal = code.trim_start().to_string();
+
+        loop {
+            if let Some(code_final) = code_internal.strip_prefix(')') {
+                if instructions.is_empty() {
+                    return Err(TokenizationError::new([name, " requires at least one instruction"].join("")));
+                }
+                return Ok((instructions, code_final.to_string()));
+            }
+
+            if code_internal.is_empty() {
+                return Err(TokenizationError::new(["Expected ) at the end of ", name].join("")));
+            }
+
+            let (instruction, code_final) = Instruction::try_tokenize(interpreter, code_internal)?;
+
+            if let Instruction::Join(_) = instruction {
+                return Err(TokenizationError::new([";; is not allowed inside of ", name].join("")));
+            }
+
+            if let Instruction::Limit(_) = instruction {
+                return Err(TokenizationError::new(["limit is not allowed inside of ", name].join("")));
+            }
+
+            instructions.push(instruction);
+            code_internal = code_final.trim_start().to_string();
+        }
+    })
+}
+
+/**
+   Writes the instructions of a nested group back, like they have been tokenized
+ */
+fn group_to_string(instructions: &[Instruction]) -> String {
+    instructions.iter()
+        .map(|instruction| instruction.to_string())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+/**
+   Turns the instructions of a nested group into a single sql condition,
+   that combines them with AND
+ */
+fn group_to_sql(interpreter: &AdapterInterpreter, instructions: &[Instruction], sql_expression: &mut SqlExpression) -> Result<String, SqlParseError> {
+    let mut nested = sql_expression.begin_nested();
+
+    for instruction in instructions {
+        nested = instruction.to_sql(interpreter, nested)?;
+    }
+
+    Ok(sql_expression.end_nested(nested))
+}
+
+/**
+   Matches tickets, that don't satisfy all of it's instructions, like
+   not(with_tag(wontfix))
+ */
+pub struct NotInstruction {
+    pub instructions: Vec<Instruction>
+}
+
+impl VerifiableInstruction for NotInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        ["not(", group_to_string(&self.instructions).as_str(), ") "].join("")
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        match code.trim_start().strip_prefix("not(") {
+            Some(code_internal) => try_tokenize_group(interpreter, code_internal, "not(...)")
+                .map(|(instructions, code_final)| (NotInstruction { instructions }, code_final)),
+            None => Err(TokenizationError::new("Expected not(...) for Token")),
+        }
+    }
+}
+
+impl SqlParsable for NotInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let condition = group_to_sql(interpreter, &self.instructions, &mut sql_expression)?;
+        sql_expression.add_to_where(["NOT (", condition.as_str(), ")"].join(""));
+        Ok(sql_expression)
+    }
+}
+
+/**
+   Matches tickets, that satisfy at least one of it's instructions. To
+   require multiple instructions in one alternative, they can be grouped
+   with parentheses, like or(with_tag(bug) (with_state(open) assigned_to(::me)))
+ */
+pub struct OrInstruction {
+    pub alternatives: Vec<Instruction>
+}
+
+impl VerifiableInstruction for OrInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        ["or(", group_to_string(&self.alternatives).as_str(), ") "].join("")
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        match code.trim_start().strip_prefix("or(") {
+            Some(code_internal) => try_tokenize_group(interpreter, code_internal, "or(...)")
+                .map(|(alternatives, code_final)| (OrInstruction { alternatives }, code_final)),
+            None => Err(TokenizationError::new("Expected or(...) for Token")),
+        }
+    }
+}
+
+impl SqlParsable for OrInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let mut conditions: Vec<String> = vec![];
+
+        for alternative in self.alternatives.iter() {
+            conditions.push(group_to_sql(interpreter, std::slice::from_ref(alternative), &mut sql_expression)?);
+        }
+
+        sql_expression.add_to_where(["((", conditions.join(") OR (").as_str(), "))"].join(""));
+        Ok(sql_expression)
+    }
+}
+
+/**
+   Groups instructions with parentheses, so they count as a single one,
+   for example as one alternative of or(...)
+ */
+pub struct GroupInstruction {
+    pub instructions: Vec<Instruction>
+}
+
+impl VerifiableInstruction for GroupInstruction {
+    fn is_valid_after(&self, _interpreter: &AdapterInterpreter, _instruction: Instruction) -> bool {
+        true
+    }
+
+    fn to_string(&self) -> String {
+        ["(", group_to_string(&self.instructions).as_str(), ") "].join("")
+    }
+
+    fn try_tokenize(interpreter: &mut AdapterInterpreter, code: String) -> Result<(Self, String), TokenizationError> {
+        match code.trim_start().strip_prefix('(') {
+            Some(code_internal) => try_tokenize_group(interpreter, code_internal, "(...)")
+                .map(|(instructions, code_final)| (GroupInstruction { instructions }, code_final)),
+            None => Err(TokenizationError::new("Expected (...) for Token")),
+        }
+    }
+}
+
+impl SqlParsable for GroupInstruction {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        let condition = group_to_sql(interpreter, &self.instructions, &mut sql_expression)?;
+        sql_expression.add_to_where(["(", condition.as_str(), ")"].join(""));
+        Ok(sql_expression)
+    }
+}
+
 #[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Debug)]
 pub struct JoinInstruction {}
 
@@ -0,0 +1,51 @@
+use tickets_rs_core::{FilterCondition, FilterQuery};
+
+use super::interpreter::{
+    AdapterInterpreter,
+    SqlExpression,
+    SqlParsable
+};
+
+use super::interpreter_errors::SqlParseError;
+
+impl SqlParsable for FilterCondition {
+    fn to_sql(&self, _interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+
+        // Tags and buckets are tested with subqueries instead of joins,
+        // so multiple conditions on them don't interfere with eachother
+        let expression = match self {
+            FilterCondition::State(state) => ["tickets.state_name = ", sql_expression.bind(state.clone()).as_str()].join(""),
+            FilterCondition::Tag(tag) => ["tickets.id IN (SELECT ticket_id FROM ticket_tags WHERE tag_name = ", sql_expression.bind(tag.clone()).as_str(), ")"].join(""),
+            FilterCondition::Bucket(bucket) => ["tickets.bucket_id IN (SELECT id FROM buckets WHERE name = ", sql_expression.bind(bucket.clone()).as_str(), ")"].join(""),
+            FilterCondition::AssignedTo(users) => {
+                let users: Vec<String> = users.iter().map(|user| sql_expression.bind(user.clone())).collect();
+                ["tickets.assigned_to IN (", users.join(", ").as_str(), ")"].join("")
+            },
+            FilterCondition::TitleContains(title) => ["tickets.title LIKE '%' || ", sql_expression.bind(title.clone()).as_str(), " || '%'"].join(""),
+            FilterCondition::DescriptionContains(description) => ["tickets.description LIKE '%' || ", sql_expression.bind(description.clone()).as_str(), " || '%'"].join(""),
+            FilterCondition::TextContains(text) => {
+                let text = sql_expression.bind(text.clone());
+                ["(tickets.title LIKE '%' || ", text.as_str(), " || '%' OR tickets.description LIKE '%' || ", text.as_str(), " || '%')"].join("")
+            },
+            FilterCondition::DueInDays(days) => ["tickets.due_at < (SELECT unixepoch('now','start of day',", sql_expression.bind(["+", days.to_string().as_str(), " day"].join("")).as_str(), ") * 1000)"].join(""),
+            FilterCondition::DueBefore(timestamp) => ["tickets.due_at < ", sql_expression.bind(FilterCondition::get_due_millis(*timestamp)).as_str()].join(""),
+            FilterCondition::DueAfter(timestamp) => ["tickets.due_at >= ", sql_expression.bind(FilterCondition::get_due_millis(*timestamp)).as_str()].join(""),
+            FilterCondition::CreatedBefore(timestamp) => ["tickets.created_at < ", sql_expression.bind(*timestamp).as_str()].join(""),
+            FilterCondition::CreatedAfter(timestamp) => ["tickets.created_at >= ", sql_expression.bind(*timestamp).as_str()].join(""),
+        };
+
+        sql_expression.add_to_where(expression);
+        Ok(sql_expression)
+    }
+}
+
+impl SqlParsable for FilterQuery {
+    fn to_sql(&self, interpreter: &AdapterInterpreter, mut sql_expression: SqlExpression) -> Result<SqlExpression, SqlParseError> {
+        for condition in self.get_conditions() {
+            sql_expression = condition.to_sql(interpreter, sql_expression)?;
+        }
+
+        sql_expression.flush();
+        Ok(sql_expression)
+    }
+}
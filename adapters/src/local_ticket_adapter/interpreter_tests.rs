@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use rusqlite::types::Value;
+
     use crate::local_ticket_adapter::{
         interpreter::{
             AdapterInterpreter, 
@@ -43,14 +45,14 @@ mod tests {
                 "FROM ((tickets) ",
                     "JOIN buckets ON tickets.bucket_id = buckets.id) ",
                     "JOIN ticket_tags ON tickets.id = ticket_tags.ticket_id ",
-                "WHERE buckets.name = 'default.bucket' ",
-                    "AND ticket_tags.tag_name = 'documentation' ",
+                "WHERE buckets.name = ?1 ",
+                    "AND ticket_tags.tag_name = ?2 ",
             "UNION SELECT tickets.* ",
                 "FROM ((tickets) ",
                     "JOIN buckets ON tickets.bucket_id = buckets.id) ",
                     "JOIN ticket_tags ON tickets.id = ticket_tags.ticket_id ",
-                "WHERE buckets.name = 'empty.bucket' ",
-                    "AND ticket_tags.tag_name = 'documentation';"
+                "WHERE buckets.name = ?3 ",
+                    "AND ticket_tags.tag_name = ?4;"
 
             ].join(""));
     }
@@ -76,6 +78,47 @@ mod tests {
         ].join(" \n"));
     }
 
+    #[test]
+    fn test_not_or_groups() {
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        interpreter.set_variable("me", "biochemist");
+        let code = "with_tag(bug) not(with_tag(wontfix)) or(with_state(open) (title_contains(crash) assigned_to(::me)))";
+        let _ = interpreter.try_tokenize(code.to_string());
+
+        assert_eq!(interpreter.get_last_error(), None);
+        assert_eq!(interpreter.to_string(), [
+            "with_tag(bug)",
+            "not(with_tag(wontfix))",
+            "or(with_state(open) (title_contains(crash) assigned_to(::me)))"
+        ].join(" \n"));
+
+        // Reading the written instructions again gives the same instructions
+        let written = interpreter.to_string();
+        let mut reread: AdapterInterpreter = AdapterInterpreter::default();
+        reread.set_variable("me", "biochemist");
+        assert!(reread.try_tokenize(written.clone()).is_ok());
+        assert_eq!(reread.to_string(), written);
+
+        assert_eq!(interpreter.construct_sql().unwrap(), [
+            "SELECT tickets.* ",
+                "FROM (tickets) ",
+                    "JOIN ticket_tags ON tickets.id = ticket_tags.ticket_id ",
+                "WHERE ticket_tags.tag_name = ?1 ",
+                "AND NOT (tickets.id IN (",
+                    "SELECT tickets.id FROM (tickets) JOIN ticket_tags ON tickets.id = ticket_tags.ticket_id ",
+                    "WHERE ticket_tags.tag_name = ?2)) ",
+                "AND ((tickets.state_name = ?3) OR ((tickets.title LIKE '%' || ?4 || '%' AND (tickets.assigned_to = ?5))));"
+        ].join(""));
+
+        // Each group has it's own uniqueness, but the surrounding one still has to stay unique
+        assert!(interpreter.try_tokenize("title_contains(a) not(title_contains(b))".to_string()).is_ok());
+        assert!(interpreter.try_tokenize("not(title_contains(a)) title_contains(b) title_contains(c)".to_string()).is_err());
+
+        assert!(interpreter.try_tokenize("not()".to_string()).is_err());
+        assert!(interpreter.try_tokenize("or(with_tag(bug)".to_string()).is_err());
+        assert!(interpreter.try_tokenize("(with_tag(bug);; with_tag(feature))".to_string()).is_err());
+    }
+
     #[test]
     fn test_single_tokens() {
         let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
@@ -143,4 +186,130 @@ mod tests {
             vec!["biochemic".to_string(), "user1".to_string(), "user2".to_string()]);
         
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_with_field_to_sql() {
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        let _ = interpreter.try_tokenize("with_field(severity, high)".to_string());
+
+        assert_eq!(interpreter.get_last_error(), None);
+        assert_eq!(interpreter.construct_sql().unwrap(), [
+            "SELECT tickets.* FROM tickets ",
+            "WHERE tickets.id IN (SELECT ticket_id FROM ticket_custom_fields ",
+            "WHERE field_name = ?1 AND value = ?2);"
+        ].join(""));
+
+        // A field without value can't be turned into sql
+        let _ = interpreter.try_tokenize("with_field(severity)".to_string());
+        assert!(interpreter.construct_sql().is_err());
+    }
+
+    #[test]
+    fn test_blockers_to_sql() {
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        interpreter.set_variable("adapter", "local");
+        let _ = interpreter.try_tokenize("blocked_by(3, 5) has_open_blockers()".to_string());
+
+        assert_eq!(interpreter.get_last_error(), None);
+        assert_eq!(interpreter.construct_sql().unwrap(), [
+            "SELECT tickets.* FROM tickets WHERE tickets.id IN (",
+                "SELECT source_id FROM ticket_links WHERE link_type = 'blocked_by' ",
+                "AND target_adapter = ?1 AND target_id IN (?2, ?3) ",
+            "UNION ",
+                "SELECT target_id FROM ticket_links WHERE link_type = 'blocks' ",
+                "AND target_adapter = ?1 AND source_id IN (?2, ?3)) ",
+            "AND tickets.id IN (",
+                "SELECT ticket_links.source_id FROM ticket_links ",
+                "JOIN tickets AS blockers ON blockers.id = ticket_links.target_id ",
+                "WHERE ticket_links.link_type = 'blocked_by' AND ticket_links.target_adapter = ?4 ",
+                "AND blockers.state_name <> ?5 ",
+            "UNION ",
+                "SELECT ticket_links.target_id FROM ticket_links ",
+                "JOIN tickets AS blockers ON blockers.id = ticket_links.source_id ",
+                "WHERE ticket_links.link_type = 'blocks' AND ticket_links.target_adapter = ?4 ",
+                "AND blockers.state_name <> ?5);"
+        ].join(""));
+
+        // The done state, the adapter has been configured with, is bound as parameter
+        interpreter.set_variable("done_state", "closed");
+        let _ = interpreter.try_tokenize("has_open_blockers()".to_string());
+        let sql_expression = interpreter.construct_sql_expression().unwrap();
+        assert_eq!(sql_expression.get_parameters(), &[Value::Text("local".to_string()), Value::Text("closed".to_string())]);
+
+        // Ticket ids have to be numbers
+        let _ = interpreter.try_tokenize("blocked_by(first ticket)".to_string());
+        assert!(interpreter.construct_sql().is_err());
+    }
+
+    #[test]
+    fn test_priority_to_sql() {
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        let _ = interpreter.try_tokenize("with_priority(low, critical) priority_at_least(high)".to_string());
+
+        assert_eq!(interpreter.get_last_error(), None);
+        assert_eq!(interpreter.construct_sql().unwrap(), [
+            "SELECT tickets.* FROM tickets ",
+            "WHERE tickets.priority IN (?1, ?2) AND tickets.priority >= ?3;"
+        ].join(""));
+
+        // Unknown priorities can't be turned into sql
+        let _ = interpreter.try_tokenize("priority_at_least(urgent)".to_string());
+        assert!(interpreter.construct_sql().is_err());
+    }
+
+    #[test]
+    fn test_date_and_metadata_to_sql() {
+        let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+        let code = "created_after(2024-01-01) created_before(1717200000) due_between(2024-01-01, 2024-02-01) overdue() unassigned() has_no_tags() id_in(3, 5) limit(10)";
+        let _ = interpreter.try_tokenize(code.to_string());
+
+        assert_eq!(interpreter.get_last_error(), None);
+        assert_eq!(interpreter.to_string(), [
+            "created_after(2024-01-01)",
+            "created_before(1717200000)",
+            "due_between(2024-01-01, 2024-02-01)",
+            "overdue()",
+            "unassigned()",
+            "has_no_tags()",
+            "id_in(3, 5)",
+            "limit(10)"
+        ].join(" \n"));
+
+        let mut sql_expression = interpreter.construct_sql_expression().unwrap();
+        assert_eq!(sql_expression.get_final().as_str(), [
+            "SELECT tickets.* FROM tickets ",
+            "WHERE tickets.created_at >= ?1 AND tickets.created_at < ?2 ",
+            "AND tickets.due_at >= ?3 AND tickets.due_at < ?4 ",
+            "AND tickets.due_at < unixepoch('now') * 1000 AND tickets.state_name <> ?5 ",
+            "AND (tickets.assigned_to IS NULL OR tickets.assigned_to = '') ",
+            "AND tickets.id NOT IN (SELECT ticket_id FROM ticket_tags) ",
+            "AND tickets.id IN (?6, ?7) ",
+            "ORDER BY priority DESC, id LIMIT ?8;"
+        ].join(""));
+        assert_eq!(sql_expression.get_parameters(), &[
+            Value::Integer(1704067200), Value::Integer(1717200000),
+            Value::Integer(1704067200000), Value::Integer(1706745600000),
+            Value::Text("done".to_string()),
+            Value::Integer(3), Value::Integer(5), Value::Integer(10)]);
+
+        // Each of them can only be used once per group, except id_in
+        let unique = ["created_after(2024-01-01)", "created_before(2024-01-01)", "due_between(2024-01-01, 2024-02-01)", 
+            "overdue()", "unassigned()", "has_no_tags()"];
+        for instruction in unique {
+            assert!(AdapterInterpreter::default().try_tokenize([instruction, instruction].join(" ")).is_err());
+            assert!(AdapterInterpreter::default().try_tokenize(["not(", instruction, ") ", instruction].join("")).is_ok());
+        }
+        assert!(AdapterInterpreter::default().try_tokenize("id_in(1) id_in(2)".to_string()).is_ok());
+
+        // limit only works once on the whole filter
+        assert!(AdapterInterpreter::default().try_tokenize("limit(1) limit(2)".to_string()).is_err());
+        assert!(AdapterInterpreter::default().try_tokenize("not(limit(1))".to_string()).is_err());
+
+        // Invalid dates, ids and amounts can't be turned into sql
+        for code in ["created_after(yesterday)", "due_between(2024-01-01)", "id_in(3, five)", "limit(-1)"] {
+            let mut interpreter: AdapterInterpreter = AdapterInterpreter::default();
+            assert!(interpreter.try_tokenize(code.to_string()).is_ok());
+            assert!(interpreter.construct_sql().is_err());
+        }
+    }
+}
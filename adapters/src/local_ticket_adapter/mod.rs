@@ -1,14 +1,21 @@
 mod adapter;
+mod adapter_tests;
 mod interpreter;
 mod interpreter_errors;
 mod interpreter_tests;
 mod interpreter_instructions;
 mod interpreter_parameters;
+mod interpreter_query;
 
-use std::sync::{
-    Arc, Mutex
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Arc, Mutex
+    }
 };
 
+use rusqlite::{Connection, Row, Transaction, types::Value};
+
 use tickets_rs_core::{
     LocalDatabase, 
     AppConfig, 
@@ -17,15 +24,30 @@ use tickets_rs_core::{
     State, 
     Filter,
     FilterType,
-    Ticket};
+    FilterQuery,
+    SearchHit,
+    Ticket,
+    Comment,
+    HistoryEntry,
+    CustomField,
+    CustomFieldType,
+    ChecklistItem,
+    WorkLog,
+    Priority,
+    Recurrence,
+    AdapterError,
+    AdapterErrorType};
 
 use tickets_rs_core::TicketAdapter;
 
+use interpreter::{AdapterInterpreter, SqlExpression, SqlParsable};
+
 pub struct LocalTicketAdapter {
     database: Arc<Mutex<LocalDatabase>>,
     config: Arc<Mutex<AppConfig>>,
     name: String,
-    display_name: String
+    display_name: String,
+    done_state: String
 }
 
 impl LocalTicketAdapter {
@@ -37,7 +59,14 @@ impl LocalTicketAdapter {
                 state_tables,
                 tag_tables,
                 tagticket_tables,
-                filter_tables
+                filter_tables,
+                comment_tables,
+                history_tables,
+                custom_field_tables,
+                link_tables,
+                checklist_tables,
+                attachment_tables,
+                work_log_tables
 
             ) = match self.database.lock() {
             Ok(mut lock) => {
@@ -57,7 +86,15 @@ impl LocalTicketAdapter {
                         String::from("description TEXT"), 
                         String::from("created_at INTEGER"), 
                         String::from("due_at INTEGER"),
-                        String::from("assigned_to TEXT")]);
+                        String::from("assigned_to TEXT"),
+                        String::from("estimate INTEGER NOT NULL DEFAULT 0"),
+                        String::from("priority INTEGER NOT NULL DEFAULT 1"),
+                        String::from("recurrence TEXT NOT NULL DEFAULT ''")]);
+
+                // Tickets of older databases don't have an estimate, priority and recurrence yet
+                lock.add_column("tickets", "estimate", "INTEGER NOT NULL DEFAULT 0");
+                lock.add_column("tickets", "priority", "INTEGER NOT NULL DEFAULT 1");
+                lock.add_column("tickets", "recurrence", "TEXT NOT NULL DEFAULT ''");
 
                 let ticket_tags = lock.create_table(
                     &String::from("ticket_tags"), vec![
@@ -81,11 +118,90 @@ impl LocalTicketAdapter {
                         String::from("name TEXT NOT NULL PRIMARY KEY"),
                         String::from("operation TEXT NOT NULL")]);
 
-                (buckets, tickets, states, tags, ticket_tags, filters)
+                let comments = lock.create_table(
+                    &String::from("comments"), vec![
+                        String::from("id INTEGER PRIMARY KEY AUTOINCREMENT"),
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("author TEXT NOT NULL"),
+                        String::from("body TEXT NOT NULL"),
+                        String::from("created_at INTEGER"),
+                        String::from("edited_at INTEGER")]);
+
+                let history = lock.create_table(
+                    &String::from("ticket_history"), vec![
+                        String::from("id INTEGER PRIMARY KEY AUTOINCREMENT"),
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("field TEXT NOT NULL"),
+                        String::from("old_value TEXT"),
+                        String::from("new_value TEXT"),
+                        String::from("changed_by TEXT"),
+                        String::from("changed_at INTEGER")]);
+
+                let custom_fields = lock.create_table(
+                    &String::from("custom_fields"), vec![
+                        String::from("name TEXT NOT NULL PRIMARY KEY"),
+                        String::from("field_type TEXT NOT NULL"),
+                        String::from("options TEXT")]);
+
+                let ticket_custom_fields = lock.create_table(
+                    &String::from("ticket_custom_fields"), vec![
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("field_name TEXT NOT NULL"),
+                        String::from("value TEXT NOT NULL")]);
+
+                let ticket_links = lock.create_table(
+                    &String::from("ticket_links"), vec![
+                        String::from("id INTEGER PRIMARY KEY AUTOINCREMENT"),
+                        String::from("source_id INTEGER NOT NULL"),
+                        String::from("target_adapter TEXT NOT NULL"),
+                        String::from("target_id INTEGER NOT NULL"),
+                        String::from("link_type TEXT NOT NULL")]);
+
+                let ticket_checklist = lock.create_table(
+                    &String::from("ticket_checklist"), vec![
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("position INTEGER NOT NULL"),
+                        String::from("text TEXT NOT NULL"),
+                        String::from("done INTEGER NOT NULL")]);
+
+                let attachments = lock.create_table(
+                    &String::from("attachments"), vec![
+                        String::from("id INTEGER PRIMARY KEY AUTOINCREMENT"),
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("name TEXT NOT NULL"),
+                        String::from("mime_type TEXT NOT NULL"),
+                        String::from("size INTEGER NOT NULL"),
+                        String::from("created_at INTEGER"),
+                        String::from("data BLOB NOT NULL")]);
+
+                let work_logs = lock.create_table(
+                    &String::from("work_logs"), vec![
+                        String::from("id INTEGER PRIMARY KEY AUTOINCREMENT"),
+                        String::from("ticket_id INTEGER NOT NULL"),
+                        String::from("user TEXT NOT NULL"),
+                        String::from("note TEXT"),
+                        String::from("duration INTEGER NOT NULL"),
+                        String::from("logged_at INTEGER NOT NULL")]);
+
+                (buckets, tickets, states, tags, ticket_tags, filters, comments, history, custom_fields && ticket_custom_fields, ticket_links, ticket_checklist, attachments, work_logs)
             },
-            Err(_) => (false, false, false, false, false, false),
+            Err(_) => (false, false, false, false, false, false, false, false, false, false, false, false, false),
         };
 
+        self.prepare_search_index();
+
+        // These tables don't get any default data, but tickets can't be linked,
+        // get attachments or work logs without them
+        for (created, table) in [
+            (link_tables, "ticket links"), 
+            (attachment_tables, "attachments"), 
+            (work_log_tables, "work logs")] {
+
+            if !created {
+                println!("Wasn't able to create the tables for {table}");
+            }
+        }
+
         if create_default_data {
             let mut bucket_default = Bucket::default()
                 .with_adapter(self)
@@ -129,24 +245,12 @@ impl LocalTicketAdapter {
                 .with_name(String::from("blocker"))
                 .with_hex_colors("#a71a35", "#ffd9df");
 
-            let tag_high_prio = Tag::default()
-                .with_adapter(self)
-                .with_name(String::from("high priority"))
-                .with_hex_colors("#a7581a", "#ffecd9");
-
-            let tag_low_prio = Tag::default()
-                .with_adapter(self)
-                .with_name(String::from("low priority"))
-                .with_hex_colors("#4e542a", "#c9d5b6");
-
             if tag_tables {
                 match self.tag_write(&tag_bug)
                  .and(self.tag_write(&tag_enhancement))
                  .and(self.tag_write(&tag_documentation))
                  .and(self.tag_write(&tag_wontfix))
-                 .and(self.tag_write(&tag_blocker))
-                 .and(self.tag_write(&tag_high_prio))
-                 .and(self.tag_write(&tag_low_prio)) {
+                 .and(self.tag_write(&tag_blocker)) {
                     Ok(_) => (),
                     Err(err) => println!("Wasn't able to write tags as default data due to {err}"),
                 };
@@ -198,6 +302,27 @@ impl LocalTicketAdapter {
                 }
             }
 
+            let field_severity = CustomField::default()
+                .with_adapter(self)
+                .with_name(String::from("severity"))
+                .with_type(CustomFieldType::Enum(vec![
+                    String::from("low"), 
+                    String::from("medium"), 
+                    String::from("high")]));
+
+            let field_customer = CustomField::default()
+                .with_adapter(self)
+                .with_name(String::from("customer"))
+                .with_type(CustomFieldType::Text);
+
+            if custom_field_tables {
+                match self.custom_field_write(&field_severity)
+                 .and(self.custom_field_write(&field_customer)) {
+                    Ok(_) => (),
+                    Err(err) => println!("Wasn't able to write custom fields as default data due to {err}"),
+                };
+            }
+
             if filter_tables {
 
                 let filter_state_new = Filter::default()
@@ -277,7 +402,8 @@ impl LocalTicketAdapter {
 
             }
 
-            if ticket_tables && tagticket_tables {
+            // Writing a ticket also writes it's custom fields, checklist and history
+            if ticket_tables && tagticket_tables && custom_field_tables && checklist_tables && history_tables {
 
                 let ticket_example_task = self.ticket_write(
                     &Ticket::default()
@@ -286,14 +412,20 @@ impl LocalTicketAdapter {
                         .with_details(0, String::from("Example Task"), 
                             String::from("This is an example Task, created to test functionality."))
                         .with_state(&state_new)
+                        .with_priority(Priority::Low)
                         .with_tags(vec![
-                            &tag_low_prio,
                             &tag_documentation,
                             &Tag::default().with_name(String::from("example")).with_random_colors(),
                             &Tag::default().with_name(String::from("example2")).with_random_colors(),
                             &Tag::default().with_name(String::from("example3")).with_random_colors()
                         ])
                         .with_assignee("biochemist".to_string())
+                        .with_custom_field(&field_severity.name, String::from("low"))
+                        .with_checklist(vec![
+                            ChecklistItem::new(String::from("Read the Example Task"), true),
+                            ChecklistItem::new(String::from("Check off this item"), false),
+                            ChecklistItem::new(String::from("Create your own Ticket"), false)
+                        ])
                 );
 
                 let ticket_second_task = self.ticket_write(
@@ -322,6 +454,7 @@ impl LocalTicketAdapter {
                             " moment, and it also won't fit into the ticket completely. Well",
                             ", atleast thats the plan."].join(""))
                         .with_state(&state_open)
+                        .with_priority(Priority::Critical)
                         .with_tags(vec![
                             &tag_blocker,
                             &tag_bug,
@@ -387,8 +520,750 @@ impl LocalTicketAdapter {
                     Ok(_) => (),
                     Err(err) => println!("Wasn't able to write tickets as default data due to {err}"),
                 }
+
+                if comment_tables {
+                    if let Some(ticket) = self.ticket_list_all().first() {
+                        let comment_example = Comment::default()
+                            .with_ticket(ticket)
+                            .with_details(0, "biochemist".to_string(),
+                                String::from("This is an example Comment. Comments can contain **Markdown** aswell."));
+
+                        match self.comment_write(&comment_example) {
+                            Ok(_) => (),
+                            Err(err) => println!("Wasn't able to write comments as default data due to {err}"),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /**
+       Creates the full text index over the titles and descriptions of the
+       tickets, together with the triggers, that keep it in sync with the
+       tickets table. Databases, that didn't have the index yet, get all
+       of their existing tickets indexed once
+     */
+    pub(crate) fn prepare_search_index(&self) {
+        let result = match self.database.lock() {
+            Ok(db_lock) => {
+                match db_lock.connection.lock() {
+                    Ok(lock) => Self::create_search_index(&lock),
+                    Err(err) => {
+                        println!("Wasn't able to lock the connection for the search index due to {}", err);
+                        return;
+                    }
+                }
+            },
+            Err(err) => {
+                println!("Wasn't able to lock the database for the search index due to {}", err);
+                return;
             }
+        };
+
+        if let Err(err) = result {
+            println!("Wasn't able to create the search index due to {}", err);
+        }
+    }
+
+    fn create_search_index(connection: &Connection) -> Result<(), rusqlite::Error> {
+        let exists = connection.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'tickets_fts';", 
+            [], 
+            |row| row.get::<_, i64>(0))? > 0;
+
+        // Tickets are written with REPLACE, which removes the old row without
+        // running delete triggers, so the old row is removed from the index
+        // before inserting instead
+        connection.execute_batch([
+            "CREATE VIRTUAL TABLE IF NOT EXISTS tickets_fts USING fts5(title, description, content='tickets', content_rowid='id');",
+            "CREATE TRIGGER IF NOT EXISTS tickets_fts_before_insert BEFORE INSERT ON tickets BEGIN ",
+                "INSERT INTO tickets_fts(tickets_fts, rowid, title, description) ",
+                "SELECT 'delete', id, title, description FROM tickets WHERE id = new.id; ",
+            "END;",
+            "CREATE TRIGGER IF NOT EXISTS tickets_fts_after_insert AFTER INSERT ON tickets BEGIN ",
+                "INSERT INTO tickets_fts(rowid, title, description) VALUES (new.id, new.title, new.description); ",
+            "END;",
+            "CREATE TRIGGER IF NOT EXISTS tickets_fts_after_delete AFTER DELETE ON tickets BEGIN ",
+                "INSERT INTO tickets_fts(tickets_fts, rowid, title, description) VALUES ('delete', old.id, old.title, old.description); ",
+            "END;",
+            "CREATE TRIGGER IF NOT EXISTS tickets_fts_after_update AFTER UPDATE OF title, description ON tickets BEGIN ",
+                "INSERT INTO tickets_fts(tickets_fts, rowid, title, description) VALUES ('delete', old.id, old.title, old.description); ",
+                "INSERT INTO tickets_fts(rowid, title, description) VALUES (new.id, new.title, new.description); ",
+            "END;"
+        ].join("").as_str())?;
+
+        if !exists {
+            connection.execute("INSERT INTO tickets_fts(tickets_fts) VALUES ('rebuild');", [])?;
+        }
+
+        Ok(())
+    }
+
+    /**
+       Finds the tickets, that match the full text search, the best one
+       first. The text is passed to the index as it is, like in matches(...),
+       so phrases in quotes, prefixes like crash* and OR work as well
+     */
+    pub(crate) fn search_hits(&self, connection: &Connection, text: &str) -> Result<Vec<SearchHit>, rusqlite::Error> {
+        if SearchHit::words(text).is_empty() {
+            return Ok(vec![]);
+        }
+
+        let expression = [
+            "SELECT tickets.*, bm25(tickets_fts) AS search_rank FROM tickets ",
+            "JOIN tickets_fts ON tickets_fts.rowid = tickets.id ",
+            "WHERE tickets_fts MATCH ?1 ORDER BY search_rank, tickets.id;"
+        ].join("");
+
+        let mut stmt_search = connection.prepare(expression.as_str())?;
+        let mut hits = stmt_search.query_map([text], |row| {
+            Ok(SearchHit::new(self.ticket_from_row(row)?, SearchHit::rank_score(row.get("search_rank")?)))
+        })?.collect::<Result<Vec<SearchHit>, rusqlite::Error>>()?;
+
+        for hit in hits.iter_mut() {
+            Self::ticket_details(connection, &mut hit.ticket)?;
+        }
+
+        Ok(hits)
+    }
+
+    /**
+       Returns the name of the current user, that is used to sign
+       the entries of the ticket history
+     */
+    pub(crate) fn current_username(&self) -> String {
+        match self.config.lock() {
+            Ok(mut config) => config.get_or_default("username", "new User", "").raw().clone(),
+            Err(err) => {
+                println!("Wasn't able to lock Config. Reason: {}", err);
+                String::from("new User")
+            },
+        }
+    }
+
+    /**
+       Reads the currently stored state of a ticket including it's tags
+       with an already locked connection. This is used for comparing a
+       ticket before it gets overwritten.
+     */
+    pub(crate) fn ticket_snapshot(&self, connection: &Connection, id: i64) -> Option<Ticket> {
+        self.ticket_query(connection, "SELECT * FROM tickets WHERE tickets.id = ?;", [id])
+            .ok()?
+            .pop()
+    }
+
+    /**
+       Compiles an adapter neutral query into an sql expression on the tickets
+     */
+    pub(crate) fn query_sql_expression(&self, query: &FilterQuery) -> Result<SqlExpression, AdapterError> {
+        query.to_sql(&AdapterInterpreter::default(), SqlExpression::default())
+            .map_err(|err| AdapterError::new(AdapterErrorType::Expression(err.to_string())))
+    }
+
+    /**
+       Runs a select on the tickets with an already locked connection and
+       returns the found tickets including their tags. Only the selected
+       tickets get their details loaded, which keeps paged listings fast.
+     */
+    pub(crate) fn ticket_query<P: rusqlite::Params>(&self, connection: &Connection, expression: &str, params: P) -> Result<Vec<Ticket>, rusqlite::Error> {
+        let mut stmt_select = connection.prepare(expression)?;
+        let mut tickets = stmt_select.query_map(params, |row| self.ticket_from_row(row))?
+            .collect::<Result<Vec<Ticket>, rusqlite::Error>>()?;
+
+        for ticket in tickets.iter_mut() {
+            Self::ticket_details(connection, ticket)?;
+        }
+
+        Ok(tickets)
+    }
+
+    /**
+       Reads a ticket from a row, that starts with all columns of the tickets
+       table. It's tags, custom fields and checklist are loaded separately
+       with ticket_details
+     */
+    pub(crate) fn ticket_from_row(&self, row: &Row) -> Result<Ticket, rusqlite::Error> {
+        Ok(Ticket {
+            adapter: self.get_name(),
+            id: row.get(0)?,
+            bucket_id: row.get(1)?,
+            title: row.get(2)?,
+            state_name: row.get(3)?,
+            description: row.get(4)?,
+            created_at: row.get(5)?,
+            due_at: row.get(6)?,
+            assigned_to: row.get(7)?,
+            estimate: row.get(8)?,
+            priority: Priority::from_value(row.get(9)?),
+            recurrence: Recurrence::from_text(row.get::<usize, String>(10)?.as_str()),
+            tags: vec![],
+            custom_fields: BTreeMap::new(),
+            checklist: vec![],
+            additional_id: row.get::<_, i64>(0)?.to_string()
+        })
+    }
+
+    /**
+       Loads the tags, custom fields and checklist of a ticket with an
+       already locked connection
+     */
+    pub(crate) fn ticket_details(connection: &Connection, ticket: &mut Ticket) -> Result<(), rusqlite::Error> {
+        let mut stmt_tags = connection.prepare_cached("SELECT tag_name FROM ticket_tags WHERE ticket_tags.ticket_id = ?;")?;
+        ticket.tags = stmt_tags.query_map([ticket.id], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<String>, rusqlite::Error>>()?;
+        ticket.custom_fields = Self::ticket_custom_fields(connection, ticket.id);
+        ticket.checklist = Self::ticket_checklist(connection, ticket.id);
+        Ok(())
+    }
+
+    /**
+       Runs a counting expression with an already locked connection
+     */
+    pub(crate) fn ticket_count<P: rusqlite::Params>(connection: &Connection, expression: &str, params: P) -> Result<u64, rusqlite::Error> {
+        connection.query_row(expression, params, |row| row.get::<_, i64>(0)).map(|count| count as u64)
+    }
+
+    /**
+       Custom fields are recorded in their own namespace in the history,
+       otherwise a custom field named like a ticket field, e.g. "title",
+       couldn't be told apart from the ticket field itself
+     */
+    fn custom_field_name(name: &str) -> String {
+        ["custom:", name].join("")
+    }
+
+    /**
+       Compares two versions of the same ticket and returns a history
+       entry for every field, that has been changed. Tags are compared
+       individually, so that each added or removed tag gets it's own entry.
+     */
+    pub(crate) fn ticket_changes(old: &Ticket, new: &Ticket) -> Vec<HistoryEntry> {
+        let mut changes: Vec<(String, String, String)> = vec![];
+
+        if old.bucket_id != new.bucket_id {
+            changes.push(("bucket".to_string(), old.bucket_id.to_string(), new.bucket_id.to_string()));
+        }
+
+        if old.title != new.title {
+            changes.push(("title".to_string(), old.title.clone(), new.title.clone()));
+        }
+
+        if old.state_name != new.state_name {
+            changes.push(("state".to_string(), old.state_name.clone(), new.state_name.clone()));
+        }
+
+        if old.description != new.description {
+            changes.push(("description".to_string(), old.description.clone(), new.description.clone()));
+        }
+
+        if old.assigned_to != new.assigned_to {
+            changes.push(("assigned_to".to_string(), old.assigned_to.clone(), new.assigned_to.clone()));
+        }
+
+        if old.due_at != new.due_at {
+            changes.push(("due_at".to_string(), old.due_at.to_string(), new.due_at.to_string()));
+        }
+
+        if old.priority != new.priority {
+            changes.push(("priority".to_string(), old.priority.get_name(), new.priority.get_name()));
+        }
+
+        if old.recurrence != new.recurrence {
+            changes.push(("recurrence".to_string(), 
+                old.recurrence.as_ref().map(|recurrence| recurrence.to_text()).unwrap_or_default(), 
+                new.recurrence.as_ref().map(|recurrence| recurrence.to_text()).unwrap_or_default()));
+        }
+
+        if old.estimate != new.estimate {
+            changes.push(("estimate".to_string(), WorkLog::format_duration(old.estimate), WorkLog::format_duration(new.estimate)));
+        }
+
+        for tag in &new.tags {
+            if !old.tags.contains(tag) {
+                changes.push(("tag".to_string(), String::default(), tag.clone()));
+            }
+        }
+
+        for tag in &old.tags {
+            if !new.tags.contains(tag) {
+                changes.push(("tag".to_string(), tag.clone(), String::default()));
+            }
+        }
+
+        for (name, value) in &new.custom_fields {
+            let old_value = old.custom_fields.get(name).cloned().unwrap_or_default();
+            if &old_value != value {
+                changes.push((Self::custom_field_name(name), old_value, value.clone()));
+            }
+        }
+
+        for (name, value) in &old.custom_fields {
+            if !new.custom_fields.contains_key(name) {
+                changes.push((Self::custom_field_name(name), value.clone(), String::default()));
+            }
+        }
+
+        // Checklist items have no id, so they are compared by their position,
+        // renaming or checking an item is a single change that way
+        for (old_item, new_item) in old.checklist.iter().zip(new.checklist.iter()) {
+            if old_item.text != new_item.text || old_item.done != new_item.done {
+                changes.push(("checklist".to_string(), old_item.describe(), new_item.describe()));
+            }
+        }
+
+        for item in new.checklist.iter().skip(old.checklist.len()) {
+            changes.push(("checklist".to_string(), String::default(), item.describe()));
+        }
+
+        for item in old.checklist.iter().skip(new.checklist.len()) {
+            changes.push(("checklist".to_string(), item.describe(), String::default()));
         }
+
+        changes.into_iter().map(|(field, old_value, new_value)| {
+            HistoryEntry {
+                adapter: new.adapter.clone(),
+                ticket_id: new.id,
+                ..Default::default()
+            }.with_change(&field, old_value, new_value)
+        }).collect()
+    }
+
+    /**
+       Writes a single ticket with all of it's tags, custom fields, checklist
+       and history with an already locked connection. A ticket with the id 0
+       is added as a new ticket. Returns the ticket like it has been written,
+       so new tickets come back with their id
+     */
+    pub(crate) fn write_ticket(&self, connection: &Connection, ticket: &Ticket, username: &str) -> Result<Ticket, AdapterError> {
+        let mut ticket = ticket.clone();
+
+        // Remember the previous version of the ticket for the history
+        let previous_ticket = if ticket.id != 0 {
+            self.ticket_snapshot(connection, ticket.id)
+        } else {
+            None
+        };
+
+        // Replace Ticket (or add with new id, if id is 0)
+        if ticket.id != 0 {
+
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("REPLACE INTO tickets ");
+            expression.push("(id, bucket_id, title, state_name, description, created_at, due_at, assigned_to, estimate, priority, recurrence) "); 
+            expression.push("VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?); ");
+            parameters.push(Value::Integer(ticket.id));
+            parameters.push(Value::Integer(ticket.bucket_id as i64));
+            parameters.push(Value::Text(ticket.title.clone()));
+            parameters.push(Value::Text(ticket.state_name.clone()));
+            parameters.push(Value::Text(ticket.description.clone()));
+            parameters.push(Value::Integer(ticket.created_at));
+            parameters.push(Value::Integer(ticket.due_at));
+            parameters.push(Value::Text(ticket.assigned_to.clone()));
+            parameters.push(Value::Integer(ticket.estimate));
+            parameters.push(Value::Integer(ticket.priority.get_value()));
+            parameters.push(Value::Text(ticket.recurrence.as_ref().map(|recurrence| recurrence.to_text()).unwrap_or_default()));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+
+        } else {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("INSERT INTO tickets ");
+            expression.push("(bucket_id, title, state_name, description, created_at, due_at, assigned_to, estimate, priority, recurrence) "); 
+            expression.push("VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) returning id; ");
+            parameters.push(Value::Integer(ticket.bucket_id as i64));
+            parameters.push(Value::Text(ticket.title.clone()));
+            parameters.push(Value::Text(ticket.state_name.clone()));
+            parameters.push(Value::Text(ticket.description.clone()));
+            parameters.push(Value::Integer(ticket.created_at));
+            parameters.push(Value::Integer(ticket.due_at));
+            parameters.push(Value::Text(ticket.assigned_to.clone()));
+            parameters.push(Value::Integer(ticket.estimate));
+            parameters.push(Value::Integer(ticket.priority.get_value()));
+            parameters.push(Value::Text(ticket.recurrence.as_ref().map(|recurrence| recurrence.to_text()).unwrap_or_default()));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            match stmt_write.query(rusqlite::params_from_iter(parameters)) {
+                Err(err) => {
+                    return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+                },
+                Ok(mut rows) => {
+                    
+                    let ticket_ref = ticket.clone();
+                    let mut id = 0;
+
+                    while let Some(row) = rows.next()
+                        .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity("New Ticket".to_string()).with_source(err))? {
+                        id = row.get(0)
+                            .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity("New Ticket".to_string()).with_source(err))?;
+                    }
+
+                    ticket = ticket.with_details(id, ticket_ref.title, ticket_ref.description);
+                },
+            };
+        }
+
+        // Delete old tag References of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM ticket_tags WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Add new tag references
+        if !ticket.tags.is_empty() {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("INSERT INTO ticket_tags VALUES ");
+            if let Some((last_tag, tags)) = ticket.tags.split_last() {
+                for tag in tags {
+                    expression.push("(?, ?), ");
+                    parameters.push(Value::Integer(ticket.id));
+                    parameters.push(Value::Text(tag.to_string()));
+                }
+
+                expression.push("(?, ?); ");
+                parameters.push(Value::Integer(ticket.id));
+                parameters.push(Value::Text(last_tag.to_string()));
+            }
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Replace the custom field values of the ticket
+        if let Err(err) = Self::write_custom_fields(connection, &ticket) {
+            return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+        };
+
+        // Replace the checklist of the ticket
+        if let Err(err) = Self::write_checklist(connection, &ticket) {
+            return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+        };
+
+        // Record the changes in the ticket history
+        {
+            let history = match &previous_ticket {
+                Some(previous_ticket) => Self::ticket_changes(previous_ticket, &ticket),
+                None => vec![HistoryEntry {
+                    adapter: self.get_name(),
+                    ticket_id: ticket.id,
+                    ..Default::default()
+                }.with_change("ticket", String::default(), ticket.title.clone())],
+            }.into_iter().map(|entry| entry.with_author(username.to_string())).collect();
+
+            if let Err(err) = Self::write_history(connection, &history) {
+                return Err(AdapterError::new(AdapterErrorType::TicketWrite).with_entity(format!("History of Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        Ok(ticket)
+    }
+
+    /**
+       Commits the transaction of a batch, if every ticket of it succeeded,
+       otherwise it's rolled back. In that case, the tickets, that would
+       have succeeded, get an error as well, since they haven't been
+       stored either
+     */
+    pub(crate) fn finish_transaction<T>(transaction: Transaction, tickets: &[Ticket], results: Vec<Result<T, AdapterError>>, error_type: AdapterErrorType) -> Vec<Result<T, AdapterError>> {
+        if results.iter().any(Result::is_err) {
+            if let Err(err) = transaction.rollback() {
+                println!("There was an error rolling back the batch! Reason: {}", err);
+            }
+
+            return results.into_iter().zip(tickets).map(|(result, ticket)| result.and_then(|_| Err(
+                AdapterError::new(error_type.clone())
+                    .with_entity(format!("Ticket {}", ticket.id))
+                    .with_reason("The batch has been rolled back, because another ticket failed")
+            ))).collect();
+        }
+
+        match transaction.commit() {
+            Ok(_) => results,
+            Err(err) => {
+                let error = AdapterError::new(error_type).with_source(err);
+                tickets.iter().map(|_| Err(error.clone())).collect()
+            },
+        }
+    }
+
+    /**
+       Deletes a single ticket with everything, that belongs to it,
+       with an already locked connection.
+     */
+    pub(crate) fn drop_ticket(&self, connection: &Connection, ticket: &Ticket, username: &str) -> Result<(), AdapterError> {
+        // Delete old tag References of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM ticket_tags WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete custom field values of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM ticket_custom_fields WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete checklist of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM ticket_checklist WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete links from and to the ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM ticket_links WHERE source_id = ? OR (target_adapter = ? AND target_id = ?); ");
+            parameters.push(Value::Integer(ticket.id));
+            parameters.push(Value::Text(self.get_name()));
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete attachments of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM attachments WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete work logs of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM work_logs WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Delete comments of ticket
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM comments WHERE ticket_id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        //Delete ticket itself
+        {
+            let mut expression: Vec<&str> = vec![];
+            let mut parameters: Vec<rusqlite::types::Value> = vec![];
+
+            expression.push("DELETE FROM tickets WHERE id = ?; ");
+            parameters.push(Value::Integer(ticket.id));
+
+            let mut stmt_write = connection.prepare(expression.join("").as_str())
+                .map_err(|err| AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err))?;
+            if let Err(err) = stmt_write.execute(rusqlite::params_from_iter(parameters)) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        // Keep the deletion in the ticket history
+        {
+            let history = vec![HistoryEntry {
+                adapter: self.get_name(),
+                ticket_id: ticket.id,
+                ..Default::default()
+            }.with_change("ticket", ticket.title.clone(), String::default()).with_author(username.to_string())];
+
+            if let Err(err) = Self::write_history(connection, &history) {
+                return Err(AdapterError::new(AdapterErrorType::TicketDelete).with_entity(format!("History of Ticket {}", ticket.id)).with_source(err));
+            };
+        }
+
+        Ok(())
+    }
+
+    /**
+       Writes the supplied history entries into the ticket history table
+       with an already locked connection.
+     */
+    pub(crate) fn write_history(connection: &Connection, entries: &Vec<HistoryEntry>) -> Result<(), rusqlite::Error> {
+        let mut stmt_write = connection.prepare(concat!(
+            "INSERT INTO ticket_history ",
+            "(ticket_id, field, old_value, new_value, changed_by, changed_at) ",
+            "VALUES (?, ?, ?, ?, ?, ?);"))?;
+
+        for entry in entries {
+            stmt_write.execute(rusqlite::params_from_iter(vec![
+                Value::Integer(entry.ticket_id),
+                Value::Text(entry.field.clone()),
+                Value::Text(entry.old_value.clone()),
+                Value::Text(entry.new_value.clone()),
+                Value::Text(entry.changed_by.clone()),
+                Value::Integer(entry.changed_at)
+            ]))?;
+        }
+
+        Ok(())
+    }
+
+    /**
+       Reads the custom field values of a single ticket with an
+       already locked connection. Fields without a value are not
+       part of the returned map.
+     */
+    pub(crate) fn ticket_custom_fields(connection: &Connection, id: i64) -> BTreeMap<String, String> {
+        let mut custom_fields: BTreeMap<String, String> = BTreeMap::new();
+
+        match connection.prepare("SELECT field_name, value FROM ticket_custom_fields WHERE ticket_id = ?;") {
+            Ok(mut stmt_select) => {
+                if let Ok(iter) = stmt_select.query_map([id], |row| Ok((row.get(0)?, row.get(1)?))) {
+                    for (name, value) in iter.flatten() {
+                        custom_fields.insert(name, value);
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list custom fields of ticket {} on local, {}", id, err)
+        }
+
+        custom_fields
+    }
+
+    /**
+       Replaces the custom field values of a ticket with an already
+       locked connection. Empty values are not stored.
+     */
+    pub(crate) fn write_custom_fields(connection: &Connection, ticket: &Ticket) -> Result<(), rusqlite::Error> {
+        connection.execute("DELETE FROM ticket_custom_fields WHERE ticket_id = ?;", [ticket.id])?;
+
+        let mut stmt_write = connection.prepare(
+            "INSERT INTO ticket_custom_fields (ticket_id, field_name, value) VALUES (?, ?, ?);")?;
+
+        for (name, value) in &ticket.custom_fields {
+            if value.is_empty() {
+                continue;
+            }
+
+            stmt_write.execute(rusqlite::params_from_iter(vec![
+                Value::Integer(ticket.id),
+                Value::Text(name.clone()),
+                Value::Text(value.clone())
+            ]))?;
+        }
+
+        Ok(())
+    }
+
+    /**
+       Reads the checklist of a single ticket in it's stored order
+       with an already locked connection.
+     */
+    pub(crate) fn ticket_checklist(connection: &Connection, id: i64) -> Vec<ChecklistItem> {
+        let mut checklist: Vec<ChecklistItem> = vec![];
+
+        match connection.prepare("SELECT text, done FROM ticket_checklist WHERE ticket_id = ? ORDER BY position;") {
+            Ok(mut stmt_select) => {
+                if let Ok(iter) = stmt_select.query_map([id], |row| Ok(ChecklistItem::new(row.get(0)?, row.get(1)?))) {
+                    for item in iter.flatten() {
+                        checklist.push(item);
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list checklist of ticket {} on local, {}", id, err)
+        }
+
+        checklist
+    }
+
+    /**
+       Replaces the checklist of a ticket with an already locked
+       connection. The position of the items is kept.
+     */
+    pub(crate) fn write_checklist(connection: &Connection, ticket: &Ticket) -> Result<(), rusqlite::Error> {
+        connection.execute("DELETE FROM ticket_checklist WHERE ticket_id = ?;", [ticket.id])?;
+
+        let mut stmt_write = connection.prepare(
+            "INSERT INTO ticket_checklist (ticket_id, position, text, done) VALUES (?, ?, ?, ?);")?;
+
+        for (position, item) in ticket.checklist.iter().enumerate() {
+            stmt_write.execute(rusqlite::params_from_iter(vec![
+                Value::Integer(ticket.id),
+                Value::Integer(position as i64),
+                Value::Text(item.text.clone()),
+                Value::Integer(item.done as i64)
+            ]))?;
+        }
+
+        Ok(())
     }
 
     fn list_builtin_filters(&self) -> Vec<Filter> {
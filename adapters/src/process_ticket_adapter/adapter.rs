@@ -0,0 +1,316 @@
+use std::{path::Path, sync::{Arc, Mutex}, time::Duration};
+
+use serde_json::json;
+
+use tickets_rs_core::{
+    Bucket,
+    Ticket,
+    TicketPage,
+    TicketPageRequest,
+    Comment,
+    HistoryEntry,
+    CustomField,
+    TicketLink,
+    Attachment,
+    WorkLog,
+    State,
+    Filter,
+    Tag,
+    TicketAdapter,
+    AdapterError,
+    AdapterErrorType, AdapterCapabilities, AdapterStatus, Config, AppConfig, TicketProvider, EventBus, ProviderEvent
+};
+
+use super::{
+    ProcessTicketAdapter,
+    ProcessConnection
+};
+
+impl TicketAdapter for ProcessTicketAdapter {
+
+    fn get_type_name() -> String where Self: Sized {
+        "process".to_string()
+    }
+
+    fn get_fancy_type_name() -> String where Self: Sized {
+        "External Process".to_string()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn create_config() -> Config where Self: Sized {
+        TicketProvider::get_default_config::<Self>()
+            .with("executable", "", "string")
+            .with("arguments", "", "string")
+            .with("timeout", 30, "number")
+            .with("icon", "", "string")
+    }
+
+    fn from_config(_app_config: Arc<Mutex<AppConfig>>, config: &Config, events: EventBus) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized {
+
+        let name: String = match config.get("name") {
+            Some(option) => match option.get() {
+                Some(result) => result,
+                None => return Err(AdapterError::new(AdapterErrorType::Instantiation)),
+            },
+            None => return Err(AdapterError::new(AdapterErrorType::Instantiation)),
+        };
+
+        let display_name: String = match config.get("display") {
+            Some(option) => match option.get() {
+                Some(result) => result,
+                None => return Err(AdapterError::new(AdapterErrorType::Instantiation)),
+            },
+            None => return Err(AdapterError::new(AdapterErrorType::Instantiation)),
+        };
+
+        let executable: String = match config.get("executable").and_then(|option| option.get::<String>()) {
+            Some(executable) if !executable.is_empty() => executable,
+            _ => return Err(AdapterError::new(AdapterErrorType::Instantiation).with_reason("no executable has been configured")),
+        };
+
+        let arguments: Vec<String> = config.get("arguments")
+            .map(|option| ProcessTicketAdapter::split_arguments(option.raw()))
+            .unwrap_or_default();
+
+        let timeout = config.get("timeout")
+            .and_then(|option| option.get::<i32>())
+            .filter(|timeout| *timeout > 0)
+            .unwrap_or(30);
+
+        let icon = config.get("icon")
+            .map(|option| option.raw().clone())
+            .unwrap_or_default();
+
+        let timeout = Duration::from_secs(timeout as u64);
+        let connection = ProcessConnection::spawn(&executable, &arguments, timeout)?;
+
+        let adapter = ProcessTicketAdapter {
+            name,
+            display_name,
+            icon: icon.into(),
+            config: config.clone(),
+            executable,
+            arguments,
+            timeout,
+            info: Default::default(),
+            connection: Mutex::new(connection),
+            status: Default::default(),
+            events: events.clone()
+        };
+
+        let info = adapter.initialize()?;
+
+        adapter.set_info(info);
+        adapter.update_status(|status| status.mark_synced());
+
+        events.publish(ProviderEvent::AdapterLoaded(adapter.get_name()));
+
+        Ok(Box::new(adapter))
+    }
+
+    fn get_icon(&self) -> &Path {
+        self.icon.as_path()
+    }
+
+    fn get_fancy_name(&self) -> String {
+        self.display_name.clone()
+    }
+
+    fn get_capabilities(&self) -> AdapterCapabilities {
+        self.get_info().capabilities.into_iter()
+            .fold(AdapterCapabilities::read_only(), |capabilities, capability| capabilities.with(capability))
+    }
+
+    fn get_status(&self) -> AdapterStatus {
+        self.update_status(|status| status.clone()).unwrap_or_default()
+    }
+
+    fn retry(&self) -> Result<(), AdapterError> {
+        self.update_status(|status| status.mark_loading());
+
+        if let Err(err) = self.restart() {
+            self.update_status(|status| status.mark_failed(err.clone()));
+            return Err(err);
+        }
+
+        self.update_status(|status| status.mark_synced());
+        self.events.publish(ProviderEvent::AdapterLoaded(self.get_name()));
+        Ok(())
+    }
+
+    fn bucket_list_all(&self) -> Vec<Bucket> {
+        self.call_or_default("bucket_list_all", json!({}))
+    }
+
+    fn bucket_list_unique(&self, id: u64) -> Option<Bucket> {
+        self.call_or_default("bucket_list_unique", json!({ "id": id }))
+    }
+
+    fn bucket_drop(&self, bucket: &Bucket) -> Result<(), AdapterError> {
+        self.call("bucket_drop", json!({ "bucket": bucket }), AdapterErrorType::BucketDelete)
+    }
+
+    fn bucket_write(&self, bucket: &mut Bucket) -> Result<(), AdapterError> {
+        *bucket = self.call("bucket_write", json!({ "bucket": bucket }), AdapterErrorType::BucketWrite)?;
+        Ok(())
+    }
+
+    fn ticket_list_all(&self) -> Vec<Ticket> {
+        self.call_or_default("ticket_list_all", json!({}))
+    }
+
+    fn ticket_list_unique(&self, id: i64) -> Option<Ticket> {
+        self.call_or_default("ticket_list_unique", json!({ "id": id }))
+    }
+
+    fn ticket_list(&self, expression: &str) -> Result<Vec<Ticket>, AdapterError> {
+        self.call("ticket_list", json!({ "expression": expression }), AdapterErrorType::Access)
+    }
+
+    fn ticket_list_all_page(&self, page: TicketPageRequest) -> TicketPage {
+        match self.get_info().paging {
+            true => self.call("ticket_list_all_page", json!({ "page": page }), AdapterErrorType::Access)
+                .unwrap_or_else(|err| {
+                    println!("{}", err);
+                    TicketPage::new(vec![], page, 0)
+                }),
+            false => TicketPage::from_tickets(self.ticket_list_all(), page),
+        }
+    }
+
+    fn ticket_list_page(&self, expression: &str, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        match self.get_info().paging {
+            true => self.call("ticket_list_page", json!({ "expression": expression, "page": page }), AdapterErrorType::Access),
+            false => self.ticket_list(expression).map(|tickets| TicketPage::from_tickets(tickets, page)),
+        }
+    }
+
+    fn ticket_write(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        self.call("ticket_write", json!({ "ticket": ticket }), AdapterErrorType::TicketWrite)
+    }
+
+    fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        self.call("ticket_drop", json!({ "ticket": ticket }), AdapterErrorType::TicketDelete)
+    }
+
+    fn ticket_history(&self, id: i64) -> Vec<HistoryEntry> {
+        self.call_or_default("ticket_history", json!({ "id": id }))
+    }
+
+    fn comment_list(&self, ticket: &Ticket) -> Vec<Comment> {
+        self.call_or_default("comment_list", json!({ "ticket": ticket }))
+    }
+
+    fn comment_write(&self, comment: &Comment) -> Result<(), AdapterError> {
+        self.call("comment_write", json!({ "comment": comment }), AdapterErrorType::CommentWrite)
+    }
+
+    fn comment_drop(&self, comment: &Comment) -> Result<(), AdapterError> {
+        self.call("comment_drop", json!({ "comment": comment }), AdapterErrorType::CommentDelete)
+    }
+
+    fn link_list(&self, ticket: &Ticket) -> Vec<TicketLink> {
+        self.call_or_default("link_list", json!({ "ticket": ticket }))
+    }
+
+    fn link_write(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        self.call("link_write", json!({ "link": link }), AdapterErrorType::LinkWrite)
+    }
+
+    fn link_drop(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        self.call("link_drop", json!({ "link": link }), AdapterErrorType::LinkDelete)
+    }
+
+    fn attachment_list(&self, ticket: &Ticket) -> Vec<Attachment> {
+        self.call_or_default("attachment_list", json!({ "ticket": ticket }))
+    }
+
+    fn attachment_add(&self, attachment: &Attachment, data: &[u8]) -> Result<(), AdapterError> {
+        self.call("attachment_add", json!({ "attachment": attachment, "data": data }), AdapterErrorType::AttachmentWrite)
+    }
+
+    fn attachment_drop(&self, attachment: &Attachment) -> Result<(), AdapterError> {
+        self.call("attachment_drop", json!({ "attachment": attachment }), AdapterErrorType::AttachmentDelete)
+    }
+
+    fn attachment_read(&self, attachment: &Attachment) -> Option<Vec<u8>> {
+        self.call_or_default("attachment_read", json!({ "attachment": attachment }))
+    }
+
+    fn work_log_list(&self, ticket: &Ticket) -> Vec<WorkLog> {
+        self.call_or_default("work_log_list", json!({ "ticket": ticket }))
+    }
+
+    fn work_log_list_range(&self, from: i64, to: i64) -> Vec<WorkLog> {
+        self.call_or_default("work_log_list_range", json!({ "from": from, "to": to }))
+    }
+
+    fn work_log_write(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        self.call("work_log_write", json!({ "work_log": work_log }), AdapterErrorType::WorkLogWrite)
+    }
+
+    fn work_log_drop(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        self.call("work_log_drop", json!({ "work_log": work_log }), AdapterErrorType::WorkLogDelete)
+    }
+
+    fn custom_field_list_all(&self) -> Vec<CustomField> {
+        self.call_or_default("custom_field_list_all", json!({}))
+    }
+
+    fn custom_field_write(&self, field: &CustomField) -> Result<(), AdapterError> {
+        self.call("custom_field_write", json!({ "field": field }), AdapterErrorType::CustomFieldWrite)
+    }
+
+    fn custom_field_drop(&self, field: &CustomField) -> Result<(), AdapterError> {
+        self.call("custom_field_drop", json!({ "field": field }), AdapterErrorType::CustomFieldDelete)
+    }
+
+    fn state_list_all(&self) -> Vec<State> {
+        self.call_or_default("state_list_all", json!({}))
+    }
+
+    fn state_write(&self, state: &State) -> Result<(), AdapterError> {
+        self.call("state_write", json!({ "state": state }), AdapterErrorType::StateWrite)
+    }
+
+    fn tag_list_all(&self) -> Vec<Tag> {
+        self.call_or_default("tag_list_all", json!({}))
+    }
+
+    fn tag_write(&self, tag: &Tag) -> Result<(), AdapterError> {
+        self.call("tag_write", json!({ "tag": tag }), AdapterErrorType::TagWrite)
+    }
+
+    fn tag_drop(&self, tag: &Tag) -> Result<(), AdapterError> {
+        self.call("tag_drop", json!({ "tag": tag }), AdapterErrorType::TagDelete)
+    }
+
+    fn filter_list_all(&self) -> Vec<Filter> {
+        self.call_or_default("filter_list_all", json!({}))
+    }
+
+    fn filter_list(&self, filter_name: String) -> Option<Filter> {
+        self.call_or_default("filter_list", json!({ "name": filter_name }))
+    }
+
+    fn filter_write(&self, filter: &Filter) -> Result<(), AdapterError> {
+        self.call("filter_write", json!({ "filter": filter }), AdapterErrorType::FilterWrite)
+    }
+
+    fn filter_drop(&self, filter: &Filter) -> Result<(), AdapterError> {
+        self.call("filter_drop", json!({ "filter": filter }), AdapterErrorType::FilterDelete)
+    }
+
+    fn filter_expression_validate(&self, expression: &String) -> Result<(), Vec<(String, String)>> {
+        let errors: Vec<(String, String)> = self.call("filter_expression_validate", json!({ "expression": expression }), AdapterErrorType::Access)
+            .map_err(|err| vec![("operation".to_string(), err.get_text())])?;
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+mod adapter;
+mod process_tests;
+
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Mutex
+    },
+    thread,
+    time::Duration
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use tickets_rs_core::{
+    AdapterCapability,
+    AdapterError,
+    AdapterErrorType,
+    AdapterState,
+    AdapterStatus,
+    Config,
+    EventBus,
+    ProviderEvent
+};
+
+/**
+   The version of the protocol, that is described in PROCESS_ADAPTER.md.
+   It is sent to the process on initialization.
+ */
+pub const PROCESS_PROTOCOL_VERSION: u32 = 1;
+
+/**
+   An adapter, that launches an external executable and forwards every
+   call to it as a JSON-RPC request over stdin and stdout. This way adapters
+   can be written in any language, without touching the app itself.
+ */
+pub struct ProcessTicketAdapter {
+    name: String,
+    display_name: String,
+    icon: PathBuf,
+    config: Config,
+    executable: String,
+    arguments: Vec<String>,
+    timeout: Duration,
+    info: Mutex<ProcessInfo>,
+    connection: Mutex<ProcessConnection>,
+    status: Mutex<AdapterStatus>,
+    events: EventBus
+}
+
+/**
+   The running process together with the pipes to talk to it. Every line,
+   the process writes to stdout, is read on a separate thread, so a request
+   can give up waiting after the timeout.
+ */
+pub(crate) struct ProcessConnection {
+    child: Child,
+    stdin: ChildStdin,
+    responses: Receiver<String>,
+    next_id: u64,
+    timeout: Duration,
+    timed_out: bool
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    id: Option<u64>,
+    result: Option<Value>,
+    error: Option<RpcError>
+}
+
+/**
+   The error, the process answered a request with. It means, that the
+   method failed, but the process itself is still working
+ */
+#[derive(Deserialize)]
+pub(crate) struct RpcError {
+    code: i64,
+    message: String
+}
+
+impl Display for RpcError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
+
+/**
+   What the process answers to the initialize request
+ */
+#[derive(Deserialize, Default, Clone)]
+#[serde(default)]
+pub(crate) struct ProcessInfo {
+    pub capabilities: Vec<AdapterCapability>,
+    pub paging: bool
+}
+
+impl ProcessConnection {
+
+    /**
+       Launches the executable with the given arguments. Stderr is passed
+       through, so the process can use it for logging
+     */
+    pub(crate) fn spawn(executable: &str, arguments: &[String], timeout: Duration) -> Result<Self, AdapterError> {
+        let mut child = Command::new(executable)
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| AdapterError::new(AdapterErrorType::Instantiation).with_entity(format!("Process \"{}\"", executable)).with_source(err))?;
+
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(AdapterError::new(AdapterErrorType::Instantiation).with_reason("the pipes of the process are not available"));
+        };
+
+        let (sender, responses) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                match line {
+                    Ok(line) => if sender.send(line).is_err() {
+                        break;
+                    },
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(ProcessConnection { child, stdin, responses, next_id: 1, timeout, timed_out: false })
+    }
+
+    /**
+       Sends a request and waits for the response with the same id. Lines,
+       that are no response to this request, like late answers to requests,
+       that already timed out, are skipped. The outer error means, that the
+       process couldn't be reached, the inner one, that the method failed.
+       Once a request timed out, the process is considered stuck and all
+       further requests fail right away, until it's restarted
+     */
+    pub(crate) fn request(&mut self, method: &str, params: Value) -> Result<Result<Value, RpcError>, AdapterError> {
+        if self.timed_out {
+            return Err(AdapterError::new(AdapterErrorType::Access)
+                .with_reason("the process stopped answering, retry the adapter to restart it"));
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))?;
+
+        loop {
+            let line = match self.responses.recv_timeout(self.timeout) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => {
+                    self.timed_out = true;
+                    return Err(AdapterError::new(AdapterErrorType::Access)
+                        .with_reason(format!("the process didn't answer \"{}\" within {} seconds", method, self.timeout.as_secs())))
+                },
+                Err(RecvTimeoutError::Disconnected) => return Err(AdapterError::new(AdapterErrorType::Access)
+                    .with_reason("the process has exited")),
+            };
+
+            let response: RpcResponse = match serde_json::from_str(&line) {
+                Ok(response) => response,
+                Err(err) => {
+                    println!("Skipped invalid line from process: {}", err);
+                    continue;
+                }
+            };
+
+            if response.id != Some(id) {
+                continue;
+            }
+
+            return Ok(match (response.result, response.error) {
+                (_, Some(error)) => Err(error),
+                (Some(result), None) => Ok(result),
+                (None, None) => Ok(Value::Null),
+            });
+        }
+    }
+
+    /**
+       Sends a notification, that the process doesn't answer
+     */
+    pub(crate) fn notify(&mut self, method: &str) -> Result<(), AdapterError> {
+        self.send(json!({ "jsonrpc": "2.0", "method": method }))
+    }
+
+    fn send(&mut self, message: Value) -> Result<(), AdapterError> {
+        writeln!(self.stdin, "{}", message)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|err| AdapterError::new(AdapterErrorType::Access).with_source(err))
+    }
+}
+
+impl Drop for ProcessConnection {
+
+    /**
+       Asks the process to shut down and kills it, if it is still
+       running after a short moment
+     */
+    fn drop(&mut self) {
+        let _ = self.notify("shutdown");
+
+        for _ in 0..10 {
+            if let Ok(Some(_)) = self.child.try_wait() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl ProcessTicketAdapter {
+
+    /**
+       Sends the initialize request, that has to be the first one after
+       launching the process, and returns it's answer
+     */
+    pub(crate) fn initialize(&self) -> Result<ProcessInfo, AdapterError> {
+        let params = json!({
+            "protocol_version": PROCESS_PROTOCOL_VERSION,
+            "name": self.name,
+            "config": self.config
+        });
+
+        let result = match self.connection.lock() {
+            Ok(mut lock) => lock.request("initialize", params)?,
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Instantiation).with_reason(err)),
+        };
+
+        result
+            .map_err(|error| AdapterError::new(AdapterErrorType::Instantiation).with_reason(error))
+            .and_then(|result| serde_json::from_value(result)
+                .map_err(|err| AdapterError::new(AdapterErrorType::Instantiation).with_entity("Result of \"initialize\"").with_source(err)))
+    }
+
+    /**
+       Launches the process again and replaces the old one, which is shut down.
+       The new process may support other capabilities, so they are taken
+       from it's answer to initialize again
+     */
+    pub(crate) fn restart(&self) -> Result<(), AdapterError> {
+        let connection = ProcessConnection::spawn(&self.executable, &self.arguments, self.timeout)?;
+
+        match self.connection.lock() {
+            Ok(mut lock) => *lock = connection,
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Instantiation).with_reason(err)),
+        }
+
+        let info = self.initialize()?;
+        self.set_info(info);
+        Ok(())
+    }
+
+    pub(crate) fn set_info(&self, info: ProcessInfo) {
+        match self.info.lock() {
+            Ok(mut lock) => *lock = info,
+            Err(err) => println!("Wasn't able to lock the info of the \"{}\" adapter due to {}", self.name, err),
+        }
+    }
+
+    pub(crate) fn get_info(&self) -> ProcessInfo {
+        match self.info.lock() {
+            Ok(lock) => lock.clone(),
+            Err(err) => {
+                println!("Wasn't able to lock the info of the \"{}\" adapter due to {}", self.name, err);
+                ProcessInfo::default()
+            }
+        }
+    }
+
+    /**
+       Splits the configured arguments at whitespace, like a shell would.
+       Arguments in single or double quotes are kept together, and a
+       backslash takes the next character literally, except within single
+       quotes
+     */
+    pub(crate) fn split_arguments(arguments: &str) -> Vec<String> {
+        let mut result: Vec<String> = vec![];
+        let mut current: Option<String> = None;
+        let mut quote: Option<char> = None;
+        let mut characters = arguments.chars();
+
+        while let Some(character) = characters.next() {
+            match (quote, character) {
+                (Some('\''), '\'') => quote = None,
+                (Some('\''), _) => current.get_or_insert_with(String::new).push(character),
+                (_, '\\') => {
+                    let argument = current.get_or_insert_with(String::new);
+                    if let Some(escaped) = characters.next() {
+                        argument.push(escaped);
+                    }
+                },
+                (Some('"'), '"') => quote = None,
+                (Some(_), _) => current.get_or_insert_with(String::new).push(character),
+                (None, '"' | '\'') => {
+                    quote = Some(character);
+                    current.get_or_insert_with(String::new);
+                },
+                (None, _) if character.is_whitespace() => result.extend(current.take()),
+                (None, _) => current.get_or_insert_with(String::new).push(character),
+            }
+        }
+
+        result.extend(current);
+        result
+    }
+
+    /**
+       Calls a method of the process and reads the result into the expected
+       type. Failures are returned with the given error type, so they read
+       like the failures of any other adapter. If the process can't be
+       reached, the adapter is marked as failed and the failure is published
+       once
+     */
+    pub(crate) fn call<R: DeserializeOwned>(&self, method: &str, params: Value, error_type: AdapterErrorType) -> Result<R, AdapterError> {
+        let response = match self.connection.lock() {
+            Ok(mut lock) => lock.request(method, params),
+            Err(err) => Err(AdapterError::new(AdapterErrorType::Access).with_reason(err)),
+        };
+
+        let result = match response {
+            Ok(Ok(result)) => {
+                self.update_status(|status| status.mark_synced());
+                result
+            },
+            Ok(Err(error)) => return Err(AdapterError::new(error_type).with_reason(error)),
+            Err(err) => {
+                let already_failed = self.update_status(|status| {
+                    let already_failed = status.state == AdapterState::Error;
+                    status.mark_failed(err.clone());
+                    already_failed
+                });
+
+                if already_failed == Some(false) {
+                    self.events.publish(ProviderEvent::AdapterFailed(self.name.clone(), err.clone()));
+                }
+
+                return Err(AdapterError::new(error_type).with_source(err));
+            },
+        };
+
+        serde_json::from_value(result)
+            .map_err(|err| AdapterError::new(error_type).with_entity(format!("Result of \"{}\"", method)).with_source(err))
+    }
+
+    /**
+       Calls a method, that only reads. If the call fails, the error is
+       printed and the default value (like an empty list) is returned
+     */
+    pub(crate) fn call_or_default<R: DeserializeOwned + Default>(&self, method: &str, params: Value) -> R {
+        match self.call(method, params, AdapterErrorType::Access) {
+            Ok(result) => result,
+            Err(err) => {
+                println!("{}", err);
+                R::default()
+            }
+        }
+    }
+
+    pub(crate) fn update_status<T, F: FnOnce(&mut AdapterStatus) -> T>(&self, update: F) -> Option<T> {
+        match self.status.lock() {
+            Ok(mut lock) => Some(update(&mut lock)),
+            Err(err) => {
+                println!("Wasn't able to lock the status of the \"{}\" adapter due to {}", self.name, err);
+                None
+            }
+        }
+    }
+}
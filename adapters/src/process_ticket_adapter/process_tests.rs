@@ -0,0 +1,94 @@
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+    use std::{fs, process, sync::{Arc, Mutex}, time::{Duration, Instant}};
+    use tickets_rs_core::{AdapterCapability, AdapterErrorType, AdapterState, AppConfig, EventBus, LocalDatabase, Tag, TicketAdapter};
+
+    use crate::process_ticket_adapter::ProcessTicketAdapter;
+
+    #[test]
+    fn test_process_adapter() {
+        let script = [
+            "read line",
+            "echo 'not a response'",
+            "echo '{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{\"capabilities\":[\"tags\"]}}'",
+            "read line",
+            "echo '{\"jsonrpc\":\"2.0\",\"id\":2,\"result\":[{\"adapter\":\"script\",\"name\":\"urgent\",\"color\":\"#ff0000ff\",\"color_text\":\"#ffffffff\"}]}'",
+            "read line",
+            "echo '{\"jsonrpc\":\"2.0\",\"id\":3,\"error\":{\"code\":1,\"message\":\"tag is locked\"}}'",
+            "read line"
+        ].join("\n");
+
+        let script_path = std::env::temp_dir().join(format!("tickets_rs_process_test_{}.sh", process::id()));
+        fs::write(&script_path, script).unwrap();
+
+        let database = Arc::new(Mutex::new(LocalDatabase::open(":memory:".to_string()).unwrap()));
+        let config = ProcessTicketAdapter::create_config()
+            .with("name", "script", "string")
+            .with("executable", "sh", "string")
+            .with("arguments", script_path.to_string_lossy().to_string(), "string");
+
+        let adapter = ProcessTicketAdapter::from_config(
+            Arc::new(Mutex::new(AppConfig::new(database))),
+            &config,
+            EventBus::default()).unwrap();
+
+        assert!(adapter.get_capabilities().supports(AdapterCapability::Tags));
+        assert!(!adapter.get_capabilities().supports(AdapterCapability::TicketWrite));
+
+        let tags = adapter.tag_list_all();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "urgent");
+
+        let error = adapter.tag_write(&Tag::default()).unwrap_err();
+        assert!(error.error_type == AdapterErrorType::TagWrite);
+        assert!(error.get_text().contains("tag is locked"));
+
+        drop(adapter);
+        let _ = fs::remove_file(script_path);
+    }
+
+    #[test]
+    fn test_process_adapter_timeout() {
+        let script = [
+            "read line",
+            "echo '{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}'",
+            "read line",
+            "sleep 5"
+        ].join("\n");
+
+        let script_path = std::env::temp_dir().join(format!("tickets_rs_process_timeout_test_{}.sh", process::id()));
+        fs::write(&script_path, script).unwrap();
+
+        let database = Arc::new(Mutex::new(LocalDatabase::open(":memory:".to_string()).unwrap()));
+        let config = ProcessTicketAdapter::create_config()
+            .with("name", "script", "string")
+            .with("executable", "sh", "string")
+            .with("arguments", format!("\"{}\"", script_path.to_string_lossy()), "string")
+            .with("timeout", 1, "number");
+
+        let adapter = ProcessTicketAdapter::from_config(
+            Arc::new(Mutex::new(AppConfig::new(database))),
+            &config,
+            EventBus::default()).unwrap();
+
+        assert!(adapter.tag_list_all().is_empty());
+        assert!(adapter.get_status().state == AdapterState::Error);
+
+        // The process is stuck, so further calls don't wait for the timeout again
+        let started = Instant::now();
+        assert!(adapter.tag_write(&Tag::default()).is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        drop(adapter);
+        let _ = fs::remove_file(script_path);
+    }
+
+    #[test]
+    fn test_split_arguments() {
+        assert_eq!(ProcessTicketAdapter::split_arguments("  --a  b "), vec!["--a", "b"]);
+        assert_eq!(ProcessTicketAdapter::split_arguments("\"/my scripts/adapter.py\" --name 'my tickets'"),
+            vec!["/my scripts/adapter.py", "--name", "my tickets"]);
+        assert_eq!(ProcessTicketAdapter::split_arguments("a\\ b \"\" 'it\\s'"), vec!["a b", "", "it\\s"]);
+    }
+}
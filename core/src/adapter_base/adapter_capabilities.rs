@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+
+/**
+   A single thing, an adapter is able to change. Reading is always
+   possible, so only writing and deleting is described here.
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum AdapterCapability {
+    TicketWrite,
+    TicketDelete,
+    Tags,
+    States,
+    Buckets,
+    Filters,
+    Comments,
+    Links,
+    Attachments,
+    WorkLogs,
+    CustomFields
+}
+
+impl AdapterCapability {
+
+    /**
+       Returns all capabilities, an adapter can have
+     */
+    pub fn all() -> Vec<AdapterCapability> {
+        vec![
+            AdapterCapability::TicketWrite,
+            AdapterCapability::TicketDelete,
+            AdapterCapability::Tags,
+            AdapterCapability::States,
+            AdapterCapability::Buckets,
+            AdapterCapability::Filters,
+            AdapterCapability::Comments,
+            AdapterCapability::Links,
+            AdapterCapability::Attachments,
+            AdapterCapability::WorkLogs,
+            AdapterCapability::CustomFields
+        ]
+    }
+
+    /**
+       Returns a human readable description of the capability, that
+       completes a sentence like "The Adapter doesn't support ..."
+     */
+    pub fn get_text(&self) -> String {
+        match self {
+            AdapterCapability::TicketWrite => "writing Tickets",
+            AdapterCapability::TicketDelete => "deleting Tickets",
+            AdapterCapability::Tags => "changing Tags",
+            AdapterCapability::States => "changing States",
+            AdapterCapability::Buckets => "changing Buckets",
+            AdapterCapability::Filters => "changing custom Filters",
+            AdapterCapability::Comments => "changing Comments",
+            AdapterCapability::Links => "changing Ticket Links",
+            AdapterCapability::Attachments => "changing Attachments",
+            AdapterCapability::WorkLogs => "changing Work Logs",
+            AdapterCapability::CustomFields => "changing Custom Fields",
+        }.to_string()
+    }
+}
+
+/**
+   The set of capabilities, an adapter declares. The ticket provider
+   and the user interface use it to only offer actions, that the
+   adapter can actually do.
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Clone, Debug, Default)]
+pub struct AdapterCapabilities {
+    capabilities: BTreeSet<AdapterCapability>
+}
+
+impl AdapterCapabilities {
+
+    /**
+       Creates a capability set, that supports everything
+     */
+    pub fn all() -> Self {
+        AdapterCapabilities {
+            capabilities: AdapterCapability::all().into_iter().collect()
+        }
+    }
+
+    /**
+       Creates a capability set, that supports nothing but reading
+     */
+    pub fn read_only() -> Self {
+        AdapterCapabilities::default()
+    }
+
+    pub fn with(mut self, capability: AdapterCapability) -> Self {
+        self.capabilities.insert(capability);
+        self
+    }
+
+    pub fn without(mut self, capability: AdapterCapability) -> Self {
+        self.capabilities.remove(&capability);
+        self
+    }
+
+    pub fn supports(&self, capability: AdapterCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.capabilities.is_empty()
+    }
+}
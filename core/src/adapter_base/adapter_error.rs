@@ -1,10 +1,16 @@
-use std::fmt::{
-    Display, 
-    Formatter,
-    Debug,
-    Result
+use std::{
+    error::Error,
+    fmt::{
+        Display, 
+        Formatter,
+        Debug,
+        Result
+    },
+    sync::Arc
 };
 
+use super::adapter_capabilities::AdapterCapability;
+
 type Location = String;
 type Message = String;
 type ErrorsVec = Vec<(Location, Message)>;
@@ -13,6 +19,16 @@ type ErrorsVec = Vec<(Location, Message)>;
 pub enum AdapterErrorType {
     TicketWrite,
     TicketDelete,
+    CommentWrite,
+    CommentDelete,
+    CustomFieldWrite,
+    CustomFieldDelete,
+    LinkWrite,
+    LinkDelete,
+    AttachmentWrite,
+    AttachmentDelete,
+    WorkLogWrite,
+    WorkLogDelete,
     BucketWrite,
     BucketDelete,
     TagWrite,
@@ -21,28 +37,179 @@ pub enum AdapterErrorType {
     FilterWrite,
     FilterDelete,
     Access,
+    Unsupported(AdapterCapability),
     Validate(ErrorsVec, String),
     Expression(String),
     Instantiation
 }
 
-#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Clone)]
+/**
+   The error, that adapters and the ticket provider return. Next to the
+   kind of operation, that failed, it can name the entity involved and
+   keep the underlying error (like the one from the database or the
+   network), so the whole cause chain can be shown to the user.
+ */
+#[derive(Clone)]
 pub struct AdapterError {
-    pub error_type: AdapterErrorType
+    pub error_type: AdapterErrorType,
+    pub entity: Option<String>,
+    source: Option<Arc<dyn Error + Send + Sync>>
 }
 
+/**
+   A cause, that only consists of a message. It is used for errors,
+   that can't be kept around, like poisoned locks
+ */
+#[derive(Debug)]
+struct ErrorReason(String);
+
+impl Display for ErrorReason {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ErrorReason {}
+
 impl AdapterError {
     pub fn new(error_type: AdapterErrorType) -> Self {
-        AdapterError { error_type }
+        AdapterError { error_type, entity: None, source: None }
     }
 
+    /**
+       Names the entity, the failed operation was done on,
+       for example "Ticket 12"
+     */
+    pub fn with_entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    /**
+       Keeps the error, that caused this one
+     */
+    pub fn with_source<E: Error + Send + Sync + 'static>(mut self, source: E) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /**
+       Keeps the message of an error, that caused this one, but can't be
+       kept itself
+     */
+    pub fn with_reason(self, reason: impl Display) -> Self {
+        self.with_source(ErrorReason(reason.to_string()))
+    }
+
+    /**
+       Returns the operation, that failed, like "write" or "delete"
+     */
+    pub fn get_operation(&self) -> &'static str {
+        match &self.error_type {
+            AdapterErrorType::TicketWrite |
+            AdapterErrorType::CommentWrite |
+            AdapterErrorType::CustomFieldWrite |
+            AdapterErrorType::LinkWrite |
+            AdapterErrorType::AttachmentWrite |
+            AdapterErrorType::WorkLogWrite |
+            AdapterErrorType::BucketWrite |
+            AdapterErrorType::TagWrite |
+            AdapterErrorType::StateWrite |
+            AdapterErrorType::FilterWrite => "write",
+            AdapterErrorType::TicketDelete |
+            AdapterErrorType::CommentDelete |
+            AdapterErrorType::CustomFieldDelete |
+            AdapterErrorType::LinkDelete |
+            AdapterErrorType::AttachmentDelete |
+            AdapterErrorType::WorkLogDelete |
+            AdapterErrorType::BucketDelete |
+            AdapterErrorType::TagDelete |
+            AdapterErrorType::FilterDelete => "delete",
+            AdapterErrorType::Access => "access",
+            AdapterErrorType::Unsupported(_) => "unsupported",
+            AdapterErrorType::Validate(_, _) => "validate",
+            AdapterErrorType::Expression(_) => "evaluate",
+            AdapterErrorType::Instantiation => "instantiate",
+        }
+    }
+
+    /**
+       Returns the messages of all underlying errors, starting with
+       the one, that caused this error directly
+     */
+    pub fn get_causes(&self) -> Vec<String> {
+        let mut causes: Vec<String> = vec![];
+        let mut cause = self.source();
+
+        while let Some(error) = cause {
+            causes.push(error.to_string());
+            cause = error.source();
+        }
+
+        causes
+    }
+
+    /**
+       Returns the errors in the form, the user interface shows them.
+       Validation errors keep their fields, every other error is put
+       into "other", followed by it's causes
+     */
+    pub fn get_errors(&self) -> Vec<(String, String)> {
+        if let AdapterErrorType::Validate(errors, _) = &self.error_type {
+            return errors.clone();
+        }
+
+        let mut errors = vec![("other".to_string(), self.get_headline())];
+        for cause in self.get_causes() {
+            errors.push(("cause".to_string(), cause));
+        }
+
+        errors
+    }
+
+    /**
+       Returns the full message, including the entity and all causes
+     */
     pub fn get_text(&self) -> String {
+        let mut message = self.get_headline();
+
+        for cause in self.get_causes() {
+            message = format!("{}: {}", message, cause);
+        }
+
+        message
+    }
+
+    /**
+       Returns the message without causes, but with the entity involved
+     */
+    fn get_headline(&self) -> String {
+        match &self.entity {
+            Some(entity) => format!("{} ({})", self.get_summary(), entity),
+            None => self.get_summary(),
+        }
+    }
+
+    /**
+       Returns the message, that only describes the kind of error
+     */
+    fn get_summary(&self) -> String {
 
         let mut message = String::default();
 
         match &self.error_type {
             AdapterErrorType::TicketDelete => message += "Failed to delete Ticket",
             AdapterErrorType::TicketWrite => message += "Failed to write Ticket",
+            AdapterErrorType::CommentWrite => message += "Failed to write Comment",
+            AdapterErrorType::CommentDelete => message += "Failed to delete Comment",
+            AdapterErrorType::CustomFieldWrite => message += "Failed to write Custom Field",
+            AdapterErrorType::CustomFieldDelete => message += "Failed to delete Custom Field",
+            AdapterErrorType::LinkWrite => message += "Failed to write Ticket Link",
+            AdapterErrorType::LinkDelete => message += "Failed to delete Ticket Link",
+            AdapterErrorType::AttachmentWrite => message += "Failed to write Attachment",
+            AdapterErrorType::AttachmentDelete => message += "Failed to delete Attachment",
+            AdapterErrorType::WorkLogWrite => message += "Failed to write Work Log",
+            AdapterErrorType::WorkLogDelete => message += "Failed to delete Work Log",
             AdapterErrorType::Validate(_, name) => message += ("Failed to validate ".to_owned() + name).as_str(),
             AdapterErrorType::BucketWrite => message += "Failed to write Bucket",
             AdapterErrorType::BucketDelete => message += "Failed to delete Bucket",
@@ -52,6 +219,7 @@ impl AdapterError {
             AdapterErrorType::TagDelete => message += "Failed to delete Tag",
             AdapterErrorType::StateWrite => message += "Failed to write State",
             AdapterErrorType::Access => message += "Failed access Adapter Data",
+            AdapterErrorType::Unsupported(capability) => message += ("The Adapter doesn't support ".to_owned() + capability.get_text().as_str()).as_str(),
             AdapterErrorType::Expression(text) => message += ("Failed to execute Expression correctly. Reason: ".to_string() + text.as_str()).as_str(),
             AdapterErrorType::Instantiation => message += "Failed to instantiate Adapter"
         }
@@ -71,4 +239,9 @@ impl Debug for AdapterError {
         let (file, line) = (file!(), line!());
         write!(f, "{{ file: {file}, line: {line}, message: {} }}", self.get_text())
     }
-}
\ No newline at end of file
+}
+impl Error for AdapterError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|source| source.as_ref() as &(dyn Error + 'static))
+    }
+}
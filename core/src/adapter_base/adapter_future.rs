@@ -0,0 +1,43 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread}
+};
+
+/**
+   The future, that the async methods of a ticket adapter return.
+   It is driven by the thread, that created it, so it doesn't need
+   to be Send.
+ */
+pub type AdapterFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+struct ThreadWaker {
+    thread: Thread
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.thread.unpark();
+    }
+}
+
+/**
+   Runs a future to completion on the current thread, by parking it
+   until the future is woken up again. This doesn't need an async runtime,
+   so adapters, that rely on one (like tokio), have to spawn their work
+   onto it and await the result instead.
+ */
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker { thread: thread::current() }));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
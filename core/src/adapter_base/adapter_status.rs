@@ -0,0 +1,91 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::adapter_error::AdapterError;
+
+/**
+   The health of the connection between an adapter and the source
+   of it's tickets
+ */
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
+pub enum AdapterState {
+    #[default]
+    Connected,
+    Loading,
+    Stale,
+    Error
+}
+
+impl AdapterState {
+
+    /**
+       Returns a human readable description of the state
+     */
+    pub fn get_text(&self) -> String {
+        match self {
+            AdapterState::Connected => "Connected",
+            AdapterState::Loading => "Loading",
+            AdapterState::Stale => "Out of date",
+            AdapterState::Error => "Error",
+        }.to_string()
+    }
+}
+
+/**
+   Describes, how well an adapter is currently doing. Next to the state
+   itself, it keeps the time of the last successful synchronization and
+   the last error, so the user can see, since when and why an adapter
+   is failing.
+ */
+#[derive(Clone, Debug, Default)]
+pub struct AdapterStatus {
+    pub state: AdapterState,
+    pub last_sync: Option<i64>,
+    pub last_error: Option<AdapterError>
+}
+
+impl AdapterStatus {
+
+    pub fn new(state: AdapterState) -> Self {
+        AdapterStatus { state, last_sync: None, last_error: None }
+    }
+
+    /**
+       Marks the adapter as loading. The time of the last synchronization
+       and the last error are kept, until loading finished
+     */
+    pub fn mark_loading(&mut self) {
+        self.state = AdapterState::Loading;
+    }
+
+    /**
+       Marks the adapter as connected and synchronized right now
+     */
+    pub fn mark_synced(&mut self) {
+        self.state = AdapterState::Connected;
+        self.last_sync = Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64);
+        self.last_error = None;
+    }
+
+    /**
+       Marks the adapter as failed with the given error. The time of the
+       last synchronization is kept, so it shows, since when it is failing
+     */
+    pub fn mark_failed(&mut self, error: AdapterError) {
+        self.state = AdapterState::Error;
+        self.last_error = Some(error);
+    }
+
+    /**
+       Returns the status as stale, if it is connected, but hasn't been
+       synchronized for longer than the given number of seconds
+     */
+    pub fn with_max_age(mut self, seconds: i64) -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+        if self.state == AdapterState::Connected && self.last_sync.is_some_and(|last_sync| now - last_sync > seconds) {
+            self.state = AdapterState::Stale;
+        }
+
+        self
+    }
+}
@@ -1,6 +1,15 @@
 mod adapter_error;
+mod adapter_capabilities;
+mod adapter_status;
+mod adapter_future;
 mod ticket_adapter;
 
 pub use adapter_error::AdapterError;
 pub use adapter_error::AdapterErrorType;
+pub use adapter_capabilities::AdapterCapability;
+pub use adapter_capabilities::AdapterCapabilities;
+pub use adapter_status::AdapterState;
+pub use adapter_status::AdapterStatus;
+pub use adapter_future::AdapterFuture;
+pub use adapter_future::block_on;
 pub use ticket_adapter::TicketAdapter;
\ No newline at end of file
@@ -4,15 +4,29 @@ use crate::{
       data_model::{
       Bucket, 
       Ticket, 
+      TicketPage,
+      TicketPageRequest,
+      Comment, 
+      HistoryEntry, 
+      CustomField, 
+      TicketLink, 
+      Attachment, 
+      WorkLog, 
       State, 
       Tag, 
       Filter, 
+      FilterQuery,
+      SearchHit,
       Config
    }, 
-   AppConfig
+   AppConfig,
+   EventBus
 };
 
 pub use super::adapter_error::AdapterError as AdapterError;
+use super::adapter_capabilities::AdapterCapabilities;
+use super::adapter_status::AdapterStatus;
+use super::adapter_future::AdapterFuture;
 
 pub trait TicketAdapter {
 
@@ -38,9 +52,10 @@ pub trait TicketAdapter {
     fn create_config() -> Config where Self: Sized;
 
     /**
-       Creates an instance from Configuration
+       Creates an instance from Configuration. Adapters, that keep loading
+       in the background, publish their progress to the given event bus
      */
-    fn from_config(app_config: Arc<Mutex<AppConfig>>, config: &Config, finished: Arc<Mutex<bool>>) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized;
+    fn from_config(app_config: Arc<Mutex<AppConfig>>, config: &Config, events: EventBus) -> Result<Box<dyn TicketAdapter + Send + Sync>, AdapterError> where Self: Sized;
 
     /**
        Returns the path to the icon for this particular adapter.
@@ -54,10 +69,29 @@ pub trait TicketAdapter {
     fn get_fancy_name(&self) -> String;
 
     /**
-       Returns a boolean on wether the Adapter is readonly or not.
-       If it is readonly
+       Returns the set of things, this adapter is able to change.
+       Actions outside of this set are neither offered nor attempted
      */
-    fn is_read_only(&self) -> bool;
+    fn get_capabilities(&self) -> AdapterCapabilities;
+
+    /**
+       Returns, how well the adapter is currently connected to the source
+       of it's tickets. Adapters, that can't fail to connect, don't need
+       to implement it, by default they are always connected
+     */
+    fn get_status(&self) -> AdapterStatus {
+        AdapterStatus::default()
+    }
+
+    /**
+       Tries to reconnect to the source of the tickets and load them again.
+       Adapters, that load in the background, return as soon as loading
+       started and publish it's outcome as an event. By default, there is
+       nothing to retry
+     */
+    fn retry(&self) -> Result<(), AdapterError> {
+        Ok(())
+    }
 
     /**
        Lists all Buckets, that are provided by this adapter
@@ -103,6 +137,95 @@ pub trait TicketAdapter {
      */
     fn ticket_list(&self, expression: &str) -> Result<Vec<Ticket>, AdapterError>;
 
+    /**
+       Async variant of ticket_list_unique. Adapters, that wait on the
+       network, should implement it, so the waiting doesn't block a thread.
+       By default, it just calls ticket_list_unique
+     */
+    fn ticket_list_unique_async(&self, id: i64) -> AdapterFuture<'_, Option<Ticket>> {
+        Box::pin(async move { self.ticket_list_unique(id) })
+    }
+
+    /**
+       Async variant of ticket_list. Adapters, that wait on the network,
+       should implement it, so the waiting doesn't block a thread.
+       By default, it just calls ticket_list
+     */
+    fn ticket_list_async<'a>(&'a self, expression: &'a str) -> AdapterFuture<'a, Result<Vec<Ticket>, AdapterError>> {
+        Box::pin(async move { self.ticket_list(expression) })
+    }
+
+    /**
+       Lists a single page of all tickets, this adapter can provide, together
+       with the total number of tickets. Adapters with a lot of tickets should
+       implement it, by default the page is cut out of ticket_list_all
+     */
+    fn ticket_list_all_page(&self, page: TicketPageRequest) -> TicketPage {
+        TicketPage::from_tickets(self.ticket_list_all(), page)
+    }
+
+    /**
+       Lists a single page of the tickets, that match the adapter specific
+       expression, together with the total number of matching tickets.
+       By default, the page is cut out of ticket_list
+     */
+    fn ticket_list_page(&self, expression: &str, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        self.ticket_list(expression).map(|tickets| TicketPage::from_tickets(tickets, page))
+    }
+
+    /**
+       Async variant of ticket_list_page. Adapters, that wait on the network,
+       should implement it, so the waiting doesn't block a thread.
+       By default, it just calls ticket_list_page
+     */
+    fn ticket_list_page_async<'a>(&'a self, expression: &'a str, page: TicketPageRequest) -> AdapterFuture<'a, Result<TicketPage, AdapterError>> {
+        Box::pin(async move { self.ticket_list_page(expression, page) })
+    }
+
+    /**
+       Lists tickets, that match an adapter neutral filter query. Adapters
+       with their own query language should compile the query into it, by
+       default every ticket of ticket_list_all is tested against the query
+     */
+    fn ticket_list_query(&self, query: &FilterQuery) -> Result<Vec<Ticket>, AdapterError> {
+        let buckets = match query.uses_buckets() {
+            true => self.bucket_list_all(),
+            false => vec![],
+        };
+
+        Ok(self.ticket_list_all().into_iter()
+            .filter(|ticket| query.matches(ticket, &buckets))
+            .collect())
+    }
+
+    /**
+       Lists a single page of the tickets, that match an adapter neutral
+       filter query, together with the total number of matching tickets.
+       By default, the page is cut out of ticket_list_query
+     */
+    fn ticket_list_query_page(&self, query: &FilterQuery, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        self.ticket_list_query(query).map(|tickets| TicketPage::from_tickets(tickets, page))
+    }
+
+    /**
+       Searches the titles and descriptions of all tickets for the words
+       of the text and returns the hits, the best one first. Adapters with
+       their own full text index should implement it, by default every
+       ticket of ticket_list_all is scored with SearchHit::score
+     */
+    fn ticket_search(&self, text: &str) -> Result<Vec<SearchHit>, AdapterError> {
+        let mut hits: Vec<SearchHit> = self.ticket_list_all().into_iter()
+            .map(|ticket| {
+                let score = SearchHit::score(&ticket, text);
+                SearchHit::new(ticket, score)
+            })
+            .filter(|hit| hit.score > 0.0)
+            .collect();
+
+        SearchHit::sort(&mut hits);
+        Ok(hits)
+    }
+
     /**
        Tries to write a ticket to this adapter. If the write fails, it
        throw an AdapterError.
@@ -115,6 +238,158 @@ pub trait TicketAdapter {
      */
     fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError>;
 
+    /**
+       Writes multiple tickets at once and returns each ticket like it has
+       been written, in the order they were given. Adapters, that assign ids
+       to new tickets, should return them with their id. Adapters, that
+       support transactions, should write either all of them or none. By
+       default, the tickets are written one after another, so a failed
+       write doesn't stop the others
+     */
+    fn ticket_write_many(&self, tickets: &[Ticket]) -> Vec<Result<Ticket, AdapterError>> {
+        tickets.iter().map(|ticket| self.ticket_write(ticket).map(|_| ticket.clone())).collect()
+    }
+
+    /**
+       Deletes multiple tickets at once and returns the result of each ticket
+       in the order they were given. Adapters, that support transactions,
+       should delete either all of them or none. By default, the tickets are
+       deleted one after another, so a failed delete doesn't stop the others
+     */
+    fn ticket_drop_many(&self, tickets: &[Ticket]) -> Vec<Result<(), AdapterError>> {
+        tickets.iter().map(|ticket| self.ticket_drop(ticket)).collect()
+    }
+
+    /**
+       Lists the recorded changes of a single Ticket, defined by it's id,
+       ordered from oldest to newest. If the adapter doesn't keep a history,
+       or the read fails, an empty vector will be returned
+     */
+    fn ticket_history(&self, id: i64) -> Vec<HistoryEntry>;
+
+    /**
+       Lists all comments, that belong to the supplied ticket, ordered
+       from oldest to newest. If the read fails, or the ticket has no
+       comments, an empty vector will be returned
+     */
+    fn comment_list(&self, ticket: &Ticket) -> Vec<Comment>;
+
+    /**
+       Async variant of comment_list. Adapters, that wait on the network,
+       should implement it, so the waiting doesn't block a thread.
+       By default, it just calls comment_list
+     */
+    fn comment_list_async<'a>(&'a self, ticket: &'a Ticket) -> AdapterFuture<'a, Vec<Comment>> {
+        Box::pin(async move { self.comment_list(ticket) })
+    }
+
+    /**
+       Tries to write a comment to this adapter. If the id of the comment
+       is 0, a new comment is being created, otherwise the existing one
+       gets replaced. If the write fails, it throws an AdapterError.
+     */
+    fn comment_write(&self, comment: &Comment) -> Result<(), AdapterError>;
+
+    /**
+       Tries to delete a comment off this adapter. If the delete fails for
+       for whatever reason, an AdapterError is being thrown.
+     */
+    fn comment_drop(&self, comment: &Comment) -> Result<(), AdapterError>;
+
+    /**
+       Lists all links stored in this adapter, that have the supplied
+       ticket either as source or as target. The linked ticket may belong
+       to a different adapter. If the read fails, an empty vector is returned
+     */
+    fn link_list(&self, ticket: &Ticket) -> Vec<TicketLink>;
+
+    /**
+       Tries to write a link to this adapter. The source of the link has
+       to be a ticket of this adapter. If the id of the link is 0, a new
+       link is being created. If the write fails, it throws an AdapterError.
+     */
+    fn link_write(&self, link: &TicketLink) -> Result<(), AdapterError>;
+
+    /**
+       Tries to delete a link off this adapter. If the delete fails for
+       for whatever reason, an AdapterError is being thrown.
+     */
+    fn link_drop(&self, link: &TicketLink) -> Result<(), AdapterError>;
+
+    /**
+       Lists the metadata of all files, that are attached to the supplied
+       ticket. The contents are not part of the list, they have to be read
+       separately. If the read fails, an empty vector is returned
+     */
+    fn attachment_list(&self, ticket: &Ticket) -> Vec<Attachment>;
+
+    /**
+       Tries to attach a file with the supplied contents to the ticket, that
+       the attachment points to. Name, mime type and size are taken from the
+       attachment. If the write fails, it throws an AdapterError.
+     */
+    fn attachment_add(&self, attachment: &Attachment, data: &[u8]) -> Result<(), AdapterError>;
+
+    /**
+       Tries to remove an attachment off this adapter. If the delete fails for
+       for whatever reason, an AdapterError is being thrown.
+     */
+    fn attachment_drop(&self, attachment: &Attachment) -> Result<(), AdapterError>;
+
+    /**
+       Reads the contents of an attachment. If the attachment doesn't exist
+       or the read fails, None is returned
+     */
+    fn attachment_read(&self, attachment: &Attachment) -> Option<Vec<u8>>;
+
+    /**
+       Lists all work logs, that belong to the supplied ticket, ordered
+       from oldest to newest. If the read fails, or the adapter doesn't
+       track time, an empty vector will be returned
+     */
+    fn work_log_list(&self, ticket: &Ticket) -> Vec<WorkLog>;
+
+    /**
+       Lists the work logs of all tickets of this adapter, that have been
+       logged between from (inclusive) and to (exclusive). Both are unix
+       timestamps in seconds. If the read fails, an empty vector is returned
+     */
+    fn work_log_list_range(&self, from: i64, to: i64) -> Vec<WorkLog>;
+
+    /**
+       Tries to write a work log to this adapter. If the id of the work log
+       is 0, a new one is being created, otherwise the existing one gets
+       replaced. If the write fails, it throws an AdapterError.
+     */
+    fn work_log_write(&self, work_log: &WorkLog) -> Result<(), AdapterError>;
+
+    /**
+       Tries to delete a work log off this adapter. If the delete fails for
+       for whatever reason, an AdapterError is being thrown.
+     */
+    fn work_log_drop(&self, work_log: &WorkLog) -> Result<(), AdapterError>;
+
+    /**
+       Lists the schema of custom fields, that tickets of this adapter
+       can have. If the adapter doesn't support custom fields, or the
+       read fails, an empty vector will be returned
+     */
+    fn custom_field_list_all(&self) -> Vec<CustomField>;
+
+    /**
+       Writes a custom field definition to this adapter. If a field with
+       the same name exists, it gets replaced. If the write fails, it
+       throws an AdapterError.
+     */
+    fn custom_field_write(&self, field: &CustomField) -> Result<(), AdapterError>;
+
+    /**
+       Tries to delete a custom field definition including all of it's
+       values off this adapter. If the delete fails for whatever reason,
+       an AdapterError is being thrown.
+     */
+    fn custom_field_drop(&self, field: &CustomField) -> Result<(), AdapterError>;
+
     /**
        Lists all states in a list, that are available to this adapter
        or an empty list
@@ -0,0 +1,134 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH
+};
+
+use super::Ticket;
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attachment {
+    pub adapter: String,
+    pub id: i64,
+    pub ticket_id: i64,
+    pub name: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub created_at: i64
+}
+
+impl Default for Attachment {
+    fn default() -> Self {
+        Attachment {
+            adapter: "".into(),
+            id: 0,
+            ticket_id: 0,
+            name: "".into(),
+            mime_type: "application/octet-stream".into(),
+            size: 0,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+        }
+    }
+}
+
+impl Attachment {
+
+    pub fn with_ticket(mut self, ticket: &Ticket) -> Self {
+        self.adapter = ticket.adapter.clone();
+        self.ticket_id = ticket.id;
+        self
+    }
+
+    /**
+       Sets the file name of the attachment and guesses the
+       mime type from it's extension
+     */
+    pub fn with_name(mut self, name: String) -> Self {
+        self.mime_type = Attachment::mime_type_from_name(&name);
+        self.name = name;
+        self
+    }
+
+    pub fn with_mime_type(mut self, mime_type: String) -> Self {
+        self.mime_type = mime_type;
+        self
+    }
+
+    pub fn with_size(mut self, size: i64) -> Self {
+        self.size = size;
+        self
+    }
+
+    /**
+       Returns true, if the attachment is an image, that can
+       be displayed inline
+     */
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+
+    /**
+       Returns the uri, under which the contents of this attachment
+       can be referenced in a markdown description, for example
+       ![screenshot](attachment://local/3/screenshot.png)
+     */
+    pub fn get_uri(&self) -> String {
+        format!("attachment://{}/{}/{}", self.adapter, self.id, self.name)
+    }
+
+    /**
+       Creates an attachment reference from an uri, that has been created
+       with get_uri. Only adapter, id, name and mime type are known afterwards.
+       Returns None, if the uri doesn't point to an attachment
+     */
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        let mut parts = uri.strip_prefix("attachment://")?.splitn(3, '/');
+
+        let adapter = parts.next()?;
+        let id = parts.next()?.parse::<i64>().ok()?;
+        let name = parts.next()?;
+
+        if adapter.is_empty() || name.is_empty() {
+            return None;
+        }
+
+        Some(Attachment {
+            adapter: adapter.to_string(),
+            id,
+            ..Default::default()
+        }.with_name(name.to_string()))
+    }
+
+    /**
+       Returns the size of the attachment in a human readable form
+     */
+    pub fn get_size_text(&self) -> String {
+        match self.size {
+            size if size >= 1024 * 1024 => format!("{:.1} MiB", size as f64 / (1024.0 * 1024.0)),
+            size if size >= 1024 => format!("{:.1} KiB", size as f64 / 1024.0),
+            size => format!("{} B", size)
+        }
+    }
+
+    /**
+       Guesses the mime type from the extension of a file name.
+       Unknown extensions are treated as binary data
+     */
+    pub fn mime_type_from_name(name: &str) -> String {
+        let extension = name.rsplit_once('.').map(|(_, extension)| extension.to_lowercase()).unwrap_or_default();
+
+        match extension.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "bmp" => "image/bmp",
+            "svg" => "image/svg+xml",
+            "txt" | "log" => "text/plain",
+            "md" => "text/markdown",
+            "json" => "application/json",
+            "pdf" => "application/pdf",
+            "zip" => "application/zip",
+            _ => "application/octet-stream"
+        }.to_string()
+    }
+}
@@ -6,6 +6,7 @@ use std::time::{
 use crate::TicketAdapter;
 
 #[derive(Default, PartialEq, Clone, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BucketIdentifier {
     pub adapter: String,
     pub id: u64,
@@ -21,6 +22,7 @@ impl BucketIdentifier {
 }
 
 #[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bucket {
     pub identifier: BucketIdentifier,
     pub name: String,
@@ -1,5 +1,6 @@
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum BucketPanelLocationType {
     All,
     Reset,
@@ -8,7 +9,8 @@ pub enum BucketPanelLocationType {
     Entry
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BucketPanelLocation {
     pub entry_type: BucketPanelLocationType,
     pub adapter: String,
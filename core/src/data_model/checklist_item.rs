@@ -0,0 +1,34 @@
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChecklistItem {
+    pub text: String,
+    pub done: bool,
+    pub read_only: bool
+}
+
+impl ChecklistItem {
+
+    pub fn new(text: String, done: bool) -> Self {
+        ChecklistItem { 
+            text, 
+            done, 
+            read_only: false 
+        }
+    }
+
+    pub fn as_read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /**
+       Returns the item in the same form as a markdown task list
+       entry, for example "[x] write tests"
+     */
+    pub fn describe(&self) -> String {
+        match self.done {
+            true => format!("[x] {}", self.text),
+            false => format!("[ ] {}", self.text),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH
+};
+
+use crate::TicketAdapter;
+
+use super::Ticket;
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Comment {
+    pub adapter: String,
+    pub id: i64,
+    pub ticket_id: i64,
+    pub author: String,
+    pub body: String,
+    pub created_at: i64,
+    pub edited_at: i64
+}
+
+impl Default for Comment {
+    fn default() -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+        Comment {
+            adapter: "".into(),
+            id: 0,
+            ticket_id: 0,
+            author: "".into(),
+            body: "".into(),
+            created_at: now,
+            edited_at: now
+        }
+    }
+}
+
+impl Comment {
+
+    pub fn with_adapter(mut self, adapter: &dyn TicketAdapter) -> Self {
+        self.adapter = adapter.get_name();
+        self
+    }
+
+    pub fn with_ticket(mut self, ticket: &Ticket) -> Self {
+        self.adapter = ticket.adapter.clone();
+        self.ticket_id = ticket.id;
+        self
+    }
+
+    pub fn with_details(mut self, id: i64, author: String, body: String) -> Self {
+        self.id = id;
+        self.author = author;
+        self.body = body;
+        self
+    }
+
+    /**
+       Returns true, if the comment has been changed after it
+       has been created initially
+     */
+    pub fn is_edited(&self) -> bool {
+        self.edited_at > self.created_at
+    }
+
+    /**
+       Replaces the body of the comment and moves the edit
+       timestamp to now
+     */
+    pub fn edit(&mut self, body: String) {
+        self.body = body;
+        self.edited_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use super::{config_option::ToConfig, ConfigOption};
 
 #[derive(Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Config {
     options: BTreeMap<String, ConfigOption>,
 }
@@ -4,6 +4,7 @@ use std::fmt::Write;
 use std::str;
 
 #[derive(Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigOption {
     pub(super) value: String,
     pub(super) display_options: String
@@ -47,6 +48,16 @@ impl ToConfig for i32 {
     }
 }
 
+impl ToConfig for i64 {
+    fn to_config(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_self(value: &str) -> Option<Self> {
+        i64::from_str(value).ok()
+    }
+}
+
 impl ToConfig for Color32 {
     fn to_config(&self) -> String {
         let mut col_string = String::from("#");
@@ -0,0 +1,106 @@
+use crate::TicketAdapter;
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum CustomFieldType {
+    #[default]
+    Text,
+    Number,
+    Date,
+    Enum(Vec<String>),
+    Bool
+}
+
+impl CustomFieldType {
+
+    /**
+       Returns the technical name of the type, that is used
+       to store it in an adapter
+     */
+    pub fn get_type_name(&self) -> String {
+        match self {
+            CustomFieldType::Text => "text",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Date => "date",
+            CustomFieldType::Enum(_) => "enum",
+            CustomFieldType::Bool => "bool",
+        }.to_string()
+    }
+
+    /**
+       Returns the options of the type joined by commas. Only Enums
+       have options, all other types return an empty String
+     */
+    pub fn get_options(&self) -> String {
+        match self {
+            CustomFieldType::Enum(options) => options.join(","),
+            _ => String::default()
+        }
+    }
+
+    /**
+       Creates the type from it's technical name and the comma separated
+       options. Unknown type names fall back to Text
+     */
+    pub fn from_type_name(type_name: &str, options: &str) -> Self {
+        match type_name {
+            "number" => CustomFieldType::Number,
+            "date" => CustomFieldType::Date,
+            "enum" => CustomFieldType::Enum(options
+                .split(',')
+                .map(|option| option.trim().to_string())
+                .filter(|option| !option.is_empty())
+                .collect()),
+            "bool" => CustomFieldType::Bool,
+            _ => CustomFieldType::Text
+        }
+    }
+
+    /**
+       Checks, if the supplied raw value is valid for this type.
+       Empty values are always valid, they mean the field is not set.
+       Dates are expected in the format YYYY-MM-DD
+     */
+    pub fn is_valid_value(&self, value: &str) -> bool {
+        if value.is_empty() {
+            return true;
+        }
+
+        match self {
+            CustomFieldType::Text => true,
+            CustomFieldType::Number => value.parse::<f64>().is_ok(),
+            CustomFieldType::Date => {
+                let parts: Vec<Option<u32>> = value.split('-').map(|part| part.parse::<u32>().ok()).collect();
+                matches!(parts.as_slice(), [Some(_), Some(1..=12), Some(1..=31)])
+            },
+            CustomFieldType::Enum(options) => options.iter().any(|option| option == value),
+            CustomFieldType::Bool => value == "true" || value == "false",
+        }
+    }
+}
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomField {
+    pub adapter: String,
+    pub name: String,
+    pub field_type: CustomFieldType
+}
+
+impl CustomField {
+
+    pub fn with_adapter(mut self, adapter: &dyn TicketAdapter) -> Self {
+        self.adapter = adapter.get_name();
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_type(mut self, field_type: CustomFieldType) -> Self {
+        self.field_type = field_type;
+        self
+    }
+}
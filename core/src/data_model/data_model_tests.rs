@@ -1,8 +1,381 @@
 #[cfg(test)]
 mod tests {
 
+    use crate::{AdapterCapabilities, AdapterRequest, AdapterCapability, AdapterError, AdapterErrorType, AdapterState, AdapterStatus, Attachment, Bucket, BucketIdentifier, ChecklistItem, Comment, EventBus, FilterCondition, FilterQuery, ProviderEvent, HistoryEntry, Recurrence, SearchHit, State, StateIdentifier, Ticket, TicketIdentifier, TicketSort, TicketSortKey, TicketLink, TicketLinkType, WorkLog};
+
     /*#[test]
     fn test_config() {
 
     }*/
-}
\ No newline at end of file
+
+    #[test]
+    fn test_comment_with_ticket() {
+        let ticket = Ticket {
+            adapter: "local".to_string(),
+            id: 42,
+            ..Default::default()
+        };
+
+        let mut comment = Comment::default()
+            .with_ticket(&ticket)
+            .with_details(0, "biochemist".to_string(), "first".to_string());
+
+        assert_eq!(comment.adapter, "local");
+        assert_eq!(comment.ticket_id, 42);
+        assert!(!comment.is_edited());
+
+        comment.created_at -= 10;
+        comment.edit("second".to_string());
+
+        assert_eq!(comment.body, "second");
+        assert!(comment.is_edited());
+    }
+
+    #[test]
+    fn test_history_entry_describe() {
+        let entry = HistoryEntry::default().with_change("state", "new".to_string(), "done".to_string());
+        assert_eq!(entry.describe(), "state: \"new\" -> \"done\"");
+
+        let entry = HistoryEntry::default().with_change("tag", String::default(), "bug".to_string());
+        assert_eq!(entry.describe(), "tag: added \"bug\"");
+
+        let entry = HistoryEntry::default().with_change("tag", "bug".to_string(), String::default());
+        assert_eq!(entry.describe(), "tag: removed \"bug\"");
+    }
+
+    #[test]
+    fn test_ticket_link_seen_from() {
+        let blocker = TicketIdentifier::new("local", 1);
+        let blocked = TicketIdentifier::new("github", 2);
+
+        let link = TicketLink::default()
+            .with_source(blocker.clone())
+            .with_target(blocked.clone())
+            .with_type(TicketLinkType::Blocks);
+
+        assert_eq!(link.seen_from(&blocker), Some((TicketLinkType::Blocks, blocked.clone())));
+        assert_eq!(link.seen_from(&blocked), Some((TicketLinkType::BlockedBy, blocker)));
+        assert_eq!(link.seen_from(&TicketIdentifier::new("local", 2)), None);
+        assert_eq!(blocked.to_string(), "github:2");
+    }
+
+    #[test]
+    fn test_checklist_progress() {
+        let mut ticket = Ticket::default();
+        assert_eq!(ticket.checklist_progress(), None);
+
+        ticket = ticket.with_checklist(vec![
+            ChecklistItem::new("first".to_string(), true),
+            ChecklistItem::new("second".to_string(), false),
+            ChecklistItem::new("third".to_string(), true)
+        ]);
+
+        assert_eq!(ticket.checklist_progress(), Some((2, 3)));
+        assert_eq!(ticket.checklist[1].describe(), "[ ] second");
+    }
+
+    #[test]
+    fn test_attachment_uri() {
+        let ticket = Ticket {
+            adapter: "local".to_string(),
+            id: 42,
+            ..Default::default()
+        };
+
+        let mut attachment = Attachment::default()
+            .with_ticket(&ticket)
+            .with_name("screen shot.PNG".to_string());
+        attachment.id = 7;
+
+        assert!(attachment.is_image());
+        assert_eq!(attachment.get_uri(), "attachment://local/7/screen shot.PNG");
+
+        let parsed = Attachment::from_uri(&attachment.get_uri()).unwrap();
+        assert_eq!((parsed.adapter, parsed.id, parsed.name), ("local".to_string(), 7, "screen shot.PNG".to_string()));
+        assert_eq!(Attachment::from_uri("https://example.com/image.png"), None);
+    }
+
+    #[test]
+    fn test_work_log_duration() {
+        assert_eq!(WorkLog::parse_duration("1h 30m"), Some(5400));
+        assert_eq!(WorkLog::parse_duration("1.5h"), Some(5400));
+        assert_eq!(WorkLog::parse_duration("45"), Some(2700));
+        assert_eq!(WorkLog::parse_duration("an hour"), None);
+        assert_eq!(WorkLog::parse_duration(""), None);
+
+        assert_eq!(WorkLog::format_duration(5400), "1h 30m");
+        assert_eq!(WorkLog::format_duration(7200), "2h");
+        assert_eq!(WorkLog::format_duration(59), "0m");
+    }
+
+    #[test]
+    fn test_recurrence() {
+        // 2024-01-31 09:00 UTC, a monthly ticket is due on the last day of february next
+        let ticket = Ticket {
+            due_at: 1706691600000,
+            recurrence: Recurrence::from_text("monthly"),
+            checklist: vec![ChecklistItem::new("send invoice".to_string(), true)],
+            ..Default::default()
+        };
+
+        let next = ticket.next_occurrence().unwrap();
+        assert_eq!(next.due_at, 1709197200000);
+        assert!(!next.checklist[0].done);
+        assert_eq!(next.recurrence, ticket.recurrence);
+
+        // Friday 2024-02-02 09:00 UTC, weekdays continue on monday
+        let workdays = Recurrence::from_text("0 9 * * 1-5").unwrap();
+        assert_eq!(workdays.next_after(1706864400000), Some(1707123600000));
+
+        assert_eq!(Recurrence::from_text("every 2 weeks"), Some(Recurrence::Weekly(2)));
+        assert_eq!(Recurrence::from_text("every 2 weeks").unwrap().to_text(), "every 2 weeks");
+        assert_eq!(Recurrence::from_text("0 25 * * *"), None);
+        assert_eq!(Ticket::default().next_occurrence(), None);
+
+        // Daily from 2024-02-01 09:00 UTC, the first occurrence after 2024-02-04 10:00 is on the 5th
+        let daily = Recurrence::from_text("daily").unwrap();
+        assert_eq!(daily.first_after(1706778000000, 1707040800000), Some(1707123600000));
+        assert_eq!(daily.first_after(1706778000000, 0), daily.next_after(1706778000000));
+
+        // Monthly instances keep moving through the short february, like next_occurrence does
+        let after = ticket.occurrence_after(1709251200000).unwrap();
+        assert_eq!(after.due_at, 1711702800000);
+        assert_eq!(after.recurrence, ticket.recurrence);
+    }
+
+    #[test]
+    fn test_adapter_capabilities() {
+        let capabilities = AdapterCapabilities::read_only()
+            .with(AdapterCapability::TicketWrite)
+            .with(AdapterCapability::Comments);
+
+        assert!(capabilities.supports(AdapterCapability::TicketWrite));
+        assert!(!capabilities.supports(AdapterCapability::Buckets));
+        assert!(!capabilities.is_read_only());
+        assert!(AdapterCapabilities::all().without(AdapterCapability::Tags).supports(AdapterCapability::Filters));
+
+        let error = AdapterError::new(AdapterErrorType::Unsupported(AdapterCapability::Buckets));
+        assert_eq!(error.get_text(), "The Adapter doesn't support changing Buckets");
+    }
+
+    #[test]
+    fn test_adapter_error_causes() {
+        use std::error::Error;
+
+        let cause = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "database is locked");
+        let error = AdapterError::new(AdapterErrorType::TicketWrite)
+            .with_entity("Ticket 4")
+            .with_source(cause);
+
+        assert_eq!(error.get_operation(), "write");
+        assert_eq!(error.get_text(), "Failed to write Ticket (Ticket 4): database is locked");
+        assert_eq!(error.get_causes(), vec!["database is locked".to_string()]);
+        assert!(error.source().is_some());
+        assert_eq!(error.get_errors(), vec![
+            ("other".to_string(), "Failed to write Ticket (Ticket 4)".to_string()),
+            ("cause".to_string(), "database is locked".to_string())
+        ]);
+
+        let fields = vec![("title".to_string(), "must not be empty".to_string())];
+        let error = AdapterError::new(AdapterErrorType::Validate(fields.clone(), "Ticket".to_string()));
+        assert_eq!(error.get_errors(), fields);
+    }
+
+    #[test]
+    fn test_event_bus() {
+        let events = EventBus::default();
+        let receiver = events.subscribe();
+        let dropped = events.subscribe();
+        drop(dropped);
+
+        events.publish(ProviderEvent::TicketCreated(Ticket::default()));
+        events.publish(ProviderEvent::AdapterLoaded("local".to_string()));
+
+        let received: Vec<ProviderEvent> = receiver.try_iter().collect();
+        assert_eq!(received.len(), 2);
+        assert!(!received[0].affects_bucket_panel());
+        assert!(matches!(&received[1], ProviderEvent::AdapterLoaded(name) if name == "local"));
+    }
+
+    #[test]
+    fn test_adapter_status() {
+        let mut status = AdapterStatus::default();
+        assert_eq!(status.state, AdapterState::Connected);
+        assert!(status.last_sync.is_none());
+
+        status.mark_synced();
+        let last_sync = status.last_sync;
+        assert!(last_sync.is_some());
+
+        status.mark_failed(AdapterError::new(AdapterErrorType::Access));
+        status.mark_loading();
+        assert_eq!(status.state, AdapterState::Loading);
+        assert_eq!(status.last_sync, last_sync);
+        assert!(status.last_error.is_some());
+
+        status.mark_synced();
+        assert!(status.last_error.is_none());
+        assert_eq!(status.clone().with_max_age(60).state, AdapterState::Connected);
+
+        status.last_sync = status.last_sync.map(|last_sync| last_sync - 120);
+        assert_eq!(status.with_max_age(60).state, AdapterState::Stale);
+    }
+
+    #[test]
+    fn test_ticket_sort() {
+        let sort: TicketSort = "state asc, title desc".parse().unwrap();
+        assert_eq!(sort, TicketSort::new().ascending(TicketSortKey::State).descending(TicketSortKey::Title));
+        assert_eq!(sort.to_string(), "state asc, title desc");
+        assert!("due sideways".parse::<TicketSort>().is_err());
+        assert!("colour".parse::<TicketSort>().is_err());
+
+        let states = vec![
+            State { identifier: StateIdentifier::new(&"local".to_string(), &"done".to_string()), sorting_order: 2, ..Default::default() },
+            State { identifier: StateIdentifier::new(&"local".to_string(), &"open".to_string()), sorting_order: 1, ..Default::default() }
+        ];
+
+        let ticket = |id: i64, title: &str, state: &str| Ticket {
+            adapter: "local".to_string(),
+            id,
+            title: title.to_string(),
+            state_name: state.to_string(),
+            ..Default::default()
+        };
+
+        let mut tickets = vec![ticket(1, "a", "done"), ticket(2, "b", "open"), ticket(3, "c", "open")];
+        sort.sort(&mut tickets, &states);
+
+        assert_eq!(tickets.iter().map(|ticket| ticket.id).collect::<Vec<i64>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_ticket_identifier() {
+        let identifier = TicketIdentifier::new("github:work", 12);
+        assert_eq!(identifier.to_string(), "github:work:12");
+        assert_eq!("github:work:12".parse::<TicketIdentifier>().unwrap(), identifier);
+        assert_eq!(" local : 3 ".parse::<TicketIdentifier>().unwrap(), TicketIdentifier::new("local", 3));
+
+        assert!("local".parse::<TicketIdentifier>().is_err());
+        assert!(":3".parse::<TicketIdentifier>().is_err());
+        assert!("local:three".parse::<TicketIdentifier>().is_err());
+    }
+
+    #[test]
+    fn test_filter_query() {
+        let query: FilterQuery = "with_state(open)  with_tag(bug) assigned_to(anna,ben) created_after(2024-02-29)".parse().unwrap();
+        assert_eq!(query, FilterQuery::new()
+            .with(FilterCondition::State("open".to_string()))
+            .with(FilterCondition::Tag("bug".to_string()))
+            .with(FilterCondition::AssignedTo(vec!["anna".to_string(), "ben".to_string()]))
+            .with(FilterCondition::CreatedAfter(1709164800)));
+        assert_eq!(query.to_string(), "with_state(open) with_tag(bug) assigned_to(anna, ben) created_after(2024-02-29)");
+        assert_eq!(query.to_string().parse::<FilterQuery>().unwrap(), query);
+
+        assert!("".parse::<FilterQuery>().is_err());
+        assert!("with_state(open".parse::<FilterQuery>().is_err());
+        assert!("with_colour(red)".parse::<FilterQuery>().is_err());
+        assert!("created_after(2023-02-29)".parse::<FilterQuery>().is_err());
+
+        let ticket = Ticket {
+            adapter: "local".to_string(),
+            bucket_id: 2,
+            title: "Login page crashes".to_string(),
+            state_name: "open".to_string(),
+            tags: vec!["bug".to_string()],
+            assigned_to: "ben".to_string(),
+            created_at: 1709200000,
+            ..Default::default()
+        };
+
+        let buckets = vec![Bucket { identifier: BucketIdentifier::new(&"local".to_string(), 2), name: "web".to_string(), last_change: 0 }];
+
+        assert!(query.matches(&ticket, &buckets));
+        assert!("in_bucket(web) text_contains(LOGIN)".parse::<FilterQuery>().unwrap().matches(&ticket, &buckets));
+        assert!(!"in_bucket(app)".parse::<FilterQuery>().unwrap().matches(&ticket, &buckets));
+        assert!(!"with_state(open) with_tag(feature)".parse::<FilterQuery>().unwrap().matches(&ticket, &buckets));
+
+        // Due dates are set in milliseconds by the ui, creation dates are in seconds
+        let ticket = Ticket {
+            created_at: 1709200000,
+            due_at: 1710504000000,
+            ..Default::default()
+        };
+
+        assert!("due_after(2024-03-01) due_before(2024-04-01) created_before(2024-03-01)".parse::<FilterQuery>().unwrap().matches(&ticket, &[]));
+        assert!(!"due_before(2024-03-15)".parse::<FilterQuery>().unwrap().matches(&ticket, &[]));
+        assert!("due_in_days(0)".parse::<FilterQuery>().unwrap().matches(&ticket, &[]));
+    }
+
+    #[test]
+    fn test_search_hit() {
+        let ticket = |title: &str, description: &str| Ticket {
+            title: title.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(SearchHit::words("Login-Page, crashes!"), vec!["login", "page", "crashes"]);
+
+        // Words in the title count twice as much as words in the description
+        assert_eq!(SearchHit::score(&ticket("Login page", "crashes on submit"), "login crashes"), 0.75);
+        assert_eq!(SearchHit::score(&ticket("Dark mode", ""), "login crashes"), 0.0);
+        assert_eq!(SearchHit::score(&ticket("Login page", ""), "!?"), 0.0);
+
+        // Ranks of a full text index don't depend on the other hits
+        assert_eq!(SearchHit::rank_score(-3.0), 0.75);
+        assert_eq!(SearchHit::rank_score(-1.0), 0.5);
+        assert_eq!(SearchHit::rank_score(0.5), 0.0);
+
+        let mut hits = vec![
+            SearchHit::new(ticket("first", ""), 0.25),
+            SearchHit::new(ticket("second", ""), 0.5),
+            SearchHit::new(ticket("third", ""), 0.25)
+        ];
+        SearchHit::sort(&mut hits);
+
+        assert_eq!(hits.iter().map(|hit| (hit.ticket.title.as_str(), hit.score)).collect::<Vec<(&str, f64)>>(),
+            vec![("second", 0.5), ("first", 0.25), ("third", 0.25)]);
+    }
+
+    #[test]
+    fn test_adapter_request() {
+        let wait = |request: &AdapterRequest<u32>| loop {
+            if let Some(result) = request.poll() {
+                break result;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        };
+
+        let request = AdapterRequest::dispatch(|| 42);
+        assert_eq!(wait(&request).ok(), Some(42));
+        assert!(request.poll().is_none());
+
+        let request = AdapterRequest::dispatch(|| -> u32 { panic!("job failed") });
+        assert!(wait(&request).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_schema_document() {
+        use crate::{Priority, SchemaDocument, SCHEMA_VERSION};
+
+        let ticket = Ticket {
+            adapter: "local".to_string(),
+            id: 7,
+            priority: Priority::High,
+            recurrence: Recurrence::from_text("every 2 weeks"),
+            ..Default::default()
+        };
+
+        let json = SchemaDocument::new(vec![ticket.clone()]).to_json().unwrap();
+        assert!(json.contains("\"priority\": \"high\""));
+        assert!(json.contains("\"recurrence\": \"every 2 weeks\""));
+
+        let document = SchemaDocument::<Vec<Ticket>>::from_json(&json).unwrap();
+        assert_eq!(document.schema_version, SCHEMA_VERSION);
+        assert_eq!(document.data, vec![ticket]);
+
+        let newer = json.replacen(&format!("\"schema_version\": {}", SCHEMA_VERSION), &format!("\"schema_version\": {}", SCHEMA_VERSION + 1), 1);
+        assert!(SchemaDocument::<Vec<Ticket>>::from_json(&newer).is_err());
+    }
+}
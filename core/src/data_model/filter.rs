@@ -5,6 +5,7 @@ use crate::TicketAdapter;
    in the frontend for example
  */
 #[derive(Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
 pub enum FilterType {
     User,
     #[default] Builtin,
@@ -17,7 +18,8 @@ pub enum FilterType {
    The Filter Identifier is a little Structure, that contains enough data to describe the
    Filter uniquely.
  */
-#[derive(Default, PartialEq, Clone)]
+#[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FilterIdentifier {
     pub adapter: String,
     pub name: String
@@ -36,9 +38,12 @@ pub struct FilterIdentifier {
    The operations structure usually is the following:
    ```[[adaptername: operation]]```
    
-   You can chain multiple of these.
+   You can chain multiple of these. Without any brackets, the operation
+   is an adapter neutral FilterQuery like ```with_state(open) with_tag(bug)```,
+   which is answered by every adapter.
  */
 #[derive(Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Filter {
     pub identifier: FilterIdentifier,
     pub operation: String,
@@ -0,0 +1,320 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use crate::{AdapterError, AdapterErrorType};
+
+use super::{
+    Bucket,
+    Ticket,
+    recurrence::{civil_from_days, days_from_civil, days_in_month}
+};
+
+const SECONDS_PER_DAY: i64 = 86400;
+const MILLIS_PER_SECOND: i64 = 1000;
+
+/**
+   A single condition of an adapter neutral filter. Texts are compared
+   like they are written, only the contains conditions ignore the case.
+   Dates are timestamps in seconds, before means earlier than the
+   timestamp, after means at or later than the timestamp. Due dates of
+   tickets are stored in milliseconds, so they are compared with the
+   dates multiplied by 1000, see get_due_millis.
+ */
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FilterCondition {
+    State(String),
+    Tag(String),
+    Bucket(String),
+    AssignedTo(Vec<String>),
+    TitleContains(String),
+    DescriptionContains(String),
+    TextContains(String),
+    DueInDays(i64),
+    DueBefore(i64),
+    DueAfter(i64),
+    CreatedBefore(i64),
+    CreatedAfter(i64)
+}
+
+impl FilterCondition {
+
+    /**
+       Returns the name of the condition, like it is written in a filter
+     */
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FilterCondition::State(_) => "with_state",
+            FilterCondition::Tag(_) => "with_tag",
+            FilterCondition::Bucket(_) => "in_bucket",
+            FilterCondition::AssignedTo(_) => "assigned_to",
+            FilterCondition::TitleContains(_) => "title_contains",
+            FilterCondition::DescriptionContains(_) => "description_contains",
+            FilterCondition::TextContains(_) => "text_contains",
+            FilterCondition::DueInDays(_) => "due_in_days",
+            FilterCondition::DueBefore(_) => "due_before",
+            FilterCondition::DueAfter(_) => "due_after",
+            FilterCondition::CreatedBefore(_) => "created_before",
+            FilterCondition::CreatedAfter(_) => "created_after",
+        }
+    }
+
+    /**
+       Creates the condition from it's name and the text between the
+       parentheses. Fails, if the name is unknown or the value doesn't fit
+     */
+    pub fn from_parts(name: &str, value: &str) -> Result<Self, AdapterError> {
+        let invalid = |expected: &str| AdapterError::new(AdapterErrorType::Expression(
+            format!("{} requires {}, but found \"{}\"", name, expected, value)));
+
+        let text = || match value.is_empty() {
+            true => Err(invalid("a text")),
+            false => Ok(value.to_string()),
+        };
+
+        let date = || FilterCondition::parse_date(value).ok_or_else(|| invalid("a date like 2024-01-31"));
+
+        match name {
+            "with_state" => text().map(FilterCondition::State),
+            "with_tag" => text().map(FilterCondition::Tag),
+            "in_bucket" => text().map(FilterCondition::Bucket),
+            "assigned_to" => {
+                let users: Vec<String> = value.split(',')
+                    .map(str::trim)
+                    .filter(|user| !user.is_empty())
+                    .map(str::to_string)
+                    .collect();
+
+                match users.is_empty() {
+                    true => Err(invalid("a comma separated list of users")),
+                    false => Ok(FilterCondition::AssignedTo(users)),
+                }
+            },
+            "title_contains" => text().map(FilterCondition::TitleContains),
+            "description_contains" => text().map(FilterCondition::DescriptionContains),
+            "text_contains" => text().map(FilterCondition::TextContains),
+            "due_in_days" => value.parse::<i64>()
+                .map(FilterCondition::DueInDays)
+                .map_err(|_| invalid("a number")),
+            "due_before" => date().map(FilterCondition::DueBefore),
+            "due_after" => date().map(FilterCondition::DueAfter),
+            "created_before" => date().map(FilterCondition::CreatedBefore),
+            "created_after" => date().map(FilterCondition::CreatedAfter),
+            _ => Err(AdapterError::new(AdapterErrorType::Expression(format!("\"{}\" is not a known filter condition", name))))
+        }
+    }
+
+    /**
+       Returns the value of the condition, like it is written between
+       the parentheses
+     */
+    pub fn get_value(&self) -> String {
+        match self {
+            FilterCondition::State(text) |
+            FilterCondition::Tag(text) |
+            FilterCondition::Bucket(text) |
+            FilterCondition::TitleContains(text) |
+            FilterCondition::DescriptionContains(text) |
+            FilterCondition::TextContains(text) => text.clone(),
+            FilterCondition::AssignedTo(users) => users.join(", "),
+            FilterCondition::DueInDays(days) => days.to_string(),
+            FilterCondition::DueBefore(timestamp) |
+            FilterCondition::DueAfter(timestamp) |
+            FilterCondition::CreatedBefore(timestamp) |
+            FilterCondition::CreatedAfter(timestamp) => FilterCondition::format_date(*timestamp),
+        }
+    }
+
+    /**
+       Tests, if the ticket satisfies this condition. The buckets are only
+       needed for in_bucket, which compares the name of the ticket's bucket
+     */
+    pub fn matches(&self, ticket: &Ticket, buckets: &[Bucket]) -> bool {
+        let contains = |text: &str, part: &str| text.to_lowercase().contains(&part.to_lowercase());
+
+        match self {
+            FilterCondition::State(state) => ticket.state_name == *state,
+            FilterCondition::Tag(tag) => ticket.tags.contains(tag),
+            FilterCondition::Bucket(bucket) => buckets.iter().any(|candidate|
+                candidate.identifier.adapter == ticket.adapter &&
+                candidate.identifier.id == ticket.bucket_id &&
+                candidate.name == *bucket),
+            FilterCondition::AssignedTo(users) => users.contains(&ticket.assigned_to),
+            FilterCondition::TitleContains(title) => contains(&ticket.title, title),
+            FilterCondition::DescriptionContains(description) => contains(&ticket.description, description),
+            FilterCondition::TextContains(text) => contains(&ticket.title, text) || contains(&ticket.description, text),
+            FilterCondition::DueInDays(days) => ticket.due_at < FilterCondition::get_due_millis(FilterCondition::start_of_today() + days * SECONDS_PER_DAY),
+            FilterCondition::DueBefore(timestamp) => ticket.due_at < FilterCondition::get_due_millis(*timestamp),
+            FilterCondition::DueAfter(timestamp) => ticket.due_at >= FilterCondition::get_due_millis(*timestamp),
+            FilterCondition::CreatedBefore(timestamp) => ticket.created_at < *timestamp,
+            FilterCondition::CreatedAfter(timestamp) => ticket.created_at >= *timestamp,
+        }
+    }
+
+    /**
+       Turns a timestamp in seconds into milliseconds, which is the unit
+       due dates of tickets are stored in. Creation dates stay in seconds
+     */
+    pub fn get_due_millis(timestamp: i64) -> i64 {
+        timestamp.saturating_mul(MILLIS_PER_SECOND)
+    }
+
+    /**
+       Returns the timestamp of the beginning of the current day in UTC
+     */
+    pub fn start_of_today() -> i64 {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        now - now.rem_euclid(SECONDS_PER_DAY)
+    }
+
+    /**
+       Reads either a date like 2024-01-31, which is the beginning of that
+       day in UTC, or a plain timestamp in seconds
+     */
+    fn parse_date(value: &str) -> Option<i64> {
+        if let Ok(timestamp) = value.parse::<i64>() {
+            return Some(timestamp);
+        }
+
+        let mut parts = value.split('-');
+        let (Some(year), Some(month), Some(day), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return None;
+        };
+
+        let year = year.parse::<i64>().ok()?;
+        let month = month.parse::<u32>().ok().filter(|month| (1..=12).contains(month))?;
+        let day = day.parse::<u32>().ok().filter(|day| *day >= 1 && *day <= days_in_month(year, month))?;
+
+        Some(days_from_civil(year, month, day) * SECONDS_PER_DAY)
+    }
+
+    /**
+       Writes timestamps at the beginning of a day as date, every other
+       one as plain timestamp, so it can be read again by parse_date
+     */
+    fn format_date(timestamp: i64) -> String {
+        if timestamp.rem_euclid(SECONDS_PER_DAY) != 0 {
+            return timestamp.to_string();
+        }
+
+        let (year, month, day) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl Display for FilterCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}({})", self.get_name(), self.get_value())
+    }
+}
+
+/**
+   A filter, that doesn't belong to a specific adapter. It is written
+   like ```with_state(open) with_tag(bug)``` and only lists tickets,
+   that satisfy all of it's conditions. Every adapter can answer it,
+   either by compiling it into it's own query language or by testing
+   each of it's tickets with matches.
+ */
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", try_from = "String"))]
+pub struct FilterQuery {
+    conditions: Vec<FilterCondition>
+}
+
+impl FilterQuery {
+
+    /**
+       Creates a query without any conditions, which matches every ticket
+     */
+    pub fn new() -> Self {
+        FilterQuery { conditions: vec![] }
+    }
+
+    pub fn with(mut self, condition: FilterCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn get_conditions(&self) -> &[FilterCondition] {
+        &self.conditions
+    }
+
+    /**
+       Returns true, if the query needs the buckets of the adapter to be
+       evaluated, so they only have to be loaded if necessary
+     */
+    pub fn uses_buckets(&self) -> bool {
+        self.conditions.iter().any(|condition| matches!(condition, FilterCondition::Bucket(_)))
+    }
+
+    /**
+       Tests, if the ticket satisfies all conditions of the query
+     */
+    pub fn matches(&self, ticket: &Ticket, buckets: &[Bucket]) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(ticket, buckets))
+    }
+}
+
+impl FromStr for FilterQuery {
+    type Err = AdapterError;
+
+    /**
+       Parses a list of conditions separated by whitespace, like
+       "with_state(open) title_contains(login page)". Values end at the
+       first closing parenthesis
+     */
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut query = FilterQuery::new();
+        let mut rest = text.trim();
+
+        while !rest.is_empty() {
+            let Some((name, value_and_rest)) = rest.split_once('(') else {
+                return Err(AdapterError::new(AdapterErrorType::Expression(format!("Expected a condition like with_state(open), but found \"{}\"", rest))));
+            };
+
+            let Some((value, leftover)) = value_and_rest.split_once(')') else {
+                return Err(AdapterError::new(AdapterErrorType::Expression(format!("Condition \"{}\" doesn't end with )", name.trim()))));
+            };
+
+            if name.trim().contains(char::is_whitespace) {
+                return Err(AdapterError::new(AdapterErrorType::Expression(format!("Expected a single condition, but found \"{}\"", name.trim()))));
+            }
+
+            query = query.with(FilterCondition::from_parts(name.trim(), value.trim())?);
+            rest = leftover.trim_start();
+        }
+
+        if query.conditions.is_empty() {
+            return Err(AdapterError::new(AdapterErrorType::Expression("The filter doesn't contain any condition".to_string())));
+        }
+
+        Ok(query)
+    }
+}
+
+impl Display for FilterQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let conditions = self.conditions.iter()
+            .map(FilterCondition::to_string)
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        write!(f, "{}", conditions)
+    }
+}
+
+impl From<FilterQuery> for String {
+    fn from(query: FilterQuery) -> Self {
+        query.to_string()
+    }
+}
+
+impl TryFrom<String> for FilterQuery {
+    type Error = AdapterError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
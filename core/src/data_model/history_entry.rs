@@ -0,0 +1,59 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH
+};
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryEntry {
+    pub adapter: String,
+    pub ticket_id: i64,
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+    pub changed_by: String,
+    pub changed_at: i64
+}
+
+impl Default for HistoryEntry {
+    fn default() -> Self {
+        HistoryEntry {
+            adapter: "".into(),
+            ticket_id: 0,
+            field: "".into(),
+            old_value: "".into(),
+            new_value: "".into(),
+            changed_by: "".into(),
+            changed_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+        }
+    }
+}
+
+impl HistoryEntry {
+
+    pub fn with_change(mut self, field: &str, old_value: String, new_value: String) -> Self {
+        self.field = field.to_string();
+        self.old_value = old_value;
+        self.new_value = new_value;
+        self
+    }
+
+    pub fn with_author(mut self, changed_by: String) -> Self {
+        self.changed_by = changed_by;
+        self
+    }
+
+    /**
+       Returns a short, human readable description of the change,
+       such as "state: new -> done"
+     */
+    pub fn describe(&self) -> String {
+        if self.old_value.is_empty() {
+            format!("{}: added \"{}\"", self.field, self.new_value)
+        } else if self.new_value.is_empty() {
+            format!("{}: removed \"{}\"", self.field, self.old_value)
+        } else {
+            format!("{}: \"{}\" -> \"{}\"", self.field, self.old_value, self.new_value)
+        }
+    }
+}
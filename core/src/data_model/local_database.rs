@@ -44,6 +44,32 @@ impl LocalDatabase {
         }
     }
 
+    /**
+       Adds a column to an existing table, if the table doesn't have a column
+       with that name yet. This is needed, because create_table doesn't touch
+       tables, that already exist. Returns true, if the column has been added
+     */
+    pub fn add_column(&mut self, tablename: &str, column_name: &str, definition: &str) -> bool {
+        let connection = self.connection.lock().unwrap();
+        let mut stmt_columns = connection.prepare(["PRAGMA table_info(", tablename, ");"].join("").as_str()).unwrap();
+        let mut rows = stmt_columns.query([]).unwrap();
+
+        let mut columns: Vec<String> = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            columns.push(row.get(1).unwrap());
+        }
+
+        if columns.is_empty() || columns.iter().any(|column| column == column_name) {
+            false
+        } else {
+            let expression = ["ALTER TABLE ", tablename, " ADD COLUMN ", column_name, " ", definition, ";"].join("");
+
+            let mut stmt_column_add = connection.prepare(expression.as_str()).unwrap();
+            stmt_column_add.execute([]).unwrap();
+            true
+        }
+    }
+
     pub fn open(path: String) -> Result<LocalDatabase, Error> {
         match Connection::open(path.as_str()) {
             Ok(conn) => Ok(LocalDatabase{connection: Arc::new(Mutex::new(conn))}),
@@ -1,20 +1,57 @@
 pub mod tag;
 mod ticket;
+mod ticket_page;
+mod ticket_sort;
+mod search_hit;
+mod comment;
+mod history_entry;
+mod custom_field;
+mod ticket_link;
+mod checklist_item;
+mod attachment;
+mod work_log;
+mod priority;
+mod recurrence;
 mod bucket;
 mod filter;
+mod filter_query;
 mod state;
 mod config;
 mod local_database;
 mod bucket_panel_location;
+#[cfg(feature = "serde")]
+mod schema;
 mod data_model_tests;
 
 pub use tag::Tag as Tag;
 pub use ticket::Ticket as Ticket;
+pub use ticket::TicketIdentifier as TicketIdentifier;
+pub use ticket_page::TicketPage as TicketPage;
+pub use ticket_page::TicketPageRequest as TicketPageRequest;
+pub use ticket_sort::TicketSort as TicketSort;
+pub use ticket_sort::TicketSortKey as TicketSortKey;
+pub use ticket_sort::SortDirection as SortDirection;
+pub use search_hit::SearchHit as SearchHit;
+pub use comment::Comment as Comment;
+pub use history_entry::HistoryEntry as HistoryEntry;
+pub use custom_field::CustomField as CustomField;
+pub use custom_field::CustomFieldType as CustomFieldType;
+pub use ticket_link::TicketLink as TicketLink;
+pub use ticket_link::TicketLinkType as TicketLinkType;
+pub use checklist_item::ChecklistItem as ChecklistItem;
+pub use attachment::Attachment as Attachment;
+pub use work_log::WorkLog as WorkLog;
+pub use work_log::TimeSpent as TimeSpent;
+pub use priority::Priority as Priority;
+pub use recurrence::Recurrence as Recurrence;
+pub use recurrence::CronSchedule as CronSchedule;
 pub use bucket::Bucket as Bucket;
 pub use bucket::BucketIdentifier as BucketIdentifier;
 pub use filter::Filter as Filter;
 pub use filter::FilterType as FilterType;
 pub use filter::FilterIdentifier as FilterIdentifier;
+pub use filter_query::FilterQuery as FilterQuery;
+pub use filter_query::FilterCondition as FilterCondition;
 pub use state::State as State;
 pub use state::StateIdentifier as StateIdentifier;
 pub use config::AppConfig as AppConfig;
@@ -23,4 +60,8 @@ pub use config::ConfigOption as ConfigOption;
 pub use config::ToConfig as ToConfig;
 pub use local_database::LocalDatabase as LocalDatabase;
 pub use bucket_panel_location::BucketPanelLocation as BucketPanelLocation;
-pub use bucket_panel_location::BucketPanelLocationType as BucketPanelLocationType;
\ No newline at end of file
+pub use bucket_panel_location::BucketPanelLocationType as BucketPanelLocationType;
+#[cfg(feature = "serde")]
+pub use schema::SchemaDocument as SchemaDocument;
+#[cfg(feature = "serde")]
+pub use schema::SCHEMA_VERSION as SCHEMA_VERSION;
@@ -0,0 +1,72 @@
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical
+}
+
+impl Priority {
+
+    /**
+       Returns all priorities ordered from lowest to highest
+     */
+    pub fn all() -> Vec<Priority> {
+        vec![
+            Priority::Low,
+            Priority::Normal,
+            Priority::High,
+            Priority::Critical
+        ]
+    }
+
+    /**
+       Returns the technical name of the priority, that is used
+       in expressions
+     */
+    pub fn get_name(&self) -> String {
+        match self {
+            Priority::Low => "low",
+            Priority::Normal => "normal",
+            Priority::High => "high",
+            Priority::Critical => "critical",
+        }.to_string()
+    }
+
+    /**
+       Returns the numeric value of the priority. Higher values
+       mean a higher priority, which allows adapters to store and
+       compare them
+     */
+    pub fn get_value(&self) -> i64 {
+        match self {
+            Priority::Low => 0,
+            Priority::Normal => 1,
+            Priority::High => 2,
+            Priority::Critical => 3,
+        }
+    }
+
+    /**
+       Creates the priority from it's technical name. Returns None,
+       if the name is unknown
+     */
+    pub fn from_name(name: &str) -> Option<Self> {
+        Priority::all().into_iter().find(|priority| priority.get_name() == name)
+    }
+
+    /**
+       Creates the priority from it's numeric value. Values outside
+       of the known range are clamped to the lowest or highest priority
+     */
+    pub fn from_value(value: i64) -> Self {
+        match value {
+            i64::MIN..=0 => Priority::Low,
+            1 => Priority::Normal,
+            2 => Priority::High,
+            _ => Priority::Critical,
+        }
+    }
+}
@@ -0,0 +1,335 @@
+const MINUTE_MILLIS: i64 = 60 * 1000;
+const DAY_MILLIS: i64 = 24 * 60 * MINUTE_MILLIS;
+
+/**
+   How far into the future a cron expression is searched for the next
+   occurrence, before giving up. Expressions like "0 0 31 2 *" never match
+ */
+const CRON_SEARCH_DAYS: i64 = 366 * 5;
+
+/**
+   A rule, that describes how often a ticket repeats. All timestamps
+   are in milliseconds, just like the due date of a ticket, and all
+   calculations are done in UTC.
+ */
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", try_from = "String"))]
+pub enum Recurrence {
+    Daily(u32),
+    Weekly(u32),
+    Monthly(u32),
+    Cron(CronSchedule)
+}
+
+impl Recurrence {
+
+    /**
+       Reads a recurrence rule from text. Accepted are "daily", "weekly",
+       "monthly", intervals like "every 2 weeks" and cron expressions with
+       five fields (minute hour day month weekday), like "0 9 * * 1-5".
+       Returns None, if the text is not a valid rule
+     */
+    pub fn from_text(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["daily"] => Some(Recurrence::Daily(1)),
+            ["weekly"] => Some(Recurrence::Weekly(1)),
+            ["monthly"] => Some(Recurrence::Monthly(1)),
+            ["every", unit] => Recurrence::from_interval(1, unit),
+            ["every", count, unit] => match count.parse::<u32>() {
+                Ok(count) if count > 0 => Recurrence::from_interval(count, unit),
+                _ => None
+            },
+            _ => CronSchedule::from_text(text.as_str()).map(Recurrence::Cron)
+        }
+    }
+
+    fn from_interval(count: u32, unit: &str) -> Option<Self> {
+        match unit.trim_end_matches('s') {
+            "day" => Some(Recurrence::Daily(count)),
+            "week" => Some(Recurrence::Weekly(count)),
+            "month" => Some(Recurrence::Monthly(count)),
+            _ => None
+        }
+    }
+
+    /**
+       Returns the rule in the same form, that from_text accepts
+     */
+    pub fn to_text(&self) -> String {
+        match self {
+            Recurrence::Daily(1) => "daily".to_string(),
+            Recurrence::Weekly(1) => "weekly".to_string(),
+            Recurrence::Monthly(1) => "monthly".to_string(),
+            Recurrence::Daily(count) => format!("every {} days", count),
+            Recurrence::Weekly(count) => format!("every {} weeks", count),
+            Recurrence::Monthly(count) => format!("every {} months", count),
+            Recurrence::Cron(schedule) => schedule.to_text(),
+        }
+    }
+
+    /**
+       Returns the next occurrence after the given timestamp in milliseconds.
+       Intervals keep the time of day, months that are too short use their
+       last day. Returns None, if a cron expression never matches
+     */
+    pub fn next_after(&self, timestamp: i64) -> Option<i64> {
+        match self {
+            Recurrence::Daily(count) => Some(timestamp + *count as i64 * DAY_MILLIS),
+            Recurrence::Weekly(count) => Some(timestamp + *count as i64 * 7 * DAY_MILLIS),
+            Recurrence::Monthly(count) => {
+                let days = timestamp.div_euclid(DAY_MILLIS);
+                let time_of_day = timestamp.rem_euclid(DAY_MILLIS);
+                let (year, month, day) = civil_from_days(days);
+
+                let months = year * 12 + (month as i64 - 1) + *count as i64;
+                let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+                let day = day.min(days_in_month(year, month));
+
+                Some(days_from_civil(year, month, day) * DAY_MILLIS + time_of_day)
+            },
+            Recurrence::Cron(schedule) => schedule.next_after(timestamp),
+        }
+    }
+
+    /**
+       Returns the first occurrence of a series, that starts at the given
+       timestamp, which is due after the other timestamp. Both are in
+       milliseconds. Daily and weekly intervals skip ahead directly, so
+       series, that haven't been touched for a long time, don't need to
+       be stepped through
+     */
+    pub fn first_after(&self, start: i64, after: i64) -> Option<i64> {
+        let interval = match self {
+            Recurrence::Daily(count) => *count as i64 * DAY_MILLIS,
+            Recurrence::Weekly(count) => *count as i64 * 7 * DAY_MILLIS,
+            Recurrence::Monthly(_) => {
+                let mut next = self.next_after(start)?;
+                while next <= after {
+                    let following = self.next_after(next)?;
+                    if following <= next {
+                        return None;
+                    }
+                    next = following;
+                }
+                return Some(next);
+            },
+            Recurrence::Cron(schedule) => return schedule.next_after(start.max(after)),
+        };
+
+        if interval <= 0 {
+            return None;
+        }
+
+        let steps = (after - start).div_euclid(interval) + 1;
+        Some(start + steps.max(1) * interval)
+    }
+}
+
+/**
+   A cron expression with the fields minute, hour, day of month, month
+   and day of week. Each field supports "*", numbers, ranges like "1-5",
+   lists like "1,15" and steps like "0-30/5". Sunday is 0 or 7.
+ */
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", try_from = "String"))]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool
+}
+
+impl CronSchedule {
+
+    /**
+       Reads a cron expression with five fields. Returns None, if
+       a field is missing or out of range
+     */
+    pub fn from_text(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+
+        let [minutes, hours, days, months, weekdays] = fields.as_slice() else {
+            return None;
+        };
+
+        let mut weekday_mask = CronSchedule::parse_field(weekdays, 0, 7)?;
+
+        // Sunday can be written as 0 or 7
+        if weekday_mask & (1 << 7) != 0 {
+            weekday_mask |= 1;
+        }
+
+        Some(CronSchedule {
+            expression: fields.join(" "),
+            minutes: CronSchedule::parse_field(minutes, 0, 59)?,
+            hours: CronSchedule::parse_field(hours, 0, 23)?,
+            days: CronSchedule::parse_field(days, 1, 31)?,
+            months: CronSchedule::parse_field(months, 1, 12)?,
+            weekdays: weekday_mask,
+            any_day: *days == "*",
+            any_weekday: *weekdays == "*"
+        })
+    }
+
+    fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+        let mut mask: u64 = 0;
+
+        for item in field.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)?),
+                None => (item, 1),
+            };
+
+            let (start, end) = match range {
+                "*" => (min, max),
+                range => match range.split_once('-') {
+                    Some((start, end)) => (start.parse::<u32>().ok()?, end.parse::<u32>().ok()?),
+                    None => {
+                        let value = range.parse::<u32>().ok()?;
+
+                        // "5/10" means starting at 5 up to the maximum
+                        if item.contains('/') { (value, max) } else { (value, value) }
+                    }
+                }
+            };
+
+            if start < min || end > max || start > end {
+                return None;
+            }
+
+            for value in (start..=end).step_by(step as usize) {
+                mask |= 1 << value;
+            }
+        }
+
+        Some(mask)
+    }
+
+    pub fn to_text(&self) -> String {
+        self.expression.clone()
+    }
+
+    fn matches_day(&self, days: i64) -> bool {
+        let (_, month, day) = civil_from_days(days);
+
+        // The first of January 1970 was a thursday
+        let weekday = (days + 4).rem_euclid(7);
+
+        let day_matches = self.days & (1 << day) != 0;
+        let weekday_matches = self.weekdays & (1 << weekday) != 0;
+
+        // Like in cron, a restricted day and weekday match, if either of them matches
+        let day_matches = match (self.any_day, self.any_weekday) {
+            (false, false) => day_matches || weekday_matches,
+            _ => day_matches && weekday_matches
+        };
+
+        day_matches && self.months & (1 << month) != 0
+    }
+
+    /**
+       Returns the first minute after the given timestamp in milliseconds,
+       that matches the expression
+     */
+    pub fn next_after(&self, timestamp: i64) -> Option<i64> {
+        let start = timestamp.div_euclid(MINUTE_MILLIS) + 1;
+        let start_day = start.div_euclid(24 * 60);
+        let start_minute = start.rem_euclid(24 * 60);
+
+        for days in start_day..start_day + CRON_SEARCH_DAYS {
+            if !self.matches_day(days) {
+                continue;
+            }
+
+            let first_minute = if days == start_day { start_minute } else { 0 };
+
+            for minute_of_day in first_minute..24 * 60 {
+                if self.hours & (1 << (minute_of_day / 60)) != 0 && self.minutes & (1 << (minute_of_day % 60)) != 0 {
+                    return Some(days * DAY_MILLIS + minute_of_day * MINUTE_MILLIS);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/**
+   Recurrences and cron schedules are serialized in their text form,
+   so they stay readable and editable in exported files
+ */
+impl From<Recurrence> for String {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_text()
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Recurrence::from_text(&text).ok_or_else(|| format!("\"{}\" is not a valid recurrence rule", text))
+    }
+}
+
+impl From<CronSchedule> for String {
+    fn from(schedule: CronSchedule) -> Self {
+        schedule.to_text()
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        CronSchedule::from_text(&text).ok_or_else(|| format!("\"{}\" is not a valid cron expression", text))
+    }
+}
+
+/**
+   Converts days since the unix epoch into year, month and day,
+   following Howard Hinnant's date algorithms
+ */
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+/**
+   Converts year, month and day into days since the unix epoch
+ */
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
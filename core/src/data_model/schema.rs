@@ -0,0 +1,61 @@
+use serde::{
+    de::{DeserializeOwned, Error},
+    Deserialize,
+    Serialize
+};
+
+/**
+   The version of the JSON format of the data model, which is described
+   in SCHEMA.md. It is increased on every change, that existing documents
+   or the programs reading them can't cope with.
+ */
+pub const SCHEMA_VERSION: u32 = 1;
+
+/**
+   Wraps data from the data model together with the schema version, it
+   has been written with. Scripts and integrations should exchange their
+   data in such a document, so the format can evolve without silently
+   breaking them.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SchemaDocument<T> {
+    pub schema_version: u32,
+    pub data: T
+}
+
+impl<T> SchemaDocument<T> {
+
+    /**
+       Creates a document with the current schema version
+     */
+    pub fn new(data: T) -> Self {
+        SchemaDocument { schema_version: SCHEMA_VERSION, data }
+    }
+}
+
+impl<T: Serialize> SchemaDocument<T> {
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl<T: DeserializeOwned> SchemaDocument<T> {
+
+    /**
+       Reads a document from JSON. Documents from a newer schema version
+       are refused, because they may contain data, that would get lost
+     */
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let document: SchemaDocument<T> = serde_json::from_str(json)?;
+
+        if document.schema_version > SCHEMA_VERSION {
+            return Err(serde_json::Error::custom(format!(
+                "schema version {} is newer than the supported version {}",
+                document.schema_version,
+                SCHEMA_VERSION)));
+        }
+
+        Ok(document)
+    }
+}
@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+use super::Ticket;
+
+/**
+   A ticket, that has been found by a full text search, together with how
+   well it fits the search. Scores are between 0 and 1, higher is better.
+   All adapters use the same scale, so the hits of different adapters
+   can be compared with each other.
+ */
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchHit {
+    pub ticket: Ticket,
+    pub score: f64
+}
+
+impl SearchHit {
+
+    pub fn new(ticket: Ticket, score: f64) -> Self {
+        SearchHit { ticket, score }
+    }
+
+    /**
+       Splits a search into it's lower case words. Everything, that isn't
+       a letter or a digit, separates words, so the search doesn't need
+       any syntax
+     */
+    pub fn words(text: &str) -> Vec<String> {
+        text.split(|character: char| !character.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    /**
+       Scores a ticket by the share of the words of the search, that it
+       contains. Words in the title count twice as much as words in the
+       description. This is used by adapters without their own index
+     */
+    pub fn score(ticket: &Ticket, text: &str) -> f64 {
+        let words = SearchHit::words(text);
+
+        if words.is_empty() {
+            return 0.0;
+        }
+
+        let title = ticket.title.to_lowercase();
+        let description = ticket.description.to_lowercase();
+
+        let points: usize = words.iter()
+            .map(|word| match (title.contains(word.as_str()), description.contains(word.as_str())) {
+                (true, _) => 2,
+                (false, true) => 1,
+                (false, false) => 0,
+            })
+            .sum();
+
+        points as f64 / (2 * words.len()) as f64
+    }
+
+    /**
+       Turns a bm25 rank of a full text index into a score. The ranks are
+       negative and unbounded, the better the hit, the lower the rank. The
+       score approaches 1 for very good hits, but never reaches it, so it
+       doesn't depend on the other hits of the same search
+     */
+    pub fn rank_score(rank: f64) -> f64 {
+        let relevance = (-rank).max(0.0);
+        relevance / (1.0 + relevance)
+    }
+
+    /**
+       Sorts the hits with the best one first. Hits with the same score
+       keep their order
+     */
+    pub fn sort(hits: &mut [SearchHit]) {
+        hits.sort_by(|first, second| second.score.partial_cmp(&first.score).unwrap_or(Ordering::Equal));
+    }
+}
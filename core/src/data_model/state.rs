@@ -1,6 +1,7 @@
 use crate::TicketAdapter;
 
-#[derive(Default, PartialEq, Clone, Eq, Hash)]
+#[derive(Default, PartialEq, Clone, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateIdentifier {
     pub adapter: String,
     pub name: String,
@@ -13,6 +14,7 @@ impl StateIdentifier {
 }
 
 #[derive(Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct State {
     pub identifier: StateIdentifier,
     pub description: String,
@@ -3,6 +3,7 @@ use std::{fmt::Write, ops::Range};
 use crate::TicketAdapter;
 
 #[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag {
     pub adapter: String,
     pub name: String,
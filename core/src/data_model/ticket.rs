@@ -1,17 +1,89 @@
-use std::time::{
-    SystemTime, 
-    UNIX_EPOCH
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    },
+    str::FromStr,
+    time::{
+        SystemTime, 
+        UNIX_EPOCH
+    }
 };
 
-use crate::TicketAdapter;
+use crate::{
+    TicketAdapter,
+    AdapterError,
+    AdapterErrorType
+};
 
 use super::{
     Bucket, 
     State,
-    Tag
+    Tag,
+    ChecklistItem,
+    Priority,
+    Recurrence
 };
 
+/**
+   Identifies a ticket across all adapters. The ids of tickets are only
+   unique within their adapter, so both are needed. It's string form is
+   ```adapter:id```, which can be parsed back again.
+ */
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TicketIdentifier {
+    pub adapter: String,
+    pub id: i64
+}
+
+impl TicketIdentifier {
+    pub fn new(adapter: &str, id: i64) -> Self {
+        TicketIdentifier {
+            adapter: adapter.to_string(),
+            id
+        }
+    }
+}
+
+impl From<&Ticket> for TicketIdentifier {
+    fn from(ticket: &Ticket) -> Self {
+        TicketIdentifier::new(&ticket.adapter, ticket.id)
+    }
+}
+
+impl Display for TicketIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.adapter, self.id)
+    }
+}
+
+impl FromStr for TicketIdentifier {
+    type Err = AdapterError;
+
+    /**
+       Parses the ```adapter:id``` form. The id is everything after the
+       last colon, so adapter names may contain colons themselves
+     */
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || AdapterError::new(AdapterErrorType::Expression(format!("\"{}\" is not a ticket in the form adapter:id", text)));
+
+        let (adapter, id) = text.trim().rsplit_once(':').ok_or_else(invalid)?;
+        let adapter = adapter.trim();
+        let id = id.trim().parse::<i64>().map_err(|_| invalid())?;
+
+        if adapter.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(TicketIdentifier::new(adapter, id))
+    }
+}
+
 #[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ticket {
     pub adapter: String,
     pub id: i64,
@@ -23,6 +95,11 @@ pub struct Ticket {
     pub tags: Vec<String>,
     pub created_at: i64,
     pub due_at: i64,
+    pub estimate: i64,
+    pub priority: Priority,
+    pub recurrence: Option<Recurrence>,
+    pub custom_fields: BTreeMap<String, String>,
+    pub checklist: Vec<ChecklistItem>,
     pub additional_id: String
 }
 
@@ -39,6 +116,11 @@ impl Default for Ticket {
             created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64, 
             due_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64, 
             tags: vec![],
+            estimate: 0,
+            priority: Priority::default(),
+            recurrence: None,
+            custom_fields: BTreeMap::new(),
+            checklist: vec![],
             additional_id: "".into()
         }
     }
@@ -46,6 +128,10 @@ impl Default for Ticket {
 
 impl Ticket {
 
+    pub fn get_identifier(&self) -> TicketIdentifier {
+        TicketIdentifier::from(self)
+    }
+
     pub fn with_adapter(mut self, adapter: &dyn TicketAdapter) -> Self {
         self.adapter = adapter.get_name();
         self
@@ -81,6 +167,84 @@ impl Ticket {
         self
     }
 
+    pub fn with_custom_field(mut self, name: &str, value: String) -> Self {
+        self.custom_fields.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /**
+       Sets the estimated effort of the ticket in seconds. An
+       estimate of 0 means, that the ticket has no estimate
+     */
+    pub fn with_estimate(mut self, estimate: i64) -> Self {
+        self.estimate = estimate;
+        self
+    }
+
+    pub fn with_recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /**
+       Creates the next instance of a recurring ticket. It is a new ticket
+       with the same content, an unfinished checklist and the due date moved
+       to the next occurrence. The recurrence moves on to the new ticket.
+       Returns None, if the ticket doesn't recur
+     */
+    pub fn next_occurrence(&self) -> Option<Ticket> {
+        let recurrence = self.recurrence.as_ref()?;
+        let due_at = recurrence.next_after(self.due_at)?;
+
+        let mut ticket = self.clone();
+        ticket.id = 0;
+        ticket.additional_id = "".into();
+        ticket.created_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        ticket.due_at = due_at;
+
+        for item in ticket.checklist.iter_mut() {
+            item.done = false;
+        }
+
+        Some(ticket)
+    }
+
+    /**
+       Same as next_occurrence, but the instance is the first one, that is
+       due after the given timestamp in milliseconds
+     */
+    pub fn occurrence_after(&self, timestamp: i64) -> Option<Ticket> {
+        let recurrence = self.recurrence.as_ref()?;
+        let due_at = recurrence.first_after(self.due_at, timestamp)?;
+
+        let mut ticket = self.next_occurrence()?;
+        ticket.due_at = due_at;
+        Some(ticket)
+    }
+
+    pub fn with_checklist(mut self, checklist: Vec<ChecklistItem>) -> Self {
+        self.checklist = checklist;
+        self
+    }
+
+    /**
+       Returns the number of finished checklist items and the total
+       number of items. If the ticket has no checklist, None is returned
+     */
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        if self.checklist.is_empty() {
+            return None;
+        }
+
+        let done = self.checklist.iter().filter(|item| item.done).count();
+        Some((done, self.checklist.len()))
+    }
+
     pub fn add_tag(&mut self, tag: &Tag) {
         if !self.tags.contains(&tag.name) {
             self.tags.push(tag.name.clone())
@@ -0,0 +1,122 @@
+use super::TicketIdentifier;
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum TicketLinkType {
+    Blocks,
+    BlockedBy,
+    Duplicates,
+    DuplicatedBy,
+    #[default]
+    RelatesTo,
+    ParentOf,
+    ChildOf
+}
+
+impl TicketLinkType {
+
+    /**
+       Returns all available link types, in the order they
+       should be presented to the user
+     */
+    pub fn all() -> Vec<TicketLinkType> {
+        vec![
+            TicketLinkType::Blocks,
+            TicketLinkType::BlockedBy,
+            TicketLinkType::Duplicates,
+            TicketLinkType::DuplicatedBy,
+            TicketLinkType::RelatesTo,
+            TicketLinkType::ParentOf,
+            TicketLinkType::ChildOf
+        ]
+    }
+
+    /**
+       Returns the technical name of the link type, that is used
+       to store it in an adapter
+     */
+    pub fn get_type_name(&self) -> String {
+        match self {
+            TicketLinkType::Blocks => "blocks",
+            TicketLinkType::BlockedBy => "blocked_by",
+            TicketLinkType::Duplicates => "duplicates",
+            TicketLinkType::DuplicatedBy => "duplicated_by",
+            TicketLinkType::RelatesTo => "relates_to",
+            TicketLinkType::ParentOf => "parent_of",
+            TicketLinkType::ChildOf => "child_of",
+        }.to_string()
+    }
+
+    /**
+       Returns the human readable name of the link type
+     */
+    pub fn get_fancy_name(&self) -> String {
+        self.get_type_name().replace('_', " ")
+    }
+
+    /**
+       Creates the link type from it's technical name. Returns None,
+       if the name is unknown
+     */
+    pub fn from_type_name(type_name: &str) -> Option<Self> {
+        TicketLinkType::all().into_iter().find(|link_type| link_type.get_type_name() == type_name)
+    }
+
+    /**
+       Returns the link type, that describes the same relation
+       from the point of view of the target ticket
+     */
+    pub fn inverse(&self) -> Self {
+        match self {
+            TicketLinkType::Blocks => TicketLinkType::BlockedBy,
+            TicketLinkType::BlockedBy => TicketLinkType::Blocks,
+            TicketLinkType::Duplicates => TicketLinkType::DuplicatedBy,
+            TicketLinkType::DuplicatedBy => TicketLinkType::Duplicates,
+            TicketLinkType::RelatesTo => TicketLinkType::RelatesTo,
+            TicketLinkType::ParentOf => TicketLinkType::ChildOf,
+            TicketLinkType::ChildOf => TicketLinkType::ParentOf,
+        }
+    }
+}
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TicketLink {
+    pub id: i64,
+    pub source: TicketIdentifier,
+    pub target: TicketIdentifier,
+    pub link_type: TicketLinkType
+}
+
+impl TicketLink {
+
+    pub fn with_source(mut self, source: TicketIdentifier) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_target(mut self, target: TicketIdentifier) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_type(mut self, link_type: TicketLinkType) -> Self {
+        self.link_type = link_type;
+        self
+    }
+
+    /**
+       Describes this link from the point of view of the supplied ticket.
+       Returns the relation and the other ticket, or None, if the ticket
+       is not part of this link
+     */
+    pub fn seen_from(&self, ticket: &TicketIdentifier) -> Option<(TicketLinkType, TicketIdentifier)> {
+        if &self.source == ticket {
+            Some((self.link_type.clone(), self.target.clone()))
+        } else if &self.target == ticket {
+            Some((self.link_type.inverse(), self.source.clone()))
+        } else {
+            None
+        }
+    }
+}
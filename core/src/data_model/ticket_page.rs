@@ -0,0 +1,98 @@
+use super::Ticket;
+
+/**
+   Describes, which part of a ticket listing should be returned. The
+   offset is the number of tickets, that are skipped, and the limit is
+   the maximum number of tickets, that are returned after that.
+ */
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TicketPageRequest {
+    pub offset: u64,
+    pub limit: u64
+}
+
+impl TicketPageRequest {
+
+    pub fn new(offset: u64, limit: u64) -> Self {
+        TicketPageRequest { offset, limit }
+    }
+
+    /**
+       Creates the request for the very first page with the given size
+     */
+    pub fn first(limit: u64) -> Self {
+        TicketPageRequest { offset: 0, limit }
+    }
+
+    /**
+       Returns the request for the page directly after this one
+     */
+    pub fn next(&self) -> Self {
+        TicketPageRequest { offset: self.offset + self.limit, limit: self.limit }
+    }
+
+    /**
+       Returns the range of indices, this request covers within a listing
+       with the given number of tickets
+     */
+    pub fn range(&self, total: usize) -> std::ops::Range<usize> {
+        let start = (self.offset as usize).min(total);
+        let end = start.saturating_add(self.limit as usize).min(total);
+        start..end
+    }
+}
+
+/**
+   A single page of a ticket listing. Besides the tickets themselves, it
+   holds the request, it answers, and the total number of tickets, the
+   whole listing contains. Adapters may return less tickets than requested,
+   even if the page isn't the last one.
+ */
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TicketPage {
+    pub tickets: Vec<Ticket>,
+    pub request: TicketPageRequest,
+    pub total: u64
+}
+
+impl TicketPage {
+
+    pub fn new(tickets: Vec<Ticket>, request: TicketPageRequest, total: u64) -> Self {
+        TicketPage { tickets, request, total }
+    }
+
+    /**
+       Creates a page by cutting the requested part out of an already
+       complete listing. This is used by adapters, that can't page natively
+     */
+    pub fn from_tickets(mut tickets: Vec<Ticket>, request: TicketPageRequest) -> Self {
+        let total = tickets.len() as u64;
+        let range = request.range(tickets.len());
+
+        TicketPage {
+            tickets: tickets.drain(range).collect(),
+            request,
+            total
+        }
+    }
+
+    /**
+       Returns true, if there are more tickets after this page
+     */
+    pub fn has_more(&self) -> bool {
+        self.request.limit > 0 && self.request.offset + self.request.limit < self.total
+    }
+
+    /**
+       Returns the request for the following page, or None, if this
+       page was the last one
+     */
+    pub fn next_request(&self) -> Option<TicketPageRequest> {
+        match self.has_more() {
+            true => Some(self.request.next()),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr
+};
+
+use crate::{AdapterError, AdapterErrorType};
+
+use super::{State, Ticket};
+
+/**
+   A single property, tickets can be sorted by
+ */
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum TicketSortKey {
+    Priority,
+    DueDate,
+    CreatedDate,
+    State,
+    Title,
+    Assignee,
+    Adapter,
+    Id
+}
+
+impl TicketSortKey {
+
+    /**
+       Returns the name of the key, like it is written in a sort_by expression
+     */
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TicketSortKey::Priority => "priority",
+            TicketSortKey::DueDate => "due",
+            TicketSortKey::CreatedDate => "created",
+            TicketSortKey::State => "state",
+            TicketSortKey::Title => "title",
+            TicketSortKey::Assignee => "assignee",
+            TicketSortKey::Adapter => "adapter",
+            TicketSortKey::Id => "id",
+        }
+    }
+
+    /**
+       Creates the key from it's name. The names of the ticket fields
+       are accepted as well. Returns None, if there is no such key
+     */
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "priority" => Some(TicketSortKey::Priority),
+            "due" | "due_at" => Some(TicketSortKey::DueDate),
+            "created" | "created_at" => Some(TicketSortKey::CreatedDate),
+            "state" | "state_name" => Some(TicketSortKey::State),
+            "title" => Some(TicketSortKey::Title),
+            "assignee" | "assigned_to" => Some(TicketSortKey::Assignee),
+            "adapter" => Some(TicketSortKey::Adapter),
+            "id" => Some(TicketSortKey::Id),
+            _ => None
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum SortDirection {
+    Ascending,
+    Descending
+}
+
+/**
+   Describes the order of a ticket listing with multiple keys. The first
+   key decides, the following ones are only used, if the tickets are equal
+   on all keys before. Tickets, that are equal on every key, are ordered
+   by their adapter and id, so the order is always the same.
+ */
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "String", try_from = "String"))]
+pub struct TicketSort {
+    keys: Vec<(TicketSortKey, SortDirection)>
+}
+
+impl Default for TicketSort {
+
+    /**
+       Most important Tickets first
+     */
+    fn default() -> Self {
+        TicketSort::new().descending(TicketSortKey::Priority)
+    }
+}
+
+impl TicketSort {
+
+    /**
+       Creates a sort without any keys, which only orders by adapter and id
+     */
+    pub fn new() -> Self {
+        TicketSort { keys: vec![] }
+    }
+
+    pub fn ascending(mut self, key: TicketSortKey) -> Self {
+        self.keys.push((key, SortDirection::Ascending));
+        self
+    }
+
+    pub fn descending(mut self, key: TicketSortKey) -> Self {
+        self.keys.push((key, SortDirection::Descending));
+        self
+    }
+
+    pub fn get_keys(&self) -> &[(TicketSortKey, SortDirection)] {
+        &self.keys
+    }
+
+    /**
+       Sorts the tickets in place. The states are needed for sorting by
+       state, which uses their sorting order instead of their name
+     */
+    pub fn sort(&self, tickets: &mut [Ticket], states: &[State]) {
+        let state_order: HashMap<(&str, &str), i64> = states.iter()
+            .map(|state| ((state.identifier.adapter.as_str(), state.identifier.name.as_str()), state.sorting_order))
+            .collect();
+
+        tickets.sort_by(|first, second| self.compare(first, second, &state_order));
+    }
+
+    fn compare(&self, first: &Ticket, second: &Ticket, state_order: &HashMap<(&str, &str), i64>) -> Ordering {
+        let state_of = |ticket: &Ticket| state_order
+            .get(&(ticket.adapter.as_str(), ticket.state_name.as_str()))
+            .copied()
+            .unwrap_or(i64::MAX);
+
+        self.keys.iter()
+            .map(|(key, direction)| {
+                let ordering = match key {
+                    TicketSortKey::Priority => first.priority.cmp(&second.priority),
+                    TicketSortKey::DueDate => first.due_at.cmp(&second.due_at),
+                    TicketSortKey::CreatedDate => first.created_at.cmp(&second.created_at),
+                    TicketSortKey::State => state_of(first).cmp(&state_of(second)),
+                    TicketSortKey::Title => first.title.to_lowercase().cmp(&second.title.to_lowercase()),
+                    TicketSortKey::Assignee => first.assigned_to.to_lowercase().cmp(&second.assigned_to.to_lowercase()),
+                    TicketSortKey::Adapter => first.adapter.cmp(&second.adapter),
+                    TicketSortKey::Id => first.id.cmp(&second.id),
+                };
+
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| first.adapter.cmp(&second.adapter).then(first.id.cmp(&second.id)))
+    }
+}
+
+impl FromStr for TicketSort {
+    type Err = AdapterError;
+
+    /**
+       Parses a comma separated list of keys, each optionally followed by
+       asc or desc, like "due asc, priority desc, title"
+     */
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut sort = TicketSort::new();
+
+        for entry in text.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let mut parts = entry.split_whitespace();
+            let name = parts.next().unwrap_or_default();
+
+            let Some(key) = TicketSortKey::from_name(name) else {
+                return Err(AdapterError::new(AdapterErrorType::Expression(format!("Can't sort by \"{}\", because it is unknown", name))));
+            };
+
+            sort = match (parts.next(), parts.next()) {
+                (None, None) | (Some("asc"), None) => sort.ascending(key),
+                (Some("desc"), None) => sort.descending(key),
+                _ => return Err(AdapterError::new(AdapterErrorType::Expression(format!("Sort key \"{}\" can only be followed by asc or desc", entry))))
+            };
+        }
+
+        Ok(sort)
+    }
+}
+
+impl Display for TicketSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let keys = self.keys.iter()
+            .map(|(key, direction)| match direction {
+                SortDirection::Ascending => format!("{} asc", key.get_name()),
+                SortDirection::Descending => format!("{} desc", key.get_name()),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "{}", keys)
+    }
+}
+
+impl From<TicketSort> for String {
+    fn from(sort: TicketSort) -> Self {
+        sort.to_string()
+    }
+}
+
+impl TryFrom<String> for TicketSort {
+    type Error = AdapterError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
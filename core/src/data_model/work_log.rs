@@ -0,0 +1,102 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH
+};
+
+use super::Ticket;
+
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkLog {
+    pub adapter: String,
+    pub id: i64,
+    pub ticket_id: i64,
+    pub user: String,
+    pub note: String,
+    pub duration: i64,
+    pub logged_at: i64
+}
+
+impl Default for WorkLog {
+    fn default() -> Self {
+        WorkLog {
+            adapter: "".into(),
+            id: 0,
+            ticket_id: 0,
+            user: "".into(),
+            note: "".into(),
+            duration: 0,
+            logged_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+        }
+    }
+}
+
+impl WorkLog {
+
+    pub fn with_ticket(mut self, ticket: &Ticket) -> Self {
+        self.adapter = ticket.adapter.clone();
+        self.ticket_id = ticket.id;
+        self
+    }
+
+    pub fn with_details(mut self, id: i64, user: String, duration: i64, note: String) -> Self {
+        self.id = id;
+        self.user = user;
+        self.duration = duration;
+        self.note = note;
+        self
+    }
+
+    /**
+       Formats a duration in seconds in the same form, that parse_duration
+       accepts, for example "1h 30m". Seconds are cut off
+     */
+    pub fn format_duration(duration: i64) -> String {
+        let hours = duration / 3600;
+        let minutes = (duration % 3600) / 60;
+
+        match (hours, minutes) {
+            (0, minutes) => format!("{}m", minutes),
+            (hours, 0) => format!("{}h", hours),
+            (hours, minutes) => format!("{}h {}m", hours, minutes)
+        }
+    }
+
+    /**
+       Reads a duration like "1h 30m", "1.5h" or "90m" and returns it in
+       seconds. A number without unit is read as minutes. Returns None,
+       if the text can't be read
+     */
+    pub fn parse_duration(text: &str) -> Option<i64> {
+        let mut duration = 0.0;
+        let mut parts = text.split_whitespace().peekable();
+
+        parts.peek()?;
+
+        for part in parts {
+            let (value, factor) = match part.chars().last()? {
+                'h' => (&part[..part.len() - 1], 3600.0),
+                'm' => (&part[..part.len() - 1], 60.0),
+                _ => (part, 60.0)
+            };
+
+            let value = value.parse::<f64>().ok().filter(|value| *value >= 0.0)?;
+            duration += value * factor;
+        }
+
+        Some(duration.round() as i64)
+    }
+}
+
+/**
+   The time, that has been logged on tickets of one bucket and
+   assignee. This is the result of an aggregated time query
+ */
+#[derive(Eq, PartialOrd, Ord, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeSpent {
+    pub adapter: String,
+    pub bucket_id: u64,
+    pub assigned_to: String,
+    pub duration: i64
+}
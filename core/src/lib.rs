@@ -4,11 +4,33 @@ mod ticket_provider;
 
 pub use data_model::Tag as Tag;
 pub use data_model::Ticket as Ticket;
+pub use data_model::TicketPage as TicketPage;
+pub use data_model::TicketPageRequest as TicketPageRequest;
+pub use data_model::TicketSort as TicketSort;
+pub use data_model::TicketSortKey as TicketSortKey;
+pub use data_model::SortDirection as SortDirection;
+pub use data_model::SearchHit as SearchHit;
+pub use data_model::Comment as Comment;
+pub use data_model::HistoryEntry as HistoryEntry;
+pub use data_model::CustomField as CustomField;
+pub use data_model::CustomFieldType as CustomFieldType;
+pub use data_model::TicketIdentifier as TicketIdentifier;
+pub use data_model::TicketLink as TicketLink;
+pub use data_model::TicketLinkType as TicketLinkType;
+pub use data_model::ChecklistItem as ChecklistItem;
+pub use data_model::Attachment as Attachment;
+pub use data_model::WorkLog as WorkLog;
+pub use data_model::TimeSpent as TimeSpent;
+pub use data_model::Priority as Priority;
+pub use data_model::Recurrence as Recurrence;
+pub use data_model::CronSchedule as CronSchedule;
 pub use data_model::Bucket as Bucket;
 pub use data_model::BucketIdentifier as BucketIdentifier;
 pub use data_model::Filter as Filter;
 pub use data_model::FilterIdentifier as FilterIdentifier;
 pub use data_model::FilterType as FilterType;
+pub use data_model::FilterQuery as FilterQuery;
+pub use data_model::FilterCondition as FilterCondition;
 pub use data_model::State as State;
 pub use data_model::StateIdentifier as StateIdentifier;
 pub use data_model::AppConfig as AppConfig;
@@ -18,11 +40,24 @@ pub use data_model::ToConfig as ToConfig;
 pub use data_model::LocalDatabase as LocalDatabase;
 pub use data_model::BucketPanelLocation as BucketPanelLocation;
 pub use data_model::BucketPanelLocationType as BucketPanelLocationType;
+#[cfg(feature = "serde")]
+pub use data_model::SchemaDocument as SchemaDocument;
+#[cfg(feature = "serde")]
+pub use data_model::SCHEMA_VERSION as SCHEMA_VERSION;
 
 pub use adapter_base::AdapterError;
 pub use adapter_base::AdapterErrorType;
+pub use adapter_base::AdapterCapability;
+pub use adapter_base::AdapterCapabilities;
+pub use adapter_base::AdapterState;
+pub use adapter_base::AdapterStatus;
+pub use adapter_base::AdapterFuture;
+pub use adapter_base::block_on;
 pub use adapter_base::TicketAdapter;
 
 pub use ticket_provider::TicketProvider;
 pub use ticket_provider::AdapterConstructor;
-pub use ticket_provider::AdapterType;
\ No newline at end of file
+pub use ticket_provider::AdapterType;
+pub use ticket_provider::AdapterRequest;
+pub use ticket_provider::ProviderEvent;
+pub use ticket_provider::EventBus;
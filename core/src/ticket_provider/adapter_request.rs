@@ -0,0 +1,51 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+    thread
+};
+
+use crate::{AdapterError, AdapterErrorType};
+
+/**
+   A request, that the ticket provider runs on a background thread, so
+   the caller isn't blocked while adapters wait on the network. The user
+   interface polls it every frame, until the result has arrived.
+ */
+pub struct AdapterRequest<T> {
+    result: Arc<Mutex<Option<Result<T, AdapterError>>>>
+}
+
+impl<T: Send + 'static> AdapterRequest<T> {
+
+    /**
+       Runs the given job on a new thread and returns the request,
+       that receives it's result. If the job panics, the request
+       receives an error instead, so it doesn't wait forever
+     */
+    pub(crate) fn dispatch<F>(job: F) -> Self where F: FnOnce() -> T + Send + 'static {
+        let result: Arc<Mutex<Option<Result<T, AdapterError>>>> = Arc::new(Mutex::new(None));
+        let thread_result = result.clone();
+
+        thread::spawn(move || {
+            let output = panic::catch_unwind(AssertUnwindSafe(job))
+                .map_err(|_| AdapterError::new(AdapterErrorType::Access).with_reason("The request stopped unexpectedly"));
+
+            if let Ok(mut lock) = thread_result.lock() {
+                *lock = Some(output);
+            };
+        });
+
+        AdapterRequest { result }
+    }
+
+    /**
+       Returns the result, as soon as it has arrived. The result can only
+       be taken once, afterwards None is returned again
+     */
+    pub fn poll(&self) -> Option<Result<T, AdapterError>> {
+        match self.result.try_lock() {
+            Ok(mut lock) => lock.take(),
+            Err(_) => None,
+        }
+    }
+}
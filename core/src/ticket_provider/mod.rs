@@ -1,28 +1,74 @@
 use std::{sync::{
     Mutex, 
-    Arc
-}, time::{SystemTime, UNIX_EPOCH}};
+    Arc,
+    mpsc::Receiver
+}, time::{SystemTime, UNIX_EPOCH, Duration}, collections::{BTreeMap, HashSet}, thread::{self, JoinHandle}};
 
 use eframe::egui::epaint::ahash::{HashMap, HashMapExt};
 
-use crate::Config;
+use crate::{Config, block_on};
+
+mod adapter_request;
+mod provider_event;
+pub use adapter_request::AdapterRequest;
+pub use provider_event::ProviderEvent;
+pub use provider_event::EventBus;
 
 use super::{
     AppConfig,
     Bucket, 
     Ticket, 
+    TicketIdentifier,
+    TicketPage,
+    TicketPageRequest,
+    TicketSort,
+    Comment, 
+    HistoryEntry, 
+    CustomField, 
+    TicketLink, 
+    Attachment, 
+    WorkLog, 
+    TimeSpent, 
     Filter, 
+    FilterQuery,
+    SearchHit,
     State, 
     Tag,
     AdapterError,
     AdapterErrorType,
+    AdapterCapability,
+    AdapterCapabilities,
+    AdapterStatus,
     TicketAdapter,
     BucketPanelLocation, 
     BucketPanelLocationType
 };
 
+/**
+   The largest attachment in bytes, that can be added to a ticket
+ */
+pub const MAX_ATTACHMENT_SIZE: usize = 20 * 1024 * 1024;
+
+/**
+   The most instances of a single recurring ticket, that are created
+   when catching up on missed occurrences
+ */
+pub const MAX_RECURRENCE_CATCH_UP: usize = 31;
+
+/**
+   How often the background check looks for missed occurrences
+   of recurring tickets
+ */
+const RECURRENCE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/**
+   The config entry, that keeps the time of the last check for missed
+   occurrences in milliseconds
+ */
+const RECURRENCE_LAST_CHECK: &str = "recurrence:last_check";
+
 pub type SyncedTicketAdapter = Box<dyn TicketAdapter + Sync + Send>;
-pub type AdapterConstructor = fn(Arc<Mutex<AppConfig>>, &Config, Arc<Mutex<bool>>) -> Result<SyncedTicketAdapter, AdapterError>;
+pub type AdapterConstructor = fn(Arc<Mutex<AppConfig>>, &Config, EventBus) -> Result<SyncedTicketAdapter, AdapterError>;
 pub type AdapterConfig = fn() -> Config;
 
 
@@ -48,8 +94,8 @@ impl AdapterType {
         }
     }
 
-    pub fn from_config(&self, app_config: Arc<Mutex<AppConfig>>, config: &Config, done_loading: Arc<Mutex<bool>>) -> Result<SyncedTicketAdapter, AdapterError> {
-        (self.new_fn)(app_config, config, done_loading)
+    pub fn from_config(&self, app_config: Arc<Mutex<AppConfig>>, config: &Config, events: EventBus) -> Result<SyncedTicketAdapter, AdapterError> {
+        (self.new_fn)(app_config, config, events)
     }
 
     pub fn config(&self) -> Config {
@@ -61,7 +107,8 @@ impl AdapterType {
 pub struct TicketProvider {
     type_registry: HashMap<String, AdapterType>,
     config: Arc<Mutex<AppConfig>>,
-    adapters: Arc<Mutex<Vec<Arc<SyncedTicketAdapter>>>>
+    adapters: Arc<Mutex<Vec<Arc<SyncedTicketAdapter>>>>,
+    events: EventBus
 }
 
 impl TicketProvider {
@@ -69,9 +116,11 @@ impl TicketProvider {
     /**
        Setup the Ticket Provider. It is supposed to be a single Provider
        that manages all available Adapters, but i tried to avoid making
-       a global instance just for less problems.
+       a global instance just for less problems. Everything, that changes
+       within the provider or it's adapters, is published to the given
+       event bus.
      */
-    pub fn new(config: Arc<Mutex<AppConfig>>, types_list: Vec<AdapterType>, finished: Arc<Mutex<bool>>) -> TicketProvider {
+    pub fn new(config: Arc<Mutex<AppConfig>>, types_list: Vec<AdapterType>, events: EventBus) -> TicketProvider {
 
         let adapters: Vec<Arc<Box<dyn TicketAdapter + Sync + Send>>> = vec![];
         let mut type_registry: HashMap<String, AdapterType> = HashMap::new();
@@ -84,13 +133,142 @@ impl TicketProvider {
             type_registry,
             config,
             adapters: Arc::new(Mutex::new(adapters)),
+            events,
         };
 
-        ticket_provider.adapters_from_app_config(finished);
+        ticket_provider.adapters_from_app_config();
 
         ticket_provider
     }
 
+    /**
+       Creates a second provider, that shares the adapters and the config
+       with this one, but keeps it's own adapter list. Requests, that run
+       in the background, work on it, so they don't keep the adapter list
+       locked, while they wait for an adapter
+     */
+    fn snapshot(&self) -> TicketProvider {
+        let adapters = match self.adapters.lock() {
+            Ok(lock) => lock.clone(),
+            Err(err) => {
+                println!("Wasn't able to lock the adapter list due to {}", err);
+                vec![]
+            }
+        };
+
+        TicketProvider {
+            type_registry: HashMap::new(),
+            config: self.config.clone(),
+            adapters: Arc::new(Mutex::new(adapters)),
+            events: self.events.clone(),
+        }
+    }
+
+    /**
+       Returns a receiver, that gets every event, the provider and it's
+       adapters publish from now on
+     */
+    pub fn subscribe(&self) -> Receiver<ProviderEvent> {
+        self.events.subscribe()
+    }
+
+    /**
+       Returns the capabilities of the adapter with the given name. An
+       adapter, that can't be found, is treated as read only
+     */
+    pub fn adapter_capabilities(&self, adapter_name: &str) -> AdapterCapabilities {
+        match self.adapters.lock() {
+            Ok(lock) => match lock.iter().find(|adapter| adapter.get_name() == adapter_name) {
+                Some(adapter) => adapter.get_capabilities(),
+                None => AdapterCapabilities::read_only(),
+            },
+            Err(_) => AdapterCapabilities::read_only(),
+        }
+    }
+
+    /**
+       Returns the capabilities of all adapters by their name
+     */
+    pub fn list_adapter_capabilities(&self) -> BTreeMap<String, AdapterCapabilities> {
+        let mut capabilities: BTreeMap<String, AdapterCapabilities> = BTreeMap::new();
+
+        if let Ok(lock) = self.adapters.lock() {
+            for adapter in lock.iter() {
+                capabilities.insert(adapter.get_name(), adapter.get_capabilities());
+            }
+        }
+
+        capabilities
+    }
+
+    /**
+       Returns the status of the adapter with the given name, or None,
+       if there is no such adapter
+     */
+    pub fn adapter_status(&self, adapter_name: &str) -> Option<AdapterStatus> {
+        match self.adapters.lock() {
+            Ok(lock) => lock.iter()
+                .find(|adapter| adapter.get_name() == adapter_name)
+                .map(|adapter| adapter.get_status()),
+            Err(_) => None,
+        }
+    }
+
+    /**
+       Returns the status of all adapters by their name
+     */
+    pub fn list_adapter_status(&self) -> BTreeMap<String, AdapterStatus> {
+        let mut status: BTreeMap<String, AdapterStatus> = BTreeMap::new();
+
+        if let Ok(lock) = self.adapters.lock() {
+            for adapter in lock.iter() {
+                status.insert(adapter.get_name(), adapter.get_status());
+            }
+        }
+
+        status
+    }
+
+    /**
+       Lets the adapter with the given name try to reconnect on a background
+       thread. If the retry fails right away, an AdapterFailed event is
+       published, otherwise the adapter publishes, once it has loaded.
+       Returns false, if there is no such adapter
+     */
+    pub fn adapter_retry(&self, adapter_name: &str) -> bool {
+        let adapter = match self.adapters.lock() {
+            Ok(lock) => lock.iter().find(|adapter| adapter.get_name() == adapter_name).cloned(),
+            Err(err) => {
+                println!("Wasn't able to lock the adapter list due to {}", err);
+                None
+            }
+        };
+
+        let Some(adapter) = adapter else {
+            return false;
+        };
+
+        let events = self.events.clone();
+        thread::spawn(move || {
+            if let Err(err) = adapter.retry() {
+                events.publish(ProviderEvent::AdapterFailed(adapter.get_name(), err));
+            }
+        });
+
+        true
+    }
+
+    /**
+       Fails with an Unsupported error, if the adapter can't do what
+       the capability describes
+     */
+    fn require_capability(adapter: &SyncedTicketAdapter, capability: AdapterCapability) -> Result<(), AdapterError> {
+        match adapter.get_capabilities().supports(capability) {
+            true => Ok(()),
+            false => Err(AdapterError::new(AdapterErrorType::Unsupported(capability))),
+        }
+    }
+
     pub fn has_adapters(&self) -> bool {
         match self.adapters.lock() {
             Ok(lock) => !lock.is_empty(),
@@ -158,6 +336,8 @@ impl TicketProvider {
 
                 if let Some(index) = found_index {
                     lock.remove(index);
+                    self.events.publish(ProviderEvent::AdapterRemoved(adapter_name.clone()));
+
                     if drop_from_config {
                         match self.config.lock() {
                             Ok(mut lock) => {
@@ -204,7 +384,7 @@ impl TicketProvider {
        If it was successfull and write_to_app_config is true, then it will write
        the supplied config into the app config
      */
-    pub fn adapter_from_config(&self, config: &Config, write_to_app_config: bool, finished: Arc<Mutex<bool>>) -> Result<(), AdapterError> {
+    pub fn adapter_from_config(&self, config: &Config, write_to_app_config: bool) -> Result<(), AdapterError> {
         let type_name = match config.get("type") {
             Some(option) => match option.get::<String>() {
                 Some(type_name) => type_name,
@@ -229,7 +409,7 @@ impl TicketProvider {
             },
         };
 
-        match constructor(self.config.clone(), config, finished) {
+        match constructor(self.config.clone(), config, self.events.clone()) {
             Ok(adapter) => {
 
                 let mut adapters = match self.adapters.lock() {
@@ -241,6 +421,7 @@ impl TicketProvider {
                 };
                 let adapter_name = adapter.get_name();
                 adapters.push(Arc::new(adapter));
+                self.events.publish(ProviderEvent::AdapterAdded(adapter_name.clone()));
 
                 if write_to_app_config {
                     match self.config.lock() {
@@ -268,6 +449,8 @@ impl TicketProvider {
             },
             Err(err) => {
                 println!("Found Adapter type \"{type_name}\", but there was a problem instantiating it. Maybe a broken Config?");
+                let adapter_name = config.get("name").and_then(|option| option.get::<String>()).unwrap_or(type_name);
+                self.events.publish(ProviderEvent::AdapterFailed(adapter_name, err.clone()));
                 Err(err)
             },
         }
@@ -278,7 +461,7 @@ impl TicketProvider {
        Clears the currently installed Adapters and reloads them from the 
        config, that has been supplied on instantiation of the Ticket provider.
      */
-    pub fn adapters_from_app_config(&self, finished: Arc<Mutex<bool>>) {
+    pub fn adapters_from_app_config(&self) {
 
         match self.adapters.lock() {
             Ok(mut lock) => lock.clear(),
@@ -313,7 +496,7 @@ impl TicketProvider {
 
             let adapter_config = config.get_sub_config(["adapters", adapter_name.as_str()].join(":").as_str());
 
-            match self.adapter_from_config(&adapter_config, false, finished.clone()) {
+            match self.adapter_from_config(&adapter_config, false) {
                 Ok(_) => (),
                 Err(err) => println!("Failed creating {adapter_name}. Reason: {err}"),
             };
@@ -327,6 +510,7 @@ impl TicketProvider {
     pub fn add_adapter(&mut self, adapter: Arc<Box<dyn TicketAdapter + Sync + Send>>) {
         match self.adapters.lock() {
             Ok(mut lock) => {
+                self.events.publish(ProviderEvent::AdapterAdded(adapter.get_name()));
                 lock.push(adapter);
             },
             Err(err) => println!("Wasn't able to add the \"{}\" adapter to the System due to {}", adapter.get_name(), err)
@@ -440,11 +624,12 @@ impl TicketProvider {
             Ok(lock) => {
                 for adapter in lock.iter() {
                     if adapter.get_name() == bucket_adapter {
-                        return adapter.bucket_write(bucket);
+                        TicketProvider::require_capability(adapter, AdapterCapability::Buckets)?;
+                        return adapter.bucket_write(bucket).map(|_| self.events.publish(ProviderEvent::BucketChanged(bucket.identifier.clone())));
                     }
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::BucketWrite))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::BucketWrite).with_reason(err))
         }
 
         Ok(())
@@ -492,7 +677,7 @@ impl TicketProvider {
                     validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::Access))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
         };
 
         if validation_errors.is_empty() {
@@ -550,6 +735,17 @@ impl TicketProvider {
                             validation_errors.push(("state".to_string(), format!("The State \"{state}\" does not exist in this adapter.")));
                         }
 
+                        let custom_fields = adapter.custom_field_list_all();
+                        for (name, value) in &ticket.custom_fields {
+                            match custom_fields.iter().find(|field| &field.name == name) {
+                                Some(field) => if !field.field_type.is_valid_value(value) {
+                                    let type_name = field.field_type.get_type_name();
+                                    validation_errors.push((name.clone(), format!("The value \"{value}\" is not a valid {type_name}.")));
+                                },
+                                None => validation_errors.push((name.clone(), format!("The Custom Field \"{name}\" does not exist in this adapter."))),
+                            }
+                        }
+
                         break;
                     }
                 };
@@ -559,7 +755,7 @@ impl TicketProvider {
                     validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::Access))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
         };
 
         if validation_errors.is_empty() {
@@ -598,7 +794,7 @@ impl TicketProvider {
                     validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::Access))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
         };
 
         if validation_errors.is_empty() {
@@ -638,7 +834,7 @@ impl TicketProvider {
                     validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::Access))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
         };
 
         if validation_errors.is_empty() {
@@ -671,22 +867,48 @@ impl TicketProvider {
        Lists a singular Ticket. Not recommended to be used in Loops, it exists to get
        the most up to date version of a ticket, to make changes on.
      */
-    pub fn ticket_list_unique(&self, id: i64, adapter_name: &String) -> Option<Ticket> {
-        let mut ticket: Option<Ticket> = None;
+    pub fn ticket_list_unique(&self, identifier: &TicketIdentifier) -> Option<Ticket> {
+        // The adapter list isn't kept locked, while the adapter loads the ticket
+        match self.adapter_ref(&identifier.adapter, AdapterErrorType::Access) {
+            Ok(Some(adapter)) => block_on(adapter.ticket_list_unique_async(identifier.id)),
+            Ok(None) => None,
+            Err(err) => {
+                println!("Wasn't able to list tickets from adapters due to {}", err);
+                None
+            }
+        }
+    }
+
+    /**
+       Same as ticket_list_unique, but the ticket is loaded in the background.
+       Poll the returned request to receive the ticket, once it arrived
+     */
+    pub fn ticket_list_unique_async(&self, identifier: &TicketIdentifier) -> AdapterRequest<Option<Ticket>> {
+        let provider = self.snapshot();
+        let identifier = identifier.clone();
+
+        AdapterRequest::dispatch(move || provider.ticket_list_unique(&identifier))
+    }
+
+    /**
+       Lists the change history of a singular Ticket from it's corresponding
+       Adapter. Returns an empty vector, if the adapter doesn't keep a history.
+     */
+    pub fn ticket_history(&self, identifier: &TicketIdentifier) -> Vec<HistoryEntry> {
+        let mut history: Vec<HistoryEntry> = vec![];
 
         match self.adapters.lock() {
             Ok(lock) => {
                 for adapter in lock.iter() {
-                    if adapter.get_name().eq(adapter_name) {
-
-                        ticket = adapter.ticket_list_unique(id);
+                    if adapter.get_name().eq(&identifier.adapter) {
+                        history.append(&mut adapter.ticket_history(identifier.id));
                     }
                 }
             },
-            Err(err) => println!("Wasn't able to list tickets from adapters due to {}", err)
+            Err(err) => println!("Wasn't able to list ticket history from adapters due to {}", err)
         };
 
-        ticket
+        history
     }
 
     /**
@@ -751,7 +973,7 @@ impl TicketProvider {
                     expression = leftover_expression.trim();
                     Ok(cleaned_expression)
                 },
-                None => Err(AdapterError{ error_type: AdapterErrorType::Expression("Expression doesn't end with ]]".to_string()) })
+                None => Err(AdapterError::new(AdapterErrorType::Expression("Expression doesn't end with ]]".to_string())))
             };
 
             // If successfully found content between brackets, isolate parameters,
@@ -765,7 +987,7 @@ impl TicketProvider {
                             expression: String::from(adapter_op.split_at(1).1.trim()) //remove the :
                         })
                     },
-                    None => Err(AdapterError{ error_type: AdapterErrorType::Expression("Expression doesn't follow adapter:expression between the square brackets".to_string()) })
+                    None => Err(AdapterError::new(AdapterErrorType::Expression("Expression doesn't follow adapter:expression between the square brackets".to_string())))
                 };
 
                 if let Ok(isolated_expr) = filter_expression {
@@ -785,12 +1007,124 @@ impl TicketProvider {
         // either nothing or a wrongly formatted expression in the filter string
         if found_expressions.is_empty() {
             println!("Expression needs start with [[ ! Is the expression empty?");
-            return Err(AdapterError{ error_type: AdapterErrorType::Expression("Expression needs start with [[ ! Is the expression empty?".to_string()) });
+            return Err(AdapterError::new(AdapterErrorType::Expression("Expression needs start with [[ ! Is the expression empty?".to_string())));
         }
 
         Ok(found_expressions)
     }
 
+    /**
+       Returns the order, a filter defines with a trailing ```sort_by(...)```
+       after all of it's expressions, like ```[[ad1: expr1]] sort_by(due asc, title)```.
+       Returns None, if the filter doesn't define an order
+     */
+    pub fn filter_sort(&self, filter: &Filter) -> Result<Option<TicketSort>, AdapterError> {
+        let trailing = TicketProvider::split_filter_sort(&filter.operation).1.trim();
+
+        if trailing.is_empty() {
+            return Ok(None);
+        }
+
+        match trailing.strip_prefix("sort_by(").and_then(|keys| keys.strip_suffix(')')) {
+            Some(keys) => keys.parse::<TicketSort>().map(Some),
+            None => Err(AdapterError::new(AdapterErrorType::Expression(format!("Expected sort_by(...) after the last ]], but found \"{}\"", trailing)))),
+        }
+    }
+
+
+    /**
+       Splits the operation of a filter into it's expressions and the text
+       after them, which may only be a sort_by(...). Without any brackets,
+       the sort_by(...) is looked up at the end of the adapter neutral query
+     */
+    fn split_filter_sort(operation: &str) -> (&str, &str) {
+        let found_pos = match operation.rfind("]]") {
+            Some(found_pos) => Some(found_pos + 2),
+            None => operation.rfind("sort_by("),
+        };
+
+        match found_pos {
+            Some(found_pos) => operation.split_at(found_pos),
+            None => (operation, ""),
+        }
+    }
+
+    /**
+       Returns the adapter neutral query of a filter, that is written
+       without any brackets, like ```with_state(open) with_tag(bug)```.
+       Returns None, if the filter consists of ```[[adapter: expression]]```
+       blocks instead, and an error, if the query can't be read
+     */
+    pub fn filter_query(&self, filter: &Filter) -> Result<Option<FilterQuery>, AdapterError> {
+        if filter.operation.trim_start().starts_with("[[") {
+            return Ok(None);
+        }
+
+        TicketProvider::split_filter_sort(&filter.operation).0.parse::<FilterQuery>().map(Some)
+    }
+
+    /**
+       Lists the Tickets of all adapters, that match the adapter neutral
+       query. Every adapter answers it on it's own, either by compiling it
+       into it's own query language or by testing each of it's tickets
+     */
+    pub fn ticket_list_query(&self, query: &FilterQuery) -> Result<Vec<Ticket>, AdapterError> {
+        let mut tickets: Vec<Ticket> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    match adapter.ticket_list_query(query) {
+                        Ok(mut new_tickets) => tickets.append(&mut new_tickets),
+                        Err(err) => {
+                            println!("Adapter \"{}\" failed to evaluate query!", adapter.get_name());
+                            return Err(err);
+                        }
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err)),
+        }
+
+        Ok(tickets)
+    }
+
+    /**
+       Searches the titles and descriptions of the Tickets of all adapters
+       and returns the hits, the best one first. All adapters score their
+       hits on the same scale, so the hits are interleaved by how well they
+       fit. An adapter, that fails to search, is skipped, only if all of
+       them fail, the error is returned
+     */
+    pub fn ticket_search(&self, text: &str) -> Result<Vec<SearchHit>, AdapterError> {
+        let mut hits: Vec<SearchHit> = vec![];
+
+        if SearchHit::words(text).is_empty() {
+            return Ok(hits);
+        }
+
+        let adapters = self.list_adapter_refs();
+        let mut errors: Vec<AdapterError> = vec![];
+
+        for adapter in adapters.iter() {
+            match adapter.ticket_search(text) {
+                Ok(mut new_hits) => hits.append(&mut new_hits),
+                Err(err) => {
+                    println!("Adapter \"{}\" failed to search due to {}", adapter.get_name(), err);
+                    errors.push(err);
+                }
+            }
+        }
+
+        if errors.len() == adapters.len() {
+            if let Some(err) = errors.pop() {
+                return Err(err);
+            }
+        }
+
+        SearchHit::sort(&mut hits);
+        Ok(hits)
+    }
 
     /**
        Lists all Tickets, that satisfy expressions specific to the adapters
@@ -799,46 +1133,59 @@ impl TicketProvider {
        results from multiple adapters such as ```[[ad1: expr1]][[ad2: expr2]]...```
        the adapter in this case is the name of the adapter (not the fancy name)
        and the expression is specific to the adapter, so you have to look
-       it up on the adapters documentation.
+       it up on the adapters documentation. A filter without any brackets
+       is an adapter neutral query instead, that is sent to all adapters.
      */
     pub fn ticket_list(&self, filter: &Filter) -> Result<Vec<Ticket>, AdapterError> {
 
+        if let Some(query) = self.filter_query(filter)? {
+            let mut tickets = self.ticket_list_query(&query)?;
+
+            if let Some(sort) = self.filter_sort(filter)? {
+                sort.sort(&mut tickets, &self.state_list_all());
+            }
+
+            return Ok(tickets);
+        }
+
         let found_expressions = match self.split_filter_expression(filter.operation.clone()) {
             Ok(expressions) => expressions,
             Err(err) => return Err(err),
         };
+
+        let sort = self.filter_sort(filter)?;
         
         let mut tickets: Vec<Ticket> = vec![];
 
         // Find adapters with corresponding name and execute operation on them.
-        // then collect their results into final list
-        let local_adapters = self.adapters.clone();
-        if let Ok(lock) = local_adapters.lock() {
-            
-            for found_expression in found_expressions {
-                let expression_adapter = found_expression.adapter.clone();
-                let found_adapter = lock.iter().by_ref().into_iter().find(move |adapter| adapter.get_name() == expression_adapter);
-                if let Some(adapter) = found_adapter {
-                    if let Err(error) = match adapter.ticket_list(&found_expression.expression) {
-                        Ok(mut new_tickets) => {
-                            tickets.append(&mut new_tickets);
-                            Ok(())
-                        },
-                        Err(err) => Err(err),
-                    } {
-                        println!("Adapter \"{}\" failed to evaluate expression!", found_expression.adapter);
-                        return Err(error);
-                    }
-
-                } else {
-                    println!("Adapter \"{}\" has not been found in Adapterlist!", found_expression.adapter);
-                    return Err(AdapterError{ error_type: AdapterErrorType::Expression(format!("Adapter \"{}\" has not been found in Adapterlist!", found_expression.adapter)) });
+        // then collect their results into final list. The adapter list isn't
+        // kept locked, while the adapters are waiting for their results
+        let adapters = self.list_adapter_refs();
+
+        for found_expression in found_expressions {
+            let expression_adapter = found_expression.adapter.clone();
+            let found_adapter = adapters.iter().find(move |adapter| adapter.get_name() == expression_adapter);
+            if let Some(adapter) = found_adapter {
+                if let Err(error) = match block_on(adapter.ticket_list_async(&found_expression.expression)) {
+                    Ok(mut new_tickets) => {
+                        tickets.append(&mut new_tickets);
+                        Ok(())
+                    },
+                    Err(err) => Err(err),
+                } {
+                    println!("Adapter \"{}\" failed to evaluate expression!", found_expression.adapter);
+                    return Err(error);
                 }
+
+            } else {
+                println!("Adapter \"{}\" has not been found in Adapterlist!", found_expression.adapter);
+                return Err(AdapterError::new(AdapterErrorType::Expression(format!("Adapter \"{}\" has not been found in Adapterlist!", found_expression.adapter))));
             }
+        }
 
-        } else {
-            println!("Wasn't able to Lock Adapterlist");
-            return Err(AdapterError{ error_type: AdapterErrorType::TicketWrite });
+        // If the filter defines an order, the tickets of all adapters are sorted together
+        if let Some(sort) = sort {
+            sort.sort(&mut tickets, &self.state_list_all());
         }
 
         // If everything went fine, return the final ticket List
@@ -847,153 +1194,1090 @@ impl TicketProvider {
     }
 
     /**
-       Write a given Ticket to it's corresponding Adapter. Throws an 
-       Error, if the write failed. Other reasons depend on used adapters.
+       Lists a single page of all Tickets from all Adapters, together with
+       the total number of Tickets. The adapters are paged one after the
+       other, in the order they have been added. Doesn't fail.
      */
-    pub fn ticket_write(&self, ticket: &Ticket) -> Result<(), AdapterError> {
-        let ticket_adapter = ticket.adapter.clone();
-
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    if adapter.get_name() == ticket_adapter {
-                        return adapter.ticket_write(ticket);
-                    }
-                }
-            },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::TicketWrite))
-        }
+    pub fn ticket_list_all_page(&self, page: TicketPageRequest) -> TicketPage {
+        let adapters = self.list_adapter_refs();
 
-        Ok(())
+        TicketProvider::ticket_page_concat(page, adapters.len(), |index, request| {
+            Ok(adapters[index].ticket_list_all_page(request))
+        }).unwrap_or_else(|_| TicketPage::new(vec![], page, 0))
     }
 
     /**
-       Tries to delete a given Ticket from it's corresponding Adapter. Throws
-       an error, if the delete fails for whatever reason.
+       Same as ticket_list, but only returns a single page of the result,
+       together with the total number of Tickets, the filter matches.
+       If the filter spans multiple adapters, their results are paged
+       one after the other.
      */
-    pub fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError> {
-        let ticket_adapter = ticket.adapter.clone();
+    pub fn ticket_list_page(&self, filter: &Filter, page: TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        let query = self.filter_query(filter)?;
 
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    if adapter.get_name() == ticket_adapter {
-                        return adapter.ticket_drop(ticket);
-                    }
-                }
-            },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::TicketDelete))
+        // The adapter list isn't kept locked, while the adapters are loading their pages
+        let adapters = self.list_adapter_refs();
+
+        if let Some(query) = query {
+            return TicketProvider::ticket_page_concat(page, adapters.len(), |index, request| {
+                adapters[index].ticket_list_query_page(&query, request)
+            });
         }
 
-        Ok(())
+        let found_expressions = self.split_filter_expression(filter.operation.clone())?;
+
+        TicketProvider::ticket_page_concat(page, found_expressions.len(), |index, request| {
+            let found_expression = &found_expressions[index];
+
+            match adapters.iter().find(|adapter| adapter.get_name() == found_expression.adapter) {
+                Some(adapter) => block_on(adapter.ticket_list_page_async(&found_expression.expression, request)),
+                None => Err(AdapterError::new(AdapterErrorType::Expression(format!("Adapter \"{}\" has not been found in Adapterlist!", found_expression.adapter)))),
+            }
+        })
     }
 
     /**
-       Lists all states from all Adapters. Returns a vector of all
-       found states. Doesn't fail.
+       Same as ticket_list_page, but the page is loaded in the background.
+       Poll the returned request to receive the page, once it arrived
      */
-    pub fn state_list_all(&self) -> Vec<State> {
-        let mut states: Vec<State> = vec![];
+    pub fn ticket_list_page_async(&self, filter: &Filter, page: TicketPageRequest) -> AdapterRequest<Result<TicketPage, AdapterError>> {
+        let provider = self.snapshot();
+        let filter = filter.clone();
 
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    states.append(&mut adapter.state_list_all());
-                }
-            },
-            Err(err) => println!("Wasn't able to list states from adapters due to {}", err)
-        };
+        AdapterRequest::dispatch(move || provider.ticket_list_page(&filter, page))
+    }
 
-        states
+    /**
+       Streams the Tickets, the filter matches, page by page with the given
+       page size. Each page is only loaded, when the iterator reaches it.
+       The iteration stops after the last page or the first error
+     */
+    pub fn ticket_list_pages<'a>(&'a self, filter: &'a Filter, limit: u64) -> impl Iterator<Item = Result<TicketPage, AdapterError>> + 'a {
+        let mut next = Some(TicketPageRequest::first(limit));
+
+        std::iter::from_fn(move || {
+            let request = next.take()?;
+            let page = self.ticket_list_page(filter, request);
+            next = page.as_ref().ok().and_then(TicketPage::next_request);
+            Some(page)
+        })
     }
 
     /**
-       Write a state to it's corresponding Adapter. If the write goes wrong,
-       an Error gets thrown. Other reasons of an error can be found at the
-       corresponding adapter documentation
+       Answers a page request from multiple sources, that are paged one
+       after the other. Every source is asked for it's total, even if
+       the page is already full, so the total of the result is correct
      */
-    pub fn state_write(&self, state: &State) -> Result<(), AdapterError> {
-        let state_adapter = state.identifier.adapter.clone();
+    fn ticket_page_concat<F>(page: TicketPageRequest, sources: usize, mut list: F) -> Result<TicketPage, AdapterError>
+    where F: FnMut(usize, TicketPageRequest) -> Result<TicketPage, AdapterError> {
+        let mut tickets: Vec<Ticket> = vec![];
+        let mut total: u64 = 0;
 
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    if adapter.get_name() == state_adapter {
-                        return adapter.state_write(state);
-                    }
-                }
-            },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::StateWrite))
+        for index in 0..sources {
+            let request = TicketPageRequest::new(
+                page.offset.saturating_sub(total),
+                page.limit.saturating_sub(tickets.len() as u64));
+
+            let mut source_page = list(index, request)?;
+            total += source_page.total;
+            tickets.append(&mut source_page.tickets);
         }
 
-        Ok(())
+        Ok(TicketPage::new(tickets, page, total))
     }
 
     /**
-       Lists all available tags from all Adapters. Can't fail, gives an empty
-       vector instead.
+       Write a given Ticket to it's corresponding Adapter. Throws an 
+       Error, if the write failed. Other reasons depend on used adapters.
      */
-    pub fn tag_list_all(&self) -> Vec<Tag> {
-        let mut tags: Vec<Tag> = vec![];
-
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    tags.append(&mut adapter.tag_list_all());
-                }
-            },
-            Err(err) => println!("Wasn't able to list tags from adapters due to {}", err)
+    pub fn ticket_write(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        let Some(adapter) = self.adapter_ref(&ticket.adapter, AdapterErrorType::TicketWrite)? else {
+            return Ok(());
         };
 
-        tags
+        TicketProvider::require_capability(&adapter, AdapterCapability::TicketWrite)?;
+        let tickets = self.ticket_write_expanded(&adapter, ticket);
+        let results = adapter.ticket_write_many(&tickets);
+
+        self.publish_written(&tickets, &results);
+        results.into_iter().try_for_each(|result| result.map(|_| ()))
     }
 
     /**
-       Writes a given Tag to it's corresponding Adapter. If the write fails,
-       it throws an Error. Other reasons might be available on the Adapters
-       documentation
+       Writes multiple Tickets at once. The Tickets of each Adapter are written
+       together, so Adapters with transactions write either all of them or
+       none. Returns the result of each Ticket in the order they were given.
      */
-    pub fn tag_write(&self, tag: &Tag) -> Result<(), AdapterError> {
-        let tag_adapter = tag.adapter.clone();
-
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    if adapter.get_name() == tag_adapter {
-                        return adapter.tag_write(tag);
-                    }
+    pub fn ticket_write_many(&self, tickets: &[Ticket]) -> Vec<Result<(), AdapterError>> {
+        self.ticket_batch(tickets, AdapterCapability::TicketWrite, AdapterErrorType::TicketWrite, |adapter, tickets| {
+
+            // Remember, which given ticket each of the written tickets belongs to
+            let (owners, expanded): (Vec<usize>, Vec<Ticket>) = tickets.iter().enumerate()
+                .flat_map(|(index, ticket)| self.ticket_write_expanded(adapter, ticket).into_iter().map(move |expanded| (index, expanded)))
+                .unzip();
+
+            let expanded_results = adapter.ticket_write_many(&expanded);
+            self.publish_written(&expanded, &expanded_results);
+
+            // A given ticket fails, as soon as one of it's written tickets failed
+            let mut results: Vec<Result<(), AdapterError>> = vec![Ok(()); tickets.len()];
+            for (owner, result) in owners.into_iter().zip(expanded_results) {
+                if results[owner].is_ok() {
+                    results[owner] = result.map(|_| ());
                 }
-            },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::TagWrite))
-        }
+            }
 
-        Ok(())
+            results
+        })
     }
 
     /**
-       Tries to delete a given Tag from it's corresponding Adapter. Throws
-       an error, if the delete fails for whatever reason.
+       Deletes multiple Tickets at once. The Tickets of each Adapter are deleted
+       together, so Adapters with transactions delete either all of them or
+       none. Returns the result of each Ticket in the order they were given.
      */
-    pub fn tag_drop(&self, tag: &Tag) -> Result<(), AdapterError> {
-        let tag_adapter = tag.adapter.clone();
-
-        match self.adapters.lock() {
-            Ok(lock) => {
-                for adapter in lock.iter() {
-                    if adapter.get_name() == tag_adapter {
-                        return adapter.tag_drop(tag);
-                    }
-                }
-            },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::TagDelete))
-        }
-
-        Ok(())
+    pub fn ticket_drop_many(&self, tickets: &[Ticket]) -> Vec<Result<(), AdapterError>> {
+        self.ticket_batch(tickets, AdapterCapability::TicketDelete, AdapterErrorType::TicketDelete, |adapter, tickets| {
+            let results = adapter.ticket_drop_many(tickets);
+            self.publish_dropped(tickets, &results);
+            results
+        })
     }
 
     /**
-       Tries to delete a given Bucket from it's corresponding Adapter. Throws
-       an error, if the delete fails for whatever reason.
+       Publishes the successfully written tickets, like the adapter returned
+       them. Tickets, that were given without an id, have been created, the
+       other ones updated
+     */
+    fn publish_written(&self, tickets: &[Ticket], results: &[Result<Ticket, AdapterError>]) {
+        for (ticket, result) in tickets.iter().zip(results) {
+            if let Ok(written) = result {
+                self.events.publish(match ticket.id {
+                    0 => ProviderEvent::TicketCreated(written.clone()),
+                    _ => ProviderEvent::TicketUpdated(written.clone()),
+                });
+            }
+        }
+    }
+
+    fn publish_dropped(&self, tickets: &[Ticket], results: &[Result<(), AdapterError>]) {
+        for (ticket, _) in tickets.iter().zip(results).filter(|(_, result)| result.is_ok()) {
+            self.events.publish(ProviderEvent::TicketDeleted(ticket.clone()));
+        }
+    }
+
+    /**
+       Returns the adapter with the given name, or None if there is no such
+       adapter. The adapter list isn't kept locked afterwards, so the adapter
+       can take it's time and events can be published safely
+     */
+    fn adapter_ref(&self, adapter_name: &str, error_type: AdapterErrorType) -> Result<Option<Arc<SyncedTicketAdapter>>, AdapterError> {
+        match self.adapters.lock() {
+            Ok(lock) => Ok(lock.iter().find(|adapter| adapter.get_name() == adapter_name).cloned()),
+            Err(err) => Err(AdapterError::new(error_type).with_reason(err)),
+        }
+    }
+
+    /**
+       Groups the tickets by their adapter and runs the operation once per
+       adapter. The operation returns the result of each ticket of it's
+       group, which are put back into the order, the tickets were given
+     */
+    fn ticket_batch<F>(&self, tickets: &[Ticket], capability: AdapterCapability, error_type: AdapterErrorType, operation: F) -> Vec<Result<(), AdapterError>>
+    where F: Fn(&SyncedTicketAdapter, &[Ticket]) -> Vec<Result<(), AdapterError>> {
+        let missing = AdapterError::new(error_type.clone()).with_reason("The adapter didn't report a result for this ticket");
+        let mut results: Vec<Result<(), AdapterError>> = vec![Err(missing); tickets.len()];
+
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (index, ticket) in tickets.iter().enumerate() {
+            groups.entry(ticket.adapter.clone()).or_default().push(index);
+        }
+
+        // The adapters are only looked up, so the list isn't locked while they work
+        let adapters = match self.adapters.lock() {
+            Ok(lock) => lock.clone(),
+            Err(err) => return vec![Err(AdapterError::new(error_type).with_reason(err)); tickets.len()],
+        };
+
+        for (adapter_name, indices) in groups {
+            let group: Vec<Ticket> = indices.iter().map(|index| tickets[*index].clone()).collect();
+
+            let group_results = match adapters.iter().find(|adapter| adapter.get_name() == adapter_name) {
+                Some(adapter) => match TicketProvider::require_capability(adapter, capability) {
+                    Ok(_) => operation(adapter, &group),
+                    Err(err) => vec![Err(err); group.len()],
+                },
+                None => vec![Err(AdapterError::new(error_type.clone())
+                    .with_reason(format!("Adapter \"{}\" has not been found in Adapterlist!", adapter_name))); group.len()],
+            };
+
+            for (index, result) in indices.into_iter().zip(group_results) {
+                results[index] = result;
+            }
+        }
+
+        results
+    }
+
+    /**
+       Returns the tickets, that have to be written for the given ticket. A
+       recurring ticket, that just got finished, hands over to it's next
+       instance, otherwise it's just the ticket itself
+     */
+    fn ticket_write_expanded(&self, adapter: &SyncedTicketAdapter, ticket: &Ticket) -> Vec<Ticket> {
+        if ticket.recurrence.is_some() && ticket.id != 0 {
+            let done_state = self.recurrence_done_state(&ticket.adapter);
+
+            if ticket.state_name == done_state {
+                if let Some(previous) = adapter.ticket_list_unique(ticket.id) {
+                    if previous.state_name != done_state {
+                        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+                        let next_ticket = TicketProvider::recurrence_next_ticket(ticket, &previous.state_name, now);
+                        return TicketProvider::recurrence_tickets(ticket, next_ticket);
+                    }
+                }
+            }
+        }
+
+        vec![ticket.clone()]
+    }
+
+    /**
+       Returns the state, that finishes recurring tickets of the given
+       adapter. It can be configured with the done_state option of the
+       adapter, and is "done" by default
+     */
+    fn recurrence_done_state(&self, adapter_name: &str) -> String {
+        match self.config.lock() {
+            Ok(lock) => match lock.get(["adapters", adapter_name, "done_state"].join(":").as_str()) {
+                Some(option) => option.get::<String>().unwrap_or("done".to_string()),
+                None => "done".to_string(),
+            },
+            Err(_) => "done".to_string(),
+        }
+    }
+
+    /**
+       Creates the next instance of a recurring ticket in the given state,
+       which takes over the recurrence. Occurrences, that are already due,
+       have been created by the catch up, so the instance is the first one,
+       that is due after the given timestamp in milliseconds
+     */
+    fn recurrence_next_ticket(ticket: &Ticket, state_name: &str, now: i64) -> Option<Ticket> {
+        let mut next = ticket.occurrence_after(now)?;
+        next.state_name = state_name.to_string();
+        Some(next)
+    }
+
+    /**
+       Returns a recurring ticket without it's recurrence, followed by
+       it's next instance, that recurs from now on
+     */
+    fn recurrence_tickets(ticket: &Ticket, next_ticket: Option<Ticket>) -> Vec<Ticket> {
+        let Some(next_ticket) = next_ticket else {
+            return vec![ticket.clone()];
+        };
+
+        let mut finished_ticket = ticket.clone();
+        finished_ticket.recurrence = None;
+
+        vec![finished_ticket, next_ticket]
+    }
+
+    /**
+       Creates an instance of a recurring ticket for every occurrence, that
+       fell due after the first and until the second timestamp in
+       milliseconds. The instances don't recur, the open ticket keeps it's
+       recurrence, so it still hands over, once it's finished
+     */
+    fn recurrence_missed_tickets(ticket: &Ticket, from: i64, until: i64) -> Vec<Ticket> {
+        let mut missed_tickets: Vec<Ticket> = vec![];
+        let mut current = ticket.occurrence_after(from);
+
+        while let Some(next) = current {
+            if next.due_at > until || missed_tickets.len() >= MAX_RECURRENCE_CATCH_UP {
+                break;
+            }
+
+            current = next.next_occurrence();
+
+            let mut missed = next;
+            missed.recurrence = None;
+            missed_tickets.push(missed);
+        }
+
+        missed_tickets
+    }
+
+    /**
+       Returns the time of the last check for missed occurrences in
+       milliseconds and remembers the given time as the new one. It's kept
+       in the config, so occurrences, that fell due while the app was
+       closed, are caught up as well. Without an earlier check, nothing
+       has been missed yet
+     */
+    fn recurrence_last_check(&self, now: i64) -> i64 {
+        match self.config.lock() {
+            Ok(mut lock) => {
+                let last_check = lock.get(RECURRENCE_LAST_CHECK)
+                    .and_then(|option| option.get::<i64>())
+                    .unwrap_or(now);
+
+                lock.put(RECURRENCE_LAST_CHECK, now, "");
+                last_check.min(now)
+            },
+            Err(err) => {
+                println!("Wasn't able to lock Config due to {err}");
+                now
+            },
+        }
+    }
+
+    /**
+       Creates the instances of recurring tickets, whose occurrences fell
+       due since the last check. Finished tickets are handed over, when they
+       are written, so only open tickets are looked at. Instances, that
+       already exist, aren't created again. Returns the number of created
+       tickets
+     */
+    pub fn recurrence_catch_up(&self) -> usize {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+        let last_check = self.recurrence_last_check(now);
+        let mut created = 0;
+
+        if last_check >= now {
+            return created;
+        }
+
+        // The adapters are looked up first, so the list isn't locked while the tickets are written
+        for adapter in self.list_adapter_refs() {
+            if !adapter.get_capabilities().supports(AdapterCapability::TicketWrite) {
+                continue;
+            }
+
+            let done_state = self.recurrence_done_state(&adapter.get_name());
+            let all_tickets = adapter.ticket_list_all();
+
+            let recurring_tickets = all_tickets.iter()
+                .filter(|ticket| ticket.recurrence.is_some() && ticket.state_name != done_state);
+
+            for ticket in recurring_tickets {
+                let missed_tickets: Vec<Ticket> = TicketProvider::recurrence_missed_tickets(ticket, last_check, now).into_iter()
+                    .filter(|missed| !all_tickets.iter().any(|existing| existing.title == missed.title && existing.due_at == missed.due_at))
+                    .collect();
+
+                if missed_tickets.is_empty() {
+                    continue;
+                }
+
+                let results = adapter.ticket_write_many(&missed_tickets);
+                self.publish_written(&missed_tickets, &results);
+
+                for result in results {
+                    match result {
+                        Ok(_) => created += 1,
+                        Err(err) => println!("Wasn't able to catch up on the recurring Ticket {} due to {err}", ticket.id),
+                    }
+                }
+            }
+        }
+
+        created
+    }
+
+    /**
+       Starts a background thread, that regularly catches up on recurring
+       tickets, including the ones missed while the app was closed. Created
+       tickets are published as events
+     */
+    pub fn start_recurrence_check(ticket_provider: Arc<Mutex<TicketProvider>>) -> JoinHandle<()> {
+        thread::spawn(move || loop {
+
+            // The provider is only locked to take a snapshot, so the catch up
+            // doesn't block everyone else, while it's waiting on the adapters
+            let snapshot = match ticket_provider.lock() {
+                Ok(lock) => lock.snapshot(),
+                Err(_) => return,
+            };
+
+            snapshot.recurrence_catch_up();
+            thread::sleep(RECURRENCE_CHECK_INTERVAL);
+        })
+    }
+
+    /**
+       Tries to delete a given Ticket from it's corresponding Adapter. Throws
+       an error, if the delete fails for whatever reason.
+     */
+    pub fn ticket_drop(&self, ticket: &Ticket) -> Result<(), AdapterError> {
+        let Some(adapter) = self.adapter_ref(&ticket.adapter, AdapterErrorType::TicketDelete)? else {
+            return Ok(());
+        };
+
+        TicketProvider::require_capability(&adapter, AdapterCapability::TicketDelete)?;
+        let result = adapter.ticket_drop(ticket);
+
+        self.publish_dropped(std::slice::from_ref(ticket), std::slice::from_ref(&result));
+        result
+    }
+
+    /**
+       Lists all comments of a given Ticket from it's corresponding Adapter.
+       Returns an empty vector, if the adapter wasn't found or the ticket
+       has no comments. Doesn't fail.
+     */
+    pub fn comment_list(&self, ticket: &Ticket) -> Vec<Comment> {
+        match self.adapter_ref(&ticket.adapter, AdapterErrorType::Access) {
+            Ok(Some(adapter)) => block_on(adapter.comment_list_async(ticket)),
+            Ok(None) => vec![],
+            Err(err) => {
+                println!("Wasn't able to list comments from adapters due to {}", err);
+                vec![]
+            }
+        }
+    }
+
+    /**
+       Same as comment_list, but the comments are loaded in the background.
+       Poll the returned request to receive the comments, once they arrived
+     */
+    pub fn comment_list_async(&self, ticket: &Ticket) -> AdapterRequest<Vec<Comment>> {
+        let provider = self.snapshot();
+        let ticket = ticket.clone();
+
+        AdapterRequest::dispatch(move || provider.comment_list(&ticket))
+    }
+
+    /**
+       Checks, if the comment can be written and is guaranteed
+       to keep integrity with it's attributes
+     */
+    pub fn comment_validate(&self, comment: &Comment) -> Result<(), AdapterError> {
+        let mut validation_errors: Vec<(String, String)> = Vec::default();
+
+        if comment.body.trim().is_empty() {
+            validation_errors.push(("body".to_string(), "The Comment is not supposed to be empty!".to_string()));
+        };
+
+        if comment.author.trim().is_empty() {
+            validation_errors.push(("author".to_string(), "The Author of the Comment is not supposed to be empty!".to_string()));
+        };
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                let mut adapter_matched = false;
+
+                for adapter in lock.iter() {
+                    if adapter.get_name() == comment.adapter {
+                        adapter_matched = true;
+
+                        if adapter.ticket_list_unique(comment.ticket_id).is_none() {
+                            let ticket = comment.ticket_id;
+                            validation_errors.push(("ticket".to_string(), format!("The Ticket with id {ticket} does not exist in this adapter.")));
+                        }
+
+                        break;
+                    };
+                };
+
+                if !adapter_matched {
+                    let adapter = &comment.adapter;
+                    validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
+        };
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdapterError::new(AdapterErrorType::Validate(validation_errors, "Comment".to_string())))
+        }
+    }
+
+    /**
+       Write a given Comment to it's corresponding Adapter. Throws an 
+       Error, if the write failed. Other reasons depend on used adapters.
+     */
+    pub fn comment_write(&self, comment: &Comment) -> Result<(), AdapterError> {
+        let comment_adapter = comment.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == comment_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Comments)?;
+                        return adapter.comment_write(comment);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::CommentWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Comment from it's corresponding Adapter. Throws
+       an error, if the delete fails for whatever reason.
+     */
+    pub fn comment_drop(&self, comment: &Comment) -> Result<(), AdapterError> {
+        let comment_adapter = comment.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == comment_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Comments)?;
+                        return adapter.comment_drop(comment);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::CommentDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Lists all links of a ticket from all Adapters, since a link to a
+       ticket might be stored in the adapter of the other ticket. Doesn't fail.
+     */
+    pub fn link_list(&self, ticket: &Ticket) -> Vec<TicketLink> {
+        let mut links: Vec<TicketLink> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    links.append(&mut adapter.link_list(ticket));
+                }
+            },
+            Err(err) => println!("Wasn't able to list links from adapters due to {}", err)
+        };
+
+        links
+    }
+
+    /**
+       Checks, if the link can be inserted as new. Both linked tickets
+       have to exist, and a ticket can't be linked to itself.
+     */
+    pub fn link_validate(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        let mut validation_errors: Vec<(String, String)> = Vec::default();
+
+        if link.source == link.target {
+            validation_errors.push(("target".to_string(), "A Ticket can't be linked to itself!".to_string()));
+        };
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                let mut source_matched = false;
+                let mut target_matched = false;
+
+                for adapter in lock.iter() {
+                    if adapter.get_name() == link.source.adapter {
+                        source_matched = adapter.ticket_list_unique(link.source.id).is_some();
+                    };
+
+                    if adapter.get_name() == link.target.adapter {
+                        target_matched = adapter.ticket_list_unique(link.target.id).is_some();
+                    };
+                };
+
+                if !source_matched {
+                    let source = &link.source;
+                    validation_errors.push(("source".to_string(), format!("The Ticket {source} does not exist.")));
+                }
+
+                if !target_matched {
+                    let target = &link.target;
+                    validation_errors.push(("target".to_string(), format!("The Ticket {target} does not exist.")));
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
+        };
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdapterError::new(AdapterErrorType::Validate(validation_errors, "Link".to_string())))
+        }
+    }
+
+    /**
+       Writes a given Link to the Adapter of it's source ticket. Throws an
+       Error, if the write failed. Other reasons depend on used adapters.
+     */
+    pub fn link_write(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        let link_adapter = link.source.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == link_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Links)?;
+                        return adapter.link_write(link);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::LinkWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Link from the Adapter of it's source ticket.
+       Throws an error, if the delete fails for whatever reason.
+     */
+    pub fn link_drop(&self, link: &TicketLink) -> Result<(), AdapterError> {
+        let link_adapter = link.source.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == link_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Links)?;
+                        return adapter.link_drop(link);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::LinkDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Lists the attachments of a ticket from it's corresponding Adapter.
+       Doesn't fail, but returns an empty vector instead.
+     */
+    pub fn attachment_list(&self, ticket: &Ticket) -> Vec<Attachment> {
+        let mut attachments: Vec<Attachment> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == ticket.adapter {
+                        attachments.append(&mut adapter.attachment_list(ticket));
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list attachments from adapters due to {}", err)
+        };
+
+        attachments
+    }
+
+    /**
+       Checks, if the attachment can be added to it's ticket. The name
+       must not be empty and the contents must not exceed the size limit.
+     */
+    pub fn attachment_validate(&self, attachment: &Attachment, data: &[u8]) -> Result<(), AdapterError> {
+        let mut validation_errors: Vec<(String, String)> = Vec::default();
+
+        if attachment.name.trim().is_empty() {
+            validation_errors.push(("name".to_string(), "The Name of the Attachment is not supposed to be empty!".to_string()));
+        };
+
+        if data.len() > MAX_ATTACHMENT_SIZE {
+            let limit = MAX_ATTACHMENT_SIZE / (1024 * 1024);
+            validation_errors.push(("data".to_string(), format!("The Attachment is larger than {limit} MiB!")));
+        };
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdapterError::new(AdapterErrorType::Validate(validation_errors, "Attachment".to_string())))
+        }
+    }
+
+    /**
+       Adds an attachment with the given contents to it's corresponding
+       Adapter. Throws an Error, if the write failed.
+     */
+    pub fn attachment_add(&self, attachment: &Attachment, data: &[u8]) -> Result<(), AdapterError> {
+        let attachment_adapter = attachment.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == attachment_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Attachments)?;
+                        return adapter.attachment_add(attachment, data);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::AttachmentWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Attachment from it's corresponding Adapter.
+       Throws an error, if the delete fails for whatever reason.
+     */
+    pub fn attachment_drop(&self, attachment: &Attachment) -> Result<(), AdapterError> {
+        let attachment_adapter = attachment.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == attachment_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Attachments)?;
+                        return adapter.attachment_drop(attachment);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::AttachmentDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Reads the contents of an Attachment from it's corresponding Adapter.
+       Returns None, if the contents are not available.
+     */
+    pub fn attachment_read(&self, attachment: &Attachment) -> Option<Vec<u8>> {
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == attachment.adapter {
+                        return adapter.attachment_read(attachment);
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to read attachment from adapters due to {}", err)
+        };
+
+        None
+    }
+
+    /**
+       Lists the work logs of a ticket from it's corresponding Adapter.
+       Doesn't fail, but returns an empty vector instead.
+     */
+    pub fn work_log_list(&self, ticket: &Ticket) -> Vec<WorkLog> {
+        let mut work_logs: Vec<WorkLog> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == ticket.adapter {
+                        work_logs.append(&mut adapter.work_log_list(ticket));
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list work logs from adapters due to {}", err)
+        };
+
+        work_logs
+    }
+
+    /**
+       Checks, if the work log can be written and is guaranteed
+       to keep integrity with it's attributes
+     */
+    pub fn work_log_validate(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        let mut validation_errors: Vec<(String, String)> = Vec::default();
+
+        if work_log.duration <= 0 {
+            validation_errors.push(("duration".to_string(), "The logged Time has to be longer than 0 minutes!".to_string()));
+        };
+
+        if work_log.user.trim().is_empty() {
+            validation_errors.push(("user".to_string(), "The User of the Work Log is not supposed to be empty!".to_string()));
+        };
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                let mut adapter_matched = false;
+
+                for adapter in lock.iter() {
+                    if adapter.get_name() == work_log.adapter {
+                        adapter_matched = true;
+
+                        if adapter.ticket_list_unique(work_log.ticket_id).is_none() {
+                            let ticket = work_log.ticket_id;
+                            validation_errors.push(("ticket".to_string(), format!("The Ticket with id {ticket} does not exist in this adapter.")));
+                        }
+
+                        break;
+                    };
+                };
+
+                if !adapter_matched {
+                    let adapter = &work_log.adapter;
+                    validation_errors.push(("adapter".to_string(), format!("The Adapter \"{adapter}\" does not match any of the available adapters.")));
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::Access).with_reason(err))
+        };
+
+        if validation_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdapterError::new(AdapterErrorType::Validate(validation_errors, "Work Log".to_string())))
+        }
+    }
+
+    /**
+       Write a given Work Log to it's corresponding Adapter. Throws an 
+       Error, if the write failed. Other reasons depend on used adapters.
+     */
+    pub fn work_log_write(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        let work_log_adapter = work_log.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == work_log_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::WorkLogs)?;
+                        return adapter.work_log_write(work_log);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::WorkLogWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Work Log from it's corresponding Adapter.
+       Throws an error, if the delete fails for whatever reason.
+     */
+    pub fn work_log_drop(&self, work_log: &WorkLog) -> Result<(), AdapterError> {
+        let work_log_adapter = work_log.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == work_log_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::WorkLogs)?;
+                        return adapter.work_log_drop(work_log);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::WorkLogDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Sums up the time, that has been logged between from (inclusive) and
+       to (exclusive) on all adapters. The time is grouped by the bucket
+       and the assignee of the ticket, it has been logged on. Both bounds
+       are unix timestamps in seconds. Doesn't fail.
+     */
+    pub fn time_spent(&self, from: i64, to: i64) -> Vec<TimeSpent> {
+        let mut time_spent: BTreeMap<(String, u64, String), i64> = BTreeMap::new();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    let mut tickets: BTreeMap<i64, Option<Ticket>> = BTreeMap::new();
+
+                    for work_log in adapter.work_log_list_range(from, to) {
+                        let ticket = tickets
+                            .entry(work_log.ticket_id)
+                            .or_insert_with(|| adapter.ticket_list_unique(work_log.ticket_id));
+
+                        if let Some(ticket) = ticket {
+                            let key = (adapter.get_name(), ticket.bucket_id, ticket.assigned_to.clone());
+                            *time_spent.entry(key).or_insert(0) += work_log.duration;
+                        }
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list work logs from adapters due to {}", err)
+        };
+
+        time_spent.into_iter().map(|((adapter, bucket_id, assigned_to), duration)| TimeSpent {
+            adapter,
+            bucket_id,
+            assigned_to,
+            duration
+        }).collect()
+    }
+
+    /**
+       Lists the custom field schema of a single Adapter. Returns an
+       empty vector, if the adapter doesn't support custom fields.
+     */
+    pub fn custom_field_list(&self, adapter_name: &String) -> Vec<CustomField> {
+        let mut fields: Vec<CustomField> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name().eq(adapter_name) {
+                        fields.append(&mut adapter.custom_field_list_all());
+                    }
+                }
+            },
+            Err(err) => println!("Wasn't able to list custom fields from adapters due to {}", err)
+        };
+
+        fields
+    }
+
+    /**
+       Writes a given Custom Field definition to it's corresponding Adapter.
+       If the write fails, it throws an Error.
+     */
+    pub fn custom_field_write(&self, field: &CustomField) -> Result<(), AdapterError> {
+        let field_adapter = field.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == field_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::CustomFields)?;
+                        return adapter.custom_field_write(field);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::CustomFieldWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Custom Field definition from it's corresponding
+       Adapter. Throws an error, if the delete fails for whatever reason.
+     */
+    pub fn custom_field_drop(&self, field: &CustomField) -> Result<(), AdapterError> {
+        let field_adapter = field.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == field_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::CustomFields)?;
+                        return adapter.custom_field_drop(field);
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::CustomFieldDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Lists all states from all Adapters. Returns a vector of all
+       found states. Doesn't fail.
+     */
+    pub fn state_list_all(&self) -> Vec<State> {
+        let mut states: Vec<State> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    states.append(&mut adapter.state_list_all());
+                }
+            },
+            Err(err) => println!("Wasn't able to list states from adapters due to {}", err)
+        };
+
+        states
+    }
+
+    /**
+       Write a state to it's corresponding Adapter. If the write goes wrong,
+       an Error gets thrown. Other reasons of an error can be found at the
+       corresponding adapter documentation
+     */
+    pub fn state_write(&self, state: &State) -> Result<(), AdapterError> {
+        let state_adapter = state.identifier.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == state_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::States)?;
+                        return adapter.state_write(state).map(|_| self.events.publish(ProviderEvent::StateChanged(state.identifier.clone())));
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::StateWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Lists all available tags from all Adapters. Can't fail, gives an empty
+       vector instead.
+     */
+    pub fn tag_list_all(&self) -> Vec<Tag> {
+        let mut tags: Vec<Tag> = vec![];
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    tags.append(&mut adapter.tag_list_all());
+                }
+            },
+            Err(err) => println!("Wasn't able to list tags from adapters due to {}", err)
+        };
+
+        tags
+    }
+
+    /**
+       Writes a given Tag to it's corresponding Adapter. If the write fails,
+       it throws an Error. Other reasons might be available on the Adapters
+       documentation
+     */
+    pub fn tag_write(&self, tag: &Tag) -> Result<(), AdapterError> {
+        let tag_adapter = tag.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == tag_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Tags)?;
+                        return adapter.tag_write(tag).map(|_| self.events.publish(ProviderEvent::TagChanged(tag.clone())));
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::TagWrite).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Tag from it's corresponding Adapter. Throws
+       an error, if the delete fails for whatever reason.
+     */
+    pub fn tag_drop(&self, tag: &Tag) -> Result<(), AdapterError> {
+        let tag_adapter = tag.adapter.clone();
+
+        match self.adapters.lock() {
+            Ok(lock) => {
+                for adapter in lock.iter() {
+                    if adapter.get_name() == tag_adapter {
+                        TicketProvider::require_capability(adapter, AdapterCapability::Tags)?;
+                        return adapter.tag_drop(tag).map(|_| self.events.publish(ProviderEvent::TagChanged(tag.clone())));
+                    }
+                }
+            },
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::TagDelete).with_reason(err))
+        }
+
+        Ok(())
+    }
+
+    /**
+       Tries to delete a given Bucket from it's corresponding Adapter. Throws
+       an error, if the delete fails for whatever reason.
      */
     pub fn bucket_drop(&self, bucket: &Bucket) -> Result<(), AdapterError> {
         let bucket_adapter = bucket.identifier.adapter.clone();
@@ -1002,11 +2286,12 @@ impl TicketProvider {
             Ok(lock) => {
                 for adapter in lock.iter() {
                     if adapter.get_name() == bucket_adapter {
-                        return adapter.bucket_drop(bucket);
+                        TicketProvider::require_capability(adapter, AdapterCapability::Buckets)?;
+                        return adapter.bucket_drop(bucket).map(|_| self.events.publish(ProviderEvent::BucketChanged(bucket.identifier.clone())));
                     }
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::BucketDelete))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::BucketDelete).with_reason(err))
         }
 
         Ok(())
@@ -1043,18 +2328,29 @@ impl TicketProvider {
                 if let Some(adapter) = lock.iter().find(|adapter| {
                     adapter.get_name() == adapter_name
                 }) {
-                    return adapter.filter_drop(filter);
+                    TicketProvider::require_capability(adapter, AdapterCapability::Filters)?;
+                    return adapter.filter_drop(filter).map(|_| self.events.publish(ProviderEvent::FilterChanged(filter.identifier.clone())));
                 } else {
                     return Err(AdapterError::new(AdapterErrorType::FilterDelete))
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::FilterDelete))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::FilterDelete).with_reason(err))
         }
 
         Ok(())
     }
 
     pub fn ticket_list_from_selection(&self, locations: &Vec<BucketPanelLocation>) -> Option<Vec<Ticket>> {
+        self.ticket_list_from_selection_sorted(locations, None)
+    }
+
+    /**
+       Lists the tickets of all selected locations, without duplicates. They are
+       ordered by the given sort. Without one, the order of the first selected
+       filter, that defines one with sort_by, is used, and if there is none,
+       the most important Tickets come first
+     */
+    pub fn ticket_list_from_selection_sorted(&self, locations: &Vec<BucketPanelLocation>, sort: Option<TicketSort>) -> Option<Vec<Ticket>> {
 
         // If there are no locations in there, don't do anything
         if locations.is_empty() {
@@ -1090,25 +2386,41 @@ impl TicketProvider {
         }
 
         // Execute all Filters
+        let mut filter_sort: Option<TicketSort> = None;
         let concrete_filters = self.filter_list_by_name(filters);
         for filter in concrete_filters {
+            if filter_sort.is_none() {
+                filter_sort = self.filter_sort(&filter).unwrap_or(None);
+            }
+
             match self.ticket_list(&filter) {
                 Ok(mut filtered_tickets) => tickets.append(&mut filtered_tickets),
                 Err(err) => println!("Failed to apply Filter. reason: {}", err),
             }
         }
-        
-        tickets.sort_by(|first, second| first.id.cmp(&second.id));
 
-        // Remove duplicate Tickets
-        tickets.dedup_by(|first, second| {
-            first.adapter == second.adapter &&
-            first.id == second.id
-        });
+        // Remove duplicate Tickets, the ids are only unique within an adapter
+        let mut found: HashSet<(String, i64)> = HashSet::new();
+        tickets.retain(|ticket| found.insert((ticket.adapter.clone(), ticket.id)));
+
+        sort.or(filter_sort)
+            .unwrap_or_default()
+            .sort(&mut tickets, &self.state_list_all());
 
         Some(tickets)
     }
 
+    /**
+       Same as ticket_list_from_selection, but the tickets are loaded in the
+       background, so slow adapters don't block the caller. Poll the returned
+       request to receive the tickets, once all adapters answered
+     */
+    pub fn ticket_list_from_selection_async(&self, locations: Vec<BucketPanelLocation>, sort: Option<TicketSort>) -> AdapterRequest<Option<Vec<Ticket>>> {
+        let provider = self.snapshot();
+
+        AdapterRequest::dispatch(move || provider.ticket_list_from_selection_sorted(&locations, sort))
+    }
+
     /*
        Get all filters from the supplied names, that have the same name as the 
        supplied strings. If no Filter is found, an empty vector is returned.
@@ -1165,7 +2477,14 @@ impl TicketProvider {
             validation_errors.push(("filter_type".to_string(), "The Type of the Filter does not allow it to be modified".to_string()));
         }
 
-        let found_expressions = match self.split_filter_expression(filter.operation.clone()) {
+        // Adapter neutral queries are checked completely while reading them
+        let found_expressions = match self.filter_query(filter) {
+            Ok(Some(_)) => Ok(vec![]),
+            Ok(None) => self.split_filter_expression(filter.operation.clone()),
+            Err(err) => Err(err),
+        };
+
+        let found_expressions = match found_expressions {
             Ok(found_expressions) => found_expressions,
             Err(err) => {
                 validation_errors.push(("operation".to_string(), err.get_text()));
@@ -1231,11 +2550,12 @@ impl TicketProvider {
             Ok(lock) => {
                 for adapter in lock.iter() {
                     if adapter.get_name() == filter_adapter {
-                        return adapter.filter_write(filter);
+                        TicketProvider::require_capability(adapter, AdapterCapability::Filters)?;
+                        return adapter.filter_write(filter).map(|_| self.events.publish(ProviderEvent::FilterChanged(filter.identifier.clone())));
                     }
                 }
             },
-            Err(_) => return Err(AdapterError::new(AdapterErrorType::FilterWrite))
+            Err(err) => return Err(AdapterError::new(AdapterErrorType::FilterWrite).with_reason(err))
         }
 
         Ok(())
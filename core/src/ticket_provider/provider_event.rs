@@ -0,0 +1,80 @@
+use std::sync::{
+    mpsc::{self, Receiver, Sender},
+    Arc,
+    Mutex
+};
+
+use crate::{
+    AdapterError,
+    BucketIdentifier,
+    FilterIdentifier,
+    StateIdentifier,
+    Tag,
+    Ticket
+};
+
+/**
+   Something, that changed within the ticket provider or one of it's
+   adapters. Tickets and tags are sent as they were written, everything
+   else by it's identifier. Adapters are named by their name.
+ */
+#[derive(Clone, Debug)]
+pub enum ProviderEvent {
+    TicketCreated(Ticket),
+    TicketUpdated(Ticket),
+    TicketDeleted(Ticket),
+    TagChanged(Tag),
+    StateChanged(StateIdentifier),
+    BucketChanged(BucketIdentifier),
+    FilterChanged(FilterIdentifier),
+    AdapterAdded(String),
+    AdapterRemoved(String),
+    AdapterLoaded(String),
+    AdapterFailed(String, AdapterError)
+}
+
+impl ProviderEvent {
+
+    /**
+       Returns true, if the event changes what is shown in the bucket panel,
+       like adapters, buckets, filters, tags and states
+     */
+    pub fn affects_bucket_panel(&self) -> bool {
+        !matches!(self,
+            ProviderEvent::TicketCreated(_) |
+            ProviderEvent::TicketUpdated(_) |
+            ProviderEvent::TicketDeleted(_))
+    }
+}
+
+/**
+   A channel, that adapters and the ticket provider publish their events to.
+   Every subscriber receives every event, that has been published after it
+   subscribed. Subscribers, that have been dropped, are removed on the next
+   publish.
+ */
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscribers: Arc<Mutex<Vec<Sender<ProviderEvent>>>>
+}
+
+impl EventBus {
+
+    pub fn subscribe(&self) -> Receiver<ProviderEvent> {
+        let (sender, receiver) = mpsc::channel();
+
+        match self.subscribers.lock() {
+            Ok(mut lock) => lock.push(sender),
+            Err(err) => println!("Wasn't able to subscribe to events due to {}", err),
+        };
+
+        receiver
+    }
+
+    pub fn publish(&self, event: ProviderEvent) {
+        match self.subscribers.lock() {
+            Ok(mut lock) => lock.retain(|subscriber| subscriber.send(event.clone()).is_ok()),
+            Err(err) => println!("Wasn't able to publish event due to {}", err),
+        };
+    }
+}
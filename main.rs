@@ -9,14 +9,16 @@ use std::{
 
 use tickets_rs_adapters::{
     LocalTicketAdapter,
-    GithubTicketAdapter
+    GithubTicketAdapter,
+    ProcessTicketAdapter
 };
 
 use tickets_rs_core::{
     AppConfig,
     LocalDatabase,
     TicketProvider,
-    AdapterType
+    AdapterType,
+    EventBus
 };
 
 use tickets_rs_ui::{
@@ -38,19 +40,22 @@ async fn main() {
         Arc::new(Mutex::new(database))
     };
 
-    let update_trigger = Arc::new(Mutex::new(false));
+    let events = EventBus::default();
+    let ui_events = events.subscribe();
     let configuration = Arc::new(Mutex::new(AppConfig::new(database)));
     let ticket_provider = Arc::new(Mutex::new( {
         TicketProvider::new(configuration.clone(), vec![
 
             AdapterType::new::<LocalTicketAdapter>(),
             AdapterType::new::<GithubTicketAdapter>(),
+            AdapterType::new::<ProcessTicketAdapter>(),
 
-        ], update_trigger.clone())
+        ], events)
     }));
 
+    TicketProvider::start_recurrence_check(ticket_provider.clone());
 
-    let ui_controller = UIController::new(configuration.clone(), ticket_provider, update_trigger);
+    let ui_controller = UIController::new(configuration.clone(), ticket_provider, ui_events);
     let ui_theme = UITheme::from(configuration);
     UserInterface::launch(ui_controller, ui_theme);
 
@@ -0,0 +1,73 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+use eframe::egui::{
+    Context,
+    mutex::Mutex as EguiMutex,
+    load::{BytesLoader, BytesLoadResult, BytesPoll, Bytes, LoadError}
+};
+use tickets_rs_core::{Attachment, TicketProvider};
+
+/**
+   Resolves attachment:// uris, that are used in markdown descriptions,
+   by reading the contents of the attachment from the ticket provider.
+ */
+pub struct AttachmentLoader {
+    ticket_provider: Arc<Mutex<TicketProvider>>,
+    cache: EguiMutex<HashMap<String, Bytes>>
+}
+
+impl AttachmentLoader {
+    pub fn new(ticket_provider: Arc<Mutex<TicketProvider>>) -> Self {
+        AttachmentLoader {
+            ticket_provider,
+            cache: EguiMutex::new(HashMap::new())
+        }
+    }
+}
+
+impl BytesLoader for AttachmentLoader {
+    fn id(&self) -> &str {
+        eframe::egui::generate_loader_id!(AttachmentLoader)
+    }
+
+    fn load(&self, ctx: &Context, uri: &str) -> BytesLoadResult {
+        let attachment = match Attachment::from_uri(uri) {
+            Some(attachment) => attachment,
+            None => return Err(LoadError::NotSupported),
+        };
+
+        if let Some(bytes) = self.cache.lock().get(uri).cloned() {
+            return Ok(BytesPoll::Ready { size: None, bytes, mime: Some(attachment.mime_type) });
+        }
+
+        // The provider might be in use by the ui right now, so just try again next frame
+        let data = match self.ticket_provider.try_lock() {
+            Ok(provider) => provider.attachment_read(&attachment),
+            Err(_) => {
+                ctx.request_repaint();
+                return Ok(BytesPoll::Pending { size: None });
+            }
+        };
+
+        match data {
+            Some(data) => {
+                let bytes = Bytes::from(data);
+                self.cache.lock().insert(uri.to_string(), bytes.clone());
+                Ok(BytesPoll::Ready { size: None, bytes, mime: Some(attachment.mime_type) })
+            },
+            None => Err(LoadError::Loading(format!("The Attachment {} couldn't be read.", attachment.name)))
+        }
+    }
+
+    fn forget(&self, uri: &str) {
+        self.cache.lock().remove(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn byte_size(&self) -> usize {
+        self.cache.lock().values().map(|bytes| bytes.len()).sum()
+    }
+}
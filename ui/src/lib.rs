@@ -4,12 +4,14 @@ mod ui_theme;
 mod overlays;
 mod ui_cache;
 mod helper;
+mod attachment_loader;
 
 pub use overlays::Overlay;
 pub use ui_controller::UIController;
 pub use ui_theme::UITheme;
 pub use user_interface::UserInterface;
 pub use helper::UIHelper;
+pub use attachment_loader::AttachmentLoader;
 
 pub use ui_cache::*;
 
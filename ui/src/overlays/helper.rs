@@ -1,4 +1,4 @@
-use std::{collections::{hash_map::RandomState, HashMap}, ops::Deref, sync::{Arc, Mutex}};
+use std::{collections::{hash_map::RandomState, HashMap, BTreeMap}, ops::Deref, sync::{Arc, Mutex}, time::Instant};
 
 use arboard::Clipboard;
 use chrono::{DateTime, Utc, Datelike, Duration, Timelike};
@@ -13,9 +13,11 @@ use eframe::egui::{
     TextureHandle, ColorImage, 
     Image,  
     Slider, Visuals, TextBuffer, Checkbox, color_picker::{color_edit_button_rgb, color_edit_button_rgba, Alpha}, Rgba};
+use chrono::TimeZone;
+use egui_commonmark::{CommonMarkCache, CommonMarkViewer};
 use egui_extras::DatePickerButton;
-use tickets_rs_core::{Bucket, Tag, TicketProvider, Config, ToConfig, StateIdentifier};
-use crate::{UITheme, overlays::DialogOptions, UserInterface, UIHelper, UIController, TagCacheKey};
+use tickets_rs_core::{Bucket, Tag, TicketProvider, Config, ToConfig, StateIdentifier, Comment, HistoryEntry, CustomField, CustomFieldType, TicketLink, TicketLinkType, TicketIdentifier, ChecklistItem, Ticket, Attachment, WorkLog, Priority, Recurrence};
+use crate::{UITheme, overlays::{DialogOptions, CommentOptions, LinkOptions, AttachmentOptions, WorkLogOptions}, UserInterface, UIHelper, UIController, TagCacheKey};
 
 
 pub struct OverlayHelper;
@@ -733,6 +735,83 @@ impl OverlayHelper {
         });
     }
     
+    pub fn helper_update_priority(ui: &mut Ui, ui_theme: &UITheme, priority: &mut Priority) {
+        let font_size = ui_theme.font_size as f32;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_height(font_size * 1.5);
+            ComboBox::from_id_source("priority_dropdown")
+                .selected_text(priority.get_name())
+                .width(ui.available_width() * 0.75 - 8.0)
+                .show_ui(ui, |ui| {
+                    for option in Priority::all().into_iter().rev() {
+                        ui.selectable_value(priority, option, option.get_name());
+                    }
+                }
+            );
+            ui.add_space(font_size);
+            ui.label("Priority:");
+        });
+    }
+    
+    pub fn helper_update_custom_field(ui: &mut Ui, ui_theme: &UITheme, field: &CustomField, value: &mut String) {
+        let font_size = ui_theme.font_size as f32;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_height(font_size * 1.5);
+            let width = ui.available_width() * 0.75;
+
+            match &field.field_type {
+                CustomFieldType::Text | CustomFieldType::Number => {
+                    ui.add_sized(Vec2{ x: width, y: font_size * 1.5 }, TextEdit::singleline(value));
+                },
+                CustomFieldType::Date => {
+                    ui.allocate_ui(Vec2{ x: width, y: font_size * 1.5 }, |ui| {
+                        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                            if ui.add_enabled(!value.is_empty(), Button::new("🗙")).on_hover_text_at_pointer("Clear this Field.").clicked() {
+                                value.clear();
+                            }
+
+                            let mut date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                                .unwrap_or_else(|_| Utc::now().date_naive());
+
+                            if ui.add_sized(
+                                [ui.available_width(), font_size], 
+                                DatePickerButton::new(&mut date).id_source(field.name.as_str())).changed() {
+                                *value = date.format("%Y-%m-%d").to_string();
+                            }
+                        });
+                    });
+                },
+                CustomFieldType::Enum(options) => {
+                    ComboBox::from_id_source(["custom_field_", field.name.as_str()].join(""))
+                        .selected_text(value.clone())
+                        .width(width - 8.0)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(value, String::default(), "-");
+                            for option in options {
+                                ui.selectable_value(value, option.clone(), option.clone());
+                            }
+                        }
+                    );
+                },
+                CustomFieldType::Bool => {
+                    ui.allocate_ui(Vec2{ x: width, y: font_size * 1.5 }, |ui| {
+                        ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                            let mut checked = value == "true";
+                            if ui.add(Checkbox::without_text(&mut checked)).changed() {
+                                *value = checked.to_string();
+                            }
+                        });
+                    });
+                }
+            }
+
+            ui.add_space(font_size);
+            ui.label([field.name.as_str(), ":"].join(""));
+        });
+    }
+
     pub fn helper_update_tag(ui: &mut Ui, ui_theme: &UITheme, tag_text: &String, tag_color: &Color32, tag_color_back: &Color32) {
         let font_size = ui_theme.font_size as f32;
 
@@ -755,7 +834,7 @@ impl OverlayHelper {
         });
     }
 
-    pub fn helper_update_extensions(ui: &mut Ui, ui_theme: &UITheme, ticket_provider: Arc<Mutex<TicketProvider>>, adapter_config: &mut Option<Config>, update_trigger: Arc<Mutex<bool>>) {
+    pub fn helper_update_extensions(ui: &mut Ui, ui_theme: &UITheme, ticket_provider: Arc<Mutex<TicketProvider>>, adapter_config: &mut Option<Config>) {
         let font_size = ui_theme.font_size as f32;
 
         match ticket_provider.lock() {
@@ -910,6 +989,24 @@ impl OverlayHelper {
                                                         ui.add(Label::new(option.0.clone() + ":").wrap(true));
                                                     });
                                                 },
+                                                "number" => {
+                                                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+
+                                                        let total_line_width = ui.available_width();
+
+                                                        let mut number = option.1.get::<i64>().unwrap_or_default();
+
+                                                        if ui.add_sized(
+                                                            [total_line_width * 0.75, font_size], 
+                                                            DragValue::new(&mut number)
+                                                        ).changed() {
+                                                            change_config = Some((option.0.clone(), number.to_config(), "number".to_string()));
+                                                        }
+
+                                                        ui.add_space(font_size / 2.0);
+                                                        ui.add(Label::new(option.0.clone() + ":").wrap(true));
+                                                    });
+                                                },
                                                 unmatched => {
 
                                                     ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
@@ -957,7 +1054,7 @@ impl OverlayHelper {
                     };
 
                     if finish_config {
-                        match lock.adapter_from_config(config, true, update_trigger) {
+                        match lock.adapter_from_config(config, true) {
                             Ok(_) => abort_config = true,
                             Err(err) => println!("Wasn't able to create an adapter from Config due to {err}"),
                         }
@@ -1021,6 +1118,519 @@ impl OverlayHelper {
         }
     }
 
+    pub fn helper_update_comments(ui: &mut Ui, ui_theme: &UITheme, comments: &Vec<Comment>, comment_text: &mut String, commonmark: &mut CommonMarkCache) -> CommentOptions {
+        let font_size = ui_theme.font_size as f32;
+        let mut action = CommentOptions::Nothing;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_height(font_size * 20.0);
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    ScrollArea::new([false, true])
+                        .id_source("comments_scroll_area")
+                        .auto_shrink([false, true])
+                        .max_height(font_size * 12.0)
+                        .show(ui, |ui| {
+
+                            if comments.is_empty() {
+                                ui.label(RichText::new("No Comments yet.").italics());
+                            }
+
+                            for comment in comments {
+                                ui.group(|ui| {
+                                    ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                                        ui.label(RichText::new(&comment.author).strong().color(ui_theme.foreground_marker2));
+
+                                        if let Some(created_at) = Utc.timestamp_opt(comment.created_at, 0).single() {
+                                            ui.label(created_at.format("%Y-%m-%d %H:%M").to_string());
+                                        }
+
+                                        if comment.is_edited() {
+                                            ui.label(RichText::new("(edited)").italics());
+                                        }
+
+                                        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                                            if ui.small_button("🗙").on_hover_text_at_pointer("Delete this Comment.").clicked() {
+                                                action = CommentOptions::Delete(comment.clone());
+                                            }
+                                        });
+                                    });
+
+                                    CommonMarkViewer::new(format!("comment_{}::{}", comment.id, comment.adapter)).show(ui, commonmark, &comment.body);
+                                });
+                                ui.add_space(font_size / 4.0);
+                            }
+                    });
+
+                    ui.separator();
+
+                    ui.add_sized(
+                        Vec2{x: ui.available_width(), y: font_size * 3.0}, 
+                        TextEdit::multiline(comment_text).font(TextStyle::Monospace).hint_text("Write a Comment..."));
+
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        if ui.add_enabled(!comment_text.trim().is_empty(), Button::new("Comment")).clicked() {
+                            action = CommentOptions::Submit;
+                        }
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("Comments:");
+        });
+
+        action
+    }
+
+    pub fn helper_update_checklist(ui: &mut Ui, ui_theme: &UITheme, checklist: &mut Vec<ChecklistItem>, checklist_text: &mut String) {
+        let font_size = ui_theme.font_size as f32;
+        let item_count = checklist.len();
+        let mut move_item: Option<(usize, usize)> = None;
+        let mut remove_item: Option<usize> = None;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    if checklist.is_empty() {
+                        ui.label(RichText::new("No Checklist Items yet.").italics());
+                    }
+
+                    for (index, item) in checklist.iter_mut().enumerate() {
+                        ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                            ui.add_enabled(!item.read_only, Checkbox::new(&mut item.done, item.text.as_str()));
+
+                            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                                if item.read_only {
+                                    return;
+                                }
+
+                                if ui.small_button("🗙").on_hover_text_at_pointer("Remove this Item.").clicked() {
+                                    remove_item = Some(index);
+                                }
+
+                                if ui.add_enabled(index + 1 < item_count, Button::new("⏷").small()).clicked() {
+                                    move_item = Some((index, index + 1));
+                                }
+
+                                if ui.add_enabled(index > 0, Button::new("⏶").small()).clicked() {
+                                    move_item = Some((index, index - 1));
+                                }
+                            });
+                        });
+                    }
+
+                    ui.separator();
+
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        let add_clicked = ui.add_enabled(!checklist_text.trim().is_empty(), Button::new("Add")).clicked();
+                        let response = ui.add_sized(
+                            Vec2{ x: ui.available_width(), y: font_size * 1.5 }, 
+                            TextEdit::singleline(checklist_text).hint_text("New Item..."));
+
+                        let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                        if (add_clicked || enter_pressed) && !checklist_text.trim().is_empty() {
+                            checklist.push(ChecklistItem::new(checklist_text.trim().to_string(), false));
+                            checklist_text.clear();
+                        }
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("Checklist:");
+        });
+
+        if let Some((from, to)) = move_item {
+            checklist.swap(from, to);
+        }
+
+        if let Some(index) = remove_item {
+            checklist.remove(index);
+        }
+    }
+
+    pub fn helper_update_links(
+        ui: &mut Ui, 
+        ui_theme: &UITheme, 
+        ticket: &TicketIdentifier, 
+        links: &[TicketLink], 
+        link_titles: &BTreeMap<TicketIdentifier, String>, 
+        link_type: &mut TicketLinkType, 
+        link_target: &mut String) -> LinkOptions {
+
+        let font_size = ui_theme.font_size as f32;
+        let mut action = LinkOptions::Nothing;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    if links.is_empty() {
+                        ui.label(RichText::new("No linked Tickets.").italics());
+                    }
+
+                    for link in links {
+                        if let Some((relation, other)) = link.seen_from(ticket) {
+                            ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                                ui.label(RichText::new(relation.get_fancy_name()).color(ui_theme.foreground_marker2));
+                                ui.label(RichText::new(other.to_string()).strong());
+
+                                if let Some(title) = link_titles.get(&other) {
+                                    ui.label(title);
+                                }
+
+                                ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                                    if ui.small_button("🗙").on_hover_text_at_pointer("Remove this Link.").clicked() {
+                                        action = LinkOptions::Delete(link.clone());
+                                    }
+                                });
+                            });
+                        }
+                    }
+
+                    ui.separator();
+
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        if ui.add_enabled(!link_target.trim().is_empty(), Button::new("Link")).clicked() {
+                            action = LinkOptions::Submit;
+                        }
+
+                        ui.add_sized(
+                            Vec2{ x: ui.available_width() * 0.5, y: font_size * 1.5 }, 
+                            TextEdit::singleline(link_target).hint_text("id or adapter:id"))
+                            .on_hover_text_at_pointer("Link a Ticket of another Adapter by prefixing the id with the adapter name.");
+
+                        ComboBox::from_id_source("link_type_dropdown")
+                            .selected_text(link_type.get_fancy_name())
+                            .width(ui.available_width() - 8.0)
+                            .show_ui(ui, |ui| {
+                                for available_type in TicketLinkType::all() {
+                                    let name = available_type.get_fancy_name();
+                                    ui.selectable_value(link_type, available_type, name);
+                                }
+                            }
+                        );
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("Links:");
+        });
+
+        action
+    }
+
+    pub fn helper_update_attachments(
+        ui: &mut Ui, 
+        ui_theme: &UITheme, 
+        ticket: &mut Ticket, 
+        attachments: &[Attachment]) -> AttachmentOptions {
+
+        let font_size = ui_theme.font_size as f32;
+        let mut action = AttachmentOptions::Nothing;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    if attachments.is_empty() {
+                        ui.label(RichText::new("No Attachments.").italics());
+                    }
+
+                    for attachment in attachments {
+                        ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                            ui.label(RichText::new(&attachment.name).strong());
+                            ui.label(RichText::new(attachment.get_size_text()).color(ui_theme.foreground_marker2));
+
+                            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                                if ui.small_button("🗙").on_hover_text_at_pointer("Remove this Attachment.").clicked() {
+                                    action = AttachmentOptions::Delete(attachment.clone());
+                                }
+
+                                if attachment.is_image() && ui.small_button("🖼").on_hover_text_at_pointer("Show this Image in the Description.").clicked() {
+                                    ticket.description += format!("\n\n![{}](<{}>)", attachment.name, attachment.get_uri()).as_str();
+                                }
+                            });
+                        });
+                    }
+
+                    ui.separator();
+
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        if ui.button("📋 Paste Image").clicked() {
+                            match Clipboard::new().and_then(|mut clipboard| clipboard.get_image()) {
+                                Ok(image) => {
+                                    let image_data = (image.bytes.into_owned(), image.width as u32, image.height as u32);
+
+                                    if let Some(png_bytes) = UIController::image_data_as_png(image_data) {
+                                        let attachment = Attachment::default().with_ticket(ticket);
+                                        let name = format!("pasted_{}.png", attachment.created_at);
+                                        let attachment = attachment
+                                            .with_name(name)
+                                            .with_size(png_bytes.len() as i64);
+
+                                        action = AttachmentOptions::Add(vec![(attachment, png_bytes)]);
+                                    }
+                                },
+                                Err(err) => println!("Wasn't able to paste an image from the clipboard, due to {err}"),
+                            }
+                        }
+
+                        match ui.ctx().input(|input| input.raw.hovered_files.is_empty()) {
+                            true => ui.label(RichText::new("Drop files on the window to attach them.").italics()),
+                            false => ui.label(RichText::new("Release to attach the files.").color(ui_theme.foreground_marker)),
+                        };
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("Attachments:");
+        });
+
+        action
+    }
+
+    /**
+       Collects all files, that have been dropped onto the window in this
+       frame, as attachments for the supplied ticket
+     */
+    pub fn helper_dropped_attachments(ui: &mut Ui, ticket: &Ticket) -> AttachmentOptions {
+        let dropped_files = ui.ctx().input(|input| input.raw.dropped_files.clone());
+        let mut new_attachments: Vec<(Attachment, Vec<u8>)> = vec![];
+
+        for file in dropped_files {
+            let name = match &file.path {
+                Some(path) => path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+                None => file.name.clone(),
+            };
+
+            let data = match (&file.bytes, &file.path) {
+                (Some(bytes), _) => bytes.to_vec(),
+                (None, Some(path)) => match std::fs::read(path) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        println!("Wasn't able to read dropped file {name}, due to {err}");
+                        continue;
+                    }
+                },
+                (None, None) => continue,
+            };
+
+            let attachment = Attachment::default()
+                .with_ticket(ticket)
+                .with_name(name)
+                .with_size(data.len() as i64);
+
+            new_attachments.push((attachment, data));
+        }
+
+        match new_attachments.is_empty() {
+            true => AttachmentOptions::Nothing,
+            false => AttachmentOptions::Add(new_attachments),
+        }
+    }
+
+    pub fn helper_update_estimate(ui: &mut Ui, ui_theme: &UITheme, estimate: &mut i64, estimate_text: &mut String, logged: i64) {
+        let font_size = ui_theme.font_size as f32;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_height(font_size * 1.5);
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            let logged_text = match *estimate {
+                0 => format!("{} logged", WorkLog::format_duration(logged)),
+                _ => format!("{} of {} logged", WorkLog::format_duration(logged), WorkLog::format_duration(*estimate)),
+            };
+
+            match logged > *estimate && *estimate > 0 {
+                true => ui.label(RichText::new(logged_text).color(ui_theme.foreground_marker)),
+                false => ui.label(logged_text),
+            };
+
+            let response = ui.add_sized(
+                Vec2{ x: ui.available_width(), y: font_size * 1.5 }, 
+                TextEdit::singleline(estimate_text).hint_text("e.g. 1h 30m"));
+
+            if response.changed() {
+                if estimate_text.trim().is_empty() {
+                    *estimate = 0;
+                } else if let Some(parsed) = WorkLog::parse_duration(estimate_text) {
+                    *estimate = parsed;
+                }
+            }
+
+            ui.add_space(font_size);
+            ui.label("Estimate:");
+        });
+    }
+
+    pub fn helper_update_recurrence(ui: &mut Ui, ui_theme: &UITheme, recurrence: &mut Option<Recurrence>, recurrence_text: &mut String) {
+        let font_size = ui_theme.font_size as f32;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_height(font_size * 1.5);
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            // Keep the last valid rule, until the text can be read again
+            if !recurrence_text.trim().is_empty() && Recurrence::from_text(recurrence_text).is_none() {
+                ui.label(RichText::new("invalid").color(ui_theme.foreground_marker))
+                    .on_hover_text_at_pointer("Use daily, weekly, monthly, every 2 weeks or a cron expression like 0 9 * * 1-5.");
+            }
+
+            let response = ui.add_sized(
+                Vec2{ x: ui.available_width(), y: font_size * 1.5 }, 
+                TextEdit::singleline(recurrence_text).hint_text("e.g. weekly"));
+
+            if response.changed() {
+                if recurrence_text.trim().is_empty() {
+                    *recurrence = None;
+                } else if let Some(parsed) = Recurrence::from_text(recurrence_text) {
+                    *recurrence = Some(parsed);
+                }
+            }
+
+            ui.add_space(font_size);
+            ui.label("Repeat:");
+        });
+    }
+
+    pub fn helper_update_work_logs(
+        ui: &mut Ui, 
+        ui_theme: &UITheme, 
+        work_logs: &[WorkLog], 
+        duration_text: &mut String, 
+        note_text: &mut String, 
+        timer_started: &mut Option<Instant>) -> WorkLogOptions {
+
+        let font_size = ui_theme.font_size as f32;
+        let mut action = WorkLogOptions::Nothing;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    if work_logs.is_empty() {
+                        ui.label(RichText::new("No Time logged yet.").italics());
+                    }
+
+                    for work_log in work_logs {
+                        ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                            ui.label(RichText::new(WorkLog::format_duration(work_log.duration)).strong());
+                            ui.label(RichText::new(&work_log.user).color(ui_theme.foreground_marker2));
+
+                            if let Some(logged_at) = Utc.timestamp_opt(work_log.logged_at, 0).single() {
+                                ui.label(logged_at.format("%Y-%m-%d %H:%M").to_string());
+                            }
+
+                            ui.label(&work_log.note);
+
+                            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                                if ui.small_button("🗙").on_hover_text_at_pointer("Remove this Work Log.").clicked() {
+                                    action = WorkLogOptions::Delete(work_log.clone());
+                                }
+                            });
+                        });
+                    }
+
+                    ui.separator();
+
+                    ui.add_sized(
+                        Vec2{ x: ui.available_width(), y: font_size * 1.5 }, 
+                        TextEdit::singleline(note_text).hint_text("What has been done..."));
+
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        match timer_started {
+                            Some(started) => {
+                                let elapsed = started.elapsed().as_secs() as i64;
+
+                                if ui.button(format!("⏹ Stop ({})", WorkLog::format_duration(elapsed))).clicked() {
+                                    *timer_started = None;
+                                    action = WorkLogOptions::Submit(elapsed);
+                                }
+
+                                ui.ctx().request_repaint_after(std::time::Duration::from_secs(1));
+                            },
+                            None => {
+                                if ui.button("▶ Start").on_hover_text_at_pointer("Start a Timer, that logs the Time once it is stopped.").clicked() {
+                                    *timer_started = Some(Instant::now());
+                                }
+                            }
+                        }
+
+                        let parsed_duration = WorkLog::parse_duration(duration_text);
+                        if ui.add_enabled(parsed_duration.is_some(), Button::new("Log")).clicked() {
+                            action = WorkLogOptions::Submit(parsed_duration.unwrap_or_default());
+                        }
+
+                        ui.add_sized(
+                            Vec2{ x: ui.available_width(), y: font_size * 1.5 }, 
+                            TextEdit::singleline(duration_text).hint_text("e.g. 45m"));
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("Work Logs:");
+        });
+
+        action
+    }
+
+    pub fn helper_update_history(ui: &mut Ui, ui_theme: &UITheme, history: &[HistoryEntry]) {
+        let font_size = ui_theme.font_size as f32;
+
+        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+            ui.set_max_width(ui.available_width() * 0.75);
+
+            ui.group(|ui| {
+                ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+
+                    ScrollArea::new([false, true])
+                        .id_source("history_scroll_area")
+                        .auto_shrink([false, true])
+                        .max_height(font_size * 12.0)
+                        .show(ui, |ui| {
+
+                            if history.is_empty() {
+                                ui.label(RichText::new("No History recorded.").italics());
+                            }
+
+                            for entry in history.iter().rev() {
+                                ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                                    if let Some(changed_at) = Utc.timestamp_opt(entry.changed_at, 0).single() {
+                                        ui.label(changed_at.format("%Y-%m-%d %H:%M").to_string());
+                                    }
+
+                                    ui.label(RichText::new(&entry.changed_by).strong().color(ui_theme.foreground_marker2));
+                                });
+                                ui.add(Label::new(entry.describe()).wrap(true));
+                                ui.add_space(font_size / 4.0);
+                            }
+                    });
+                });
+            });
+
+            ui.add_space(font_size);
+            ui.label("History:");
+        });
+    }
+
     pub fn helper_update_tags(ui: &mut Ui, ui_theme: &UITheme, ticket_tags: &mut Vec<String>, tag_text: &mut String, adapter: &String, tags: &HashMap<TagCacheKey, [Color32; 2]>) {
         let font_size = ui_theme.font_size as f32;
 
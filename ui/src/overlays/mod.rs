@@ -20,7 +20,7 @@ use eframe::egui::{
 
 use chrono::{Date, Utc, TimeZone, Duration, DateTime, Datelike, Timelike, offset};
 use tickets_rs_core::{State, Filter};
-use tickets_rs_core::{Ticket, Bucket, Tag};
+use tickets_rs_core::{Ticket, Comment, TicketLink, Attachment, WorkLog, Bucket, Tag};
 
 use helper::OverlayHelper as OverlayHelper;
 use crate::{UITheme, UserInterface, UIController, ui_controller, UICache};
@@ -51,7 +51,7 @@ pub enum Overlay {
     Preferences(PreferenceData),
 
     NewTicket(NewTicketData),
-    EditTicket(EditTicketData),
+    EditTicket(Box<EditTicketData>),
     UpdateTicketState(UpdateTicketData),
     UpdateTicketDetails(UpdateTicketData),
     UpdateTicketBucket(UpdateTicketDataBucket),
@@ -81,6 +81,34 @@ pub enum DialogOptions {
     Confirm
 }
 
+#[derive(PartialEq, Clone)]
+pub enum CommentOptions {
+    Nothing,
+    Submit,
+    Delete(Comment)
+}
+
+#[derive(PartialEq, Clone)]
+pub enum LinkOptions {
+    Nothing,
+    Submit,
+    Delete(TicketLink)
+}
+
+#[derive(PartialEq, Clone)]
+pub enum AttachmentOptions {
+    Nothing,
+    Add(Vec<(Attachment, Vec<u8>)>),
+    Delete(Attachment)
+}
+
+#[derive(PartialEq, Clone)]
+pub enum WorkLogOptions {
+    Nothing,
+    Submit(i64),
+    Delete(WorkLog)
+}
+
 #[derive(PartialEq)]
 pub enum OverlayAction {
     Nothing,
@@ -91,6 +119,18 @@ pub enum OverlayAction {
     UpdateTicketAdapter(Ticket, String), //Old Adapter Name
     DeleteTicket(Ticket),
 
+    NewComment(Comment),
+    DeleteComment(Comment),
+
+    NewLink(TicketLink),
+    DeleteLink(TicketLink),
+
+    NewAttachments(Vec<(Attachment, Vec<u8>)>),
+    DeleteAttachment(Attachment),
+
+    NewWorkLog(WorkLog),
+    DeleteWorkLog(WorkLog),
+
     NewTag(Tag),
     UpdateTag(Tag),
     DeleteTag(Tag),
@@ -199,6 +239,14 @@ impl OverlayAction {
             OverlayAction::EditFilter(filter) => OverlayAction::action_filter(ui_controller, cache, filter),
             OverlayAction::DeleteFilter(filter) => OverlayAction::action_filter_delete(ui_controller, cache, filter),
             OverlayAction::DeleteBucket(bucket) => OverlayAction::action_bucket_delete(ui_controller, bucket),
+            OverlayAction::NewComment(comment) => OverlayAction::action_comment(ui_controller, comment),
+            OverlayAction::DeleteComment(comment) => OverlayAction::action_comment_delete(ui_controller, comment),
+            OverlayAction::NewLink(link) => OverlayAction::action_link(ui_controller, link),
+            OverlayAction::DeleteLink(link) => OverlayAction::action_link_delete(ui_controller, link),
+            OverlayAction::NewAttachments(attachments) => OverlayAction::action_attachments(ui_controller, attachments),
+            OverlayAction::DeleteAttachment(attachment) => OverlayAction::action_attachment_delete(ui_controller, attachment),
+            OverlayAction::NewWorkLog(work_log) => OverlayAction::action_work_log(ui_controller, work_log),
+            OverlayAction::DeleteWorkLog(work_log) => OverlayAction::action_work_log_delete(ui_controller, work_log),
         };
     }
 }
\ No newline at end of file
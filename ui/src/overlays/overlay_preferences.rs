@@ -51,8 +51,7 @@ impl Overlay {
                 ui, 
                 ui_theme, 
                 ui_controller.ticket_provider.clone(),
-                &mut preference_data.extension_config,
-                ui_controller.update_panel_data.clone());
+                &mut preference_data.extension_config);
         });
 
         match OverlayHelper::helper_update_dialog_buttons(ui, ui_theme, None) {
@@ -63,8 +63,7 @@ impl OverlayAction {
                         },
                         Err(error) => {
 
-                            let error_message = error.get_text();
-                            let mut errors = vec![("other".to_string(), error_message)];
+                            let mut errors = error.get_errors();
 
                             Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
 
@@ -73,13 +72,7 @@ impl OverlayAction {
                 },
                 Err(adapter_error) => {
 
-                    let mut errors = match adapter_error.error_type {
-                        tickets_rs_core::AdapterErrorType::Validate(errors_vec, _) => errors_vec,
-                        _ => {
-                            let error_message = adapter_error.get_text();
-                            vec![("other".to_string(), error_message)]
-                        }
-                    };
+                    let mut errors = adapter_error.get_errors();
 
                     Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
                 }
@@ -1,12 +1,12 @@
-use std::collections::{hash_map::RandomState, HashMap};
+use std::{collections::{hash_map::RandomState, HashMap, BTreeMap}, time::Instant};
 
 use chrono::{Utc, DateTime};
 use eframe::egui::{Ui, Color32};
-use tickets_rs_core::{Bucket, Ticket};
+use tickets_rs_core::{Bucket, Ticket, Comment, HistoryEntry, CustomField, TicketLink, TicketLinkType, TicketIdentifier, Attachment, WorkLog, TicketProvider};
 
 use crate::{Overlay, UITheme, UIController, UICache};
 
-use super::{OverlayHelper, OverlayAction, DialogOptions};
+use super::{OverlayHelper, OverlayAction, DialogOptions, CommentOptions, LinkOptions, AttachmentOptions, WorkLogOptions};
 
 
 #[derive(Default, PartialEq, Clone)]
@@ -15,6 +15,7 @@ pub struct NewTicketData {
     pub tag_text: String,
     pub assigned_text: String,
     pub due_date: DateTime<Utc>,
+    pub recurrence_text: String,
     pub username: String,
     pub buckets: Vec<Bucket>,
     pub adapters: Vec<(String, String)>,
@@ -27,11 +28,69 @@ pub struct EditTicketData {
     pub tag_text: String,
     pub assigned_text: String,
     pub due_date: DateTime<Utc>,
+    pub recurrence_text: String,
     pub username: String,
     pub buckets: Vec<Bucket>,
+    pub custom_fields: Vec<CustomField>,
+    pub checklist_text: String,
+    pub attachments: Vec<Attachment>,
+    pub estimate_text: String,
+    pub work_logs: Vec<WorkLog>,
+    pub work_log_duration: String,
+    pub work_log_note: String,
+    pub timer_started: Option<Instant>,
+    pub comments: Vec<Comment>,
+    pub comments_loading: bool,
+    pub comment_text: String,
+    pub history: Vec<HistoryEntry>,
+    pub links: Vec<TicketLink>,
+    pub link_titles: BTreeMap<TicketIdentifier, String>,
+    pub link_type: TicketLinkType,
+    pub link_target: String,
     pub errors: Vec<(String, String)>,
 }
 
+impl EditTicketData {
+
+    /**
+       Reloads the links of the edited ticket together with the
+       titles of all linked tickets
+     */
+    pub fn refresh_links(&mut self, provider: &TicketProvider) {
+        let own_identifier = self.ticket.get_identifier();
+
+        self.links = provider.link_list(&self.ticket);
+        self.link_titles.clear();
+
+        for link in &self.links {
+            if let Some((_, other)) = link.seen_from(&own_identifier) {
+                if let Some(ticket) = provider.ticket_list_unique(&other) {
+                    self.link_titles.insert(other, ticket.title);
+                }
+            }
+        }
+    }
+
+    /**
+       Creates a new link from the current link input. The target can
+       either be just an id within the same adapter or in the form of
+       adapter:id. Returns None, if the input can't be read.
+     */
+    pub fn link_from_input(&self) -> Option<TicketLink> {
+        let input = self.link_target.trim();
+
+        let target = match input.contains(':') {
+            true => input.parse::<TicketIdentifier>().ok()?,
+            false => TicketIdentifier::new(&self.ticket.adapter, input.parse::<i64>().ok()?),
+        };
+
+        Some(TicketLink::default()
+            .with_source(self.ticket.get_identifier())
+            .with_target(target)
+            .with_type(self.link_type.clone()))
+    }
+}
+
 #[derive(Default, PartialEq, Clone)]
 pub struct UpdateTicketData {
     pub ticket: Ticket,
@@ -203,6 +262,8 @@ impl Overlay {
             OverlayHelper::helper_update_bucket(ui, ui_theme, &mut ticket_data.ticket.bucket_id, &ticket_data.buckets, &ticket_data.ticket.adapter);
             OverlayHelper::helper_update_small_spacer(ui, ui_theme);
             OverlayHelper::helper_update_state(ui, ui_theme, &mut ticket_data.ticket.state_name, &cache.states, &ticket_data.ticket.adapter);
+            OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+            OverlayHelper::helper_update_priority(ui, ui_theme, &mut ticket_data.ticket.priority);
         });
     
         OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Main Content", true, |ui| {
@@ -228,6 +289,8 @@ impl Overlay {
             if let Some(new_ts) = OverlayHelper::helper_update_due(ui, ui_theme, &mut ticket_data.due_date) {
                 ticket_data.ticket.due_at = new_ts;
             }
+            OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+            OverlayHelper::helper_update_recurrence(ui, ui_theme, &mut ticket_data.ticket.recurrence, &mut ticket_data.recurrence_text);
         });
     
         OverlayHelper::helper_update_small_spacer(ui, ui_theme);
@@ -251,6 +314,8 @@ impl Overlay {
             OverlayHelper::helper_update_bucket(ui, ui_theme, &mut ticket_data.ticket.bucket_id, &ticket_data.buckets, &ticket_data.ticket.adapter);
             OverlayHelper::helper_update_small_spacer(ui, ui_theme);
             OverlayHelper::helper_update_state(ui, ui_theme, &mut ticket_data.ticket.state_name, &cache.states, &ticket_data.ticket.adapter);
+            OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+            OverlayHelper::helper_update_priority(ui, ui_theme, &mut ticket_data.ticket.priority);
         });
     
         OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Main Content", true, |ui| {
@@ -276,10 +341,125 @@ impl Overlay {
             if let Some(new_ts) = OverlayHelper::helper_update_due(ui, ui_theme, &mut ticket_data.due_date) {
                 ticket_data.ticket.due_at = new_ts;
             }
+            OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+            OverlayHelper::helper_update_recurrence(ui, ui_theme, &mut ticket_data.ticket.recurrence, &mut ticket_data.recurrence_text);
+        });
+
+        if !ticket_data.custom_fields.is_empty() {
+            OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Custom Fields", true, |ui| {
+                for field in &ticket_data.custom_fields {
+                    let value = ticket_data.ticket.custom_fields.entry(field.name.clone()).or_default();
+                    OverlayHelper::helper_update_custom_field(ui, ui_theme, field, value);
+                    OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+                }
+            });
+        }
+
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Checklist", !ticket_data.ticket.checklist.is_empty(), |ui| {
+            OverlayHelper::helper_update_checklist(ui, ui_theme, &mut ticket_data.ticket.checklist, &mut ticket_data.checklist_text);
+        });
+
+        let mut work_log_action = WorkLogOptions::Nothing;
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Time Tracking", ticket_data.timer_started.is_some(), |ui| {
+            let logged = ticket_data.work_logs.iter().map(|work_log| work_log.duration).sum();
+            OverlayHelper::helper_update_estimate(ui, ui_theme, &mut ticket_data.ticket.estimate, &mut ticket_data.estimate_text, logged);
+            OverlayHelper::helper_update_small_spacer(ui, ui_theme);
+            work_log_action = OverlayHelper::helper_update_work_logs(
+                ui, 
+                ui_theme, 
+                &ticket_data.work_logs, 
+                &mut ticket_data.work_log_duration, 
+                &mut ticket_data.work_log_note, 
+                &mut ticket_data.timer_started
+            );
+        });
+
+        let mut attachment_action = OverlayHelper::helper_dropped_attachments(ui, &ticket_data.ticket);
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Attachments", !ticket_data.attachments.is_empty(), |ui| {
+            let action = OverlayHelper::helper_update_attachments(
+                ui, 
+                ui_theme, 
+                &mut ticket_data.ticket, 
+                &ticket_data.attachments
+            );
+
+            if action != AttachmentOptions::Nothing {
+                attachment_action = action;
+            }
+        });
+
+        let mut link_action = LinkOptions::Nothing;
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Links", false, |ui| {
+            link_action = OverlayHelper::helper_update_links(
+                ui, 
+                ui_theme, 
+                &TicketIdentifier::from(&ticket_data.ticket), 
+                &ticket_data.links, 
+                &ticket_data.link_titles, 
+                &mut ticket_data.link_type, 
+                &mut ticket_data.link_target
+            );
+        });
+
+        let mut comment_action = CommentOptions::Nothing;
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "Comments", false, |ui| {
+            if ticket_data.comments_loading {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading Comments...");
+                });
+                return;
+            }
+
+            comment_action = OverlayHelper::helper_update_comments(
+                ui, 
+                ui_theme, 
+                &ticket_data.comments, 
+                &mut ticket_data.comment_text, 
+                &mut cache.commonmark
+            );
+        });
+
+        OverlayHelper::helper_update_section_collapsing(ui, ui_theme, "History", false, |ui| {
+            OverlayHelper::helper_update_history(ui, ui_theme, &ticket_data.history);
         });
     
         OverlayHelper::helper_update_small_spacer(ui, ui_theme);
         OverlayHelper::helper_update_errors(ui, ui_theme, &ticket_data.errors);
+
+        match comment_action {
+            CommentOptions::Nothing => (),
+            CommentOptions::Submit => return OverlayAction::NewComment(
+                Comment::default()
+                    .with_ticket(&ticket_data.ticket)
+                    .with_details(0, ticket_data.username.clone(), ticket_data.comment_text.trim().to_string())),
+            CommentOptions::Delete(comment) => return OverlayAction::DeleteComment(comment),
+        };
+
+        match link_action {
+            LinkOptions::Nothing => (),
+            LinkOptions::Submit => match ticket_data.link_from_input() {
+                Some(link) => return OverlayAction::NewLink(link),
+                None => ticket_data.errors = vec![("link".to_string(), "The linked Ticket has to be an id or adapter:id.".to_string())],
+            },
+            LinkOptions::Delete(link) => return OverlayAction::DeleteLink(link),
+        };
+
+        match work_log_action {
+            WorkLogOptions::Nothing => (),
+            WorkLogOptions::Submit(duration) => return OverlayAction::NewWorkLog(
+                WorkLog::default()
+                    .with_ticket(&ticket_data.ticket)
+                    .with_details(0, ticket_data.username.clone(), duration, ticket_data.work_log_note.trim().to_string())),
+            WorkLogOptions::Delete(work_log) => return OverlayAction::DeleteWorkLog(work_log),
+        };
+
+        match attachment_action {
+            AttachmentOptions::Nothing => (),
+            AttachmentOptions::Add(attachments) => return OverlayAction::NewAttachments(attachments),
+            AttachmentOptions::Delete(attachment) => return OverlayAction::DeleteAttachment(attachment),
+        };
+
         match OverlayHelper::helper_update_dialog_buttons(ui, ui_theme, Some("Edit".to_string())) {
             DialogOptions::Nothing => OverlayAction::Nothing,
             DialogOptions::Close => OverlayAction::CloseOverlay,
@@ -316,8 +496,7 @@ impl OverlayAction {
                         Ok(_) => create_successful = true,
                         Err(error) => {
 
-                            let error_message = error.get_text();
-                            let mut errors = vec![("other".to_string(), error_message)];
+                            let mut errors = error.get_errors();
 
                             Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
 
@@ -326,13 +505,7 @@ impl OverlayAction {
                 },
                 Err(adapter_error) => {
 
-                    let mut errors = match adapter_error.error_type {
-                        tickets_rs_core::AdapterErrorType::Validate(errors_vec, _) => errors_vec,
-                        _ => {
-                            let error_message = adapter_error.get_text();
-                            vec![("other".to_string(), error_message)]
-                        }
-                    };
+                    let mut errors = adapter_error.get_errors();
 
                     Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
                 }
@@ -348,8 +521,7 @@ impl OverlayAction {
                     Ok(_) => delete_successful = true,
                     Err(error) => {
     
-                        let error_message = error.get_text();
-                        let mut errors = vec![("other".to_string(), error_message)];
+                        let mut errors = error.get_errors();
     
                         Overlay::put_errors(controller.get_current_overlay(), &mut errors);
     
@@ -360,7 +532,6 @@ impl OverlayAction {
 
         if create_successful && delete_successful {
             ui_controller.close_overlay();
-            ui_controller.execute_bucket_panel_selection();
         }
     }
 
@@ -375,8 +546,7 @@ impl OverlayAction {
                 Ok(_) => action_successful = true,
                 Err(error) => {
 
-                    let error_message = error.get_text();
-                    let mut errors = vec![("other".to_string(), error_message)];
+                    let mut errors = error.get_errors();
 
                     Overlay::put_errors(controller.get_current_overlay(), &mut errors);
 
@@ -387,7 +557,6 @@ impl OverlayAction {
 
         if action_successful {
             ui_controller.close_overlay();
-            ui_controller.execute_bucket_panel_selection();
         }
     }
 
@@ -403,8 +572,7 @@ impl OverlayAction {
                         Ok(_) => action_successful = true,
                         Err(error) => {
 
-                            let error_message = error.get_text();
-                            let mut errors = vec![("other".to_string(), error_message)];
+                            let mut errors = error.get_errors();
 
                             Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
 
@@ -413,13 +581,7 @@ impl OverlayAction {
                 },
                 Err(adapter_error) => {
 
-                    let mut errors = match adapter_error.error_type {
-                        tickets_rs_core::AdapterErrorType::Validate(errors_vec, _) => errors_vec,
-                        _ => {
-                            let error_message = adapter_error.get_text();
-                            vec![("other".to_string(), error_message)]
-                        }
-                    };
+                    let mut errors = adapter_error.get_errors();
 
                     Overlay::put_errors(&mut controller.get_current_overlay(), &mut errors);
                 }
@@ -428,7 +590,243 @@ impl OverlayAction {
 
         if action_successful {
             ui_controller.close_overlay();
-            ui_controller.execute_bucket_panel_selection();
         }
     }
-}
\ No newline at end of file
+
+    pub(crate) fn action_comment(
+        ui_controller: &mut UIController,
+        comment: Comment
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+            match provider.comment_validate(&comment) {
+                Ok(_) => {
+                    match provider.comment_write(&comment) {
+                        Ok(_) => {
+                            if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                                ticket_data.comments_loading = true;
+                                ticket_data.comment_text.clear();
+                                ticket_data.errors.clear();
+
+                                let ticket = ticket_data.ticket.clone();
+                                controller.request_comments(provider, &ticket);
+                            }
+                        },
+                        Err(error) => {
+
+                            let mut errors = error.get_errors();
+
+                            Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                        },
+                    };
+                },
+                Err(adapter_error) => {
+
+                    let mut errors = adapter_error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+                }
+            }
+        });
+    }
+
+    pub(crate) fn action_comment_delete(
+        ui_controller: &mut UIController,
+        comment: Comment
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+
+            match provider.comment_drop(&comment) {
+                Ok(_) => {
+                    if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                        ticket_data.comments_loading = true;
+                        ticket_data.errors.clear();
+
+                        let ticket = ticket_data.ticket.clone();
+                        controller.request_comments(provider, &ticket);
+                    }
+                },
+                Err(error) => {
+
+                    let mut errors = error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                },
+            };
+
+        });
+    }
+
+    pub(crate) fn action_link(
+        ui_controller: &mut UIController,
+        link: TicketLink
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+            match provider.link_validate(&link) {
+                Ok(_) => {
+                    match provider.link_write(&link) {
+                        Ok(_) => {
+                            if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                                ticket_data.refresh_links(provider);
+                                ticket_data.link_target.clear();
+                                ticket_data.errors.clear();
+                            }
+                        },
+                        Err(error) => {
+
+                            let mut errors = error.get_errors();
+
+                            Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                        },
+                    };
+                },
+                Err(adapter_error) => {
+
+                    let mut errors = adapter_error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+                }
+            }
+        });
+    }
+
+    pub(crate) fn action_link_delete(
+        ui_controller: &mut UIController,
+        link: TicketLink
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+
+            match provider.link_drop(&link) {
+                Ok(_) => {
+                    if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                        ticket_data.refresh_links(provider);
+                        ticket_data.errors.clear();
+                    }
+                },
+                Err(error) => {
+
+                    let mut errors = error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                },
+            };
+
+        });
+    }
+
+    pub(crate) fn action_attachments(
+        ui_controller: &mut UIController,
+        attachments: Vec<(Attachment, Vec<u8>)>
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+            let mut errors: Vec<(String, String)> = vec![];
+
+            for (attachment, data) in &attachments {
+                let result = provider
+                    .attachment_validate(attachment, data)
+                    .and_then(|_| provider.attachment_add(attachment, data));
+
+                if let Err(adapter_error) = result {
+                    errors.append(&mut adapter_error.get_errors());
+                }
+            }
+
+            if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                ticket_data.attachments = provider.attachment_list(&ticket_data.ticket);
+                ticket_data.errors.clear();
+            }
+
+            if !errors.is_empty() {
+                Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+            }
+        });
+    }
+
+    pub(crate) fn action_attachment_delete(
+        ui_controller: &mut UIController,
+        attachment: Attachment
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+
+            match provider.attachment_drop(&attachment) {
+                Ok(_) => {
+                    if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                        ticket_data.attachments = provider.attachment_list(&ticket_data.ticket);
+                        ticket_data.errors.clear();
+                    }
+                },
+                Err(error) => {
+
+                    let mut errors = error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                },
+            };
+
+        });
+    }
+
+    pub(crate) fn action_work_log(
+        ui_controller: &mut UIController,
+        work_log: WorkLog
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+            match provider.work_log_validate(&work_log) {
+                Ok(_) => {
+                    match provider.work_log_write(&work_log) {
+                        Ok(_) => {
+                            if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                                ticket_data.work_logs = provider.work_log_list(&ticket_data.ticket);
+                                ticket_data.work_log_duration.clear();
+                                ticket_data.work_log_note.clear();
+                                ticket_data.errors.clear();
+                            }
+                        },
+                        Err(error) => {
+
+                            let mut errors = error.get_errors();
+
+                            Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                        },
+                    };
+                },
+                Err(adapter_error) => {
+
+                    let mut errors = adapter_error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+                }
+            }
+        });
+    }
+
+    pub(crate) fn action_work_log_delete(
+        ui_controller: &mut UIController,
+        work_log: WorkLog
+    ) {
+        ui_controller.using_ticket_provider_mut(|controller, provider| {
+
+            match provider.work_log_drop(&work_log) {
+                Ok(_) => {
+                    if let Overlay::EditTicket(ticket_data) = controller.get_current_overlay() {
+                        ticket_data.work_logs = provider.work_log_list(&ticket_data.ticket);
+                        ticket_data.errors.clear();
+                    }
+                },
+                Err(error) => {
+
+                    let mut errors = error.get_errors();
+
+                    Overlay::put_errors(controller.get_current_overlay(), &mut errors);
+
+                },
+            };
+
+        });
+    }
+}
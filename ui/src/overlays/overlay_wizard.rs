@@ -129,8 +129,7 @@ impl Overlay {
                 ui, 
                 ui_theme, 
                 ui_controller.ticket_provider.clone(), 
-                &mut wizard_data.extension_config,
-                ui_controller.update_panel_data.clone()
+                &mut wizard_data.extension_config
             );
         });
     }
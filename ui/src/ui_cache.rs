@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, BTreeMap};
 use eframe::egui::Color32;
 use egui_commonmark::CommonMarkCache;
-use tickets_rs_core::StateIdentifier;
+use tickets_rs_core::{StateIdentifier, AdapterCapabilities};
 
 use crate::UIController;
 
@@ -30,6 +30,9 @@ pub struct UICache {
     pub states_valid: bool,
     pub states: HashMap<StateIdentifier, String>,
 
+    pub capabilities_valid: bool,
+    pub capabilities: BTreeMap<String, AdapterCapabilities>,
+
     pub username_valid: bool,
     pub username: String,
 
@@ -43,10 +46,12 @@ impl UICache {
         if ui_controller.invalidate_cache {
             self.tags_valid = false;
             self.states_valid = false;
+            self.capabilities_valid = false;
         }
 
         self.refresh_tags(ui_controller);
         self.refresh_states(ui_controller);
+        self.refresh_capabilities(ui_controller);
         self.refresh_username(ui_controller);
 
         ui_controller.invalidate_cache = false;
@@ -78,4 +83,19 @@ impl UICache {
             self.states_valid = true;
         }
     }
+
+    pub fn refresh_capabilities(&mut self, ui_controller: &UIController) {
+        if !self.capabilities_valid {
+            self.capabilities = ui_controller.get_capabilities();
+            self.capabilities_valid = true;
+        }
+    }
+
+    /**
+       Returns the capabilities of an adapter. Adapters, that are not
+       known (yet), are treated as read only
+     */
+    pub fn get_capabilities(&self, adapter: &String) -> AdapterCapabilities {
+        self.capabilities.get(adapter).cloned().unwrap_or_default()
+    }
 }
\ No newline at end of file
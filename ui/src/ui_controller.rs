@@ -1,10 +1,10 @@
-use std::{sync::{Arc, Mutex}, collections::{HashMap, BTreeMap}, rc::Rc, cell::RefCell, fs::File, path::Path, time::SystemTime};
+use std::{sync::{Arc, Mutex, mpsc::Receiver}, collections::{HashMap, BTreeMap}, rc::Rc, cell::RefCell, fs::File, path::Path, time::{SystemTime, Duration, Instant}};
 
 use chrono::{DateTime, NaiveDateTime, Utc, TimeZone};
 use eframe::IconData;
 use std::fmt::Write;
 use eframe::egui::{Ui, SelectableLabel, ColorImage, TextureHandle, Color32};
-use tickets_rs_core::{AppConfig, TicketProvider, BucketPanelLocation, BucketPanelLocationType, Ticket, Tag, Bucket, AdapterError, TicketAdapter, State, FilterType, Filter, FilterIdentifier, StateIdentifier, BucketIdentifier};
+use tickets_rs_core::{AppConfig, TicketProvider, BucketPanelLocation, BucketPanelLocationType, Ticket, Comment, HistoryEntry, CustomField, Attachment, WorkLog, Tag, Bucket, AdapterError, AdapterCapabilities, AdapterStatus, AdapterRequest, ProviderEvent, TicketAdapter, TicketSort, State, FilterType, Filter, FilterIdentifier, StateIdentifier, BucketIdentifier, TicketIdentifier};
 
 use crate::{UserInterface, UITheme, Overlay, overlays::{NewTicketData, OverlayAction, NewTagData, WizardData, UpdateTicketData, NewStateData, NewBucketData, UpdateTicketDataBucket, UpdateTicketDataAssign, EditTicketData, PreferenceData, DeleteAdapterData, NewFilterData, DeleteFilterData, EditFilterData, DeleteBucketData, UpdateTicketDataAdapter}, UICache, user_interface::SidePanelAction, TagsCache, TagCacheKey};
 
@@ -12,6 +12,12 @@ use self::ticket_actions::TicketAction;
 
 type ImageData = (Vec<u8>, u32, u32);
 
+/**
+   How often the status of the adapters is read again, so adapters,
+   that went out of date without any event, are shown as such
+ */
+const STATUS_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
 #[derive(Clone)]
 pub struct BucketPanelEntry {
     pub label: String,
@@ -23,7 +29,7 @@ pub struct BucketPanelEntry {
 pub mod ticket_actions {
     use std::marker::PhantomData;
 
-    use tickets_rs_core::{Bucket, Ticket, Tag};
+    use tickets_rs_core::{Bucket, Tag, TicketIdentifier};
 
     #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
     pub struct Identifier<ID, NAME> {
@@ -41,21 +47,48 @@ pub mod ticket_actions {
     #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
     pub enum TicketAction {
         NewInBucket(Identifier<u64, Bucket>),
-        Edit(Identifier<i64, Ticket>),
-        Delete(Identifier<i64, Ticket>),
-        UpdateDetails(Identifier<i64, Ticket>),
-        UpdateAssign(Identifier<i64, Ticket>),
-        UpdateState(Identifier<i64, Ticket>),
-        UpdateStateImmediate(Identifier<i64, Ticket>, String), //State_name
-        AddTagImmediate(Identifier<i64, Ticket>, String), //Tag_name
-        RemoveTagImmediate(Identifier<i64, Ticket>, String), //Tag_name
+        Edit(TicketIdentifier),
+        Delete(TicketIdentifier),
+        UpdateDetails(TicketIdentifier),
+        UpdateAssign(TicketIdentifier),
+        UpdateState(TicketIdentifier),
+        UpdateStateImmediate(TicketIdentifier, String), //State_name
+        AddTagImmediate(TicketIdentifier, String), //Tag_name
+        RemoveTagImmediate(TicketIdentifier, String), //Tag_name
         DropTagImmediate(Tag),
         NewTag(Identifier<String, Tag>), 
-        Clone(Identifier<i64, Ticket>),
-        UpdateBucket(Identifier<i64, Ticket>),
-        UpdateAdapter(Identifier<i64, Ticket>),
+        Clone(TicketIdentifier),
+        UpdateBucket(TicketIdentifier),
+        UpdateAdapter(TicketIdentifier),
         None
     }
+
+    impl TicketAction {
+
+        /**
+           Returns the ticket, this action is done on. The ticket needs
+           to be loaded from it's adapter first, before the action can run
+         */
+        pub fn get_ticket(&self) -> Option<&TicketIdentifier> {
+            match self {
+                TicketAction::Edit(id) |
+                TicketAction::Delete(id) |
+                TicketAction::UpdateDetails(id) |
+                TicketAction::UpdateAssign(id) |
+                TicketAction::UpdateState(id) |
+                TicketAction::UpdateStateImmediate(id, _) |
+                TicketAction::AddTagImmediate(id, _) |
+                TicketAction::RemoveTagImmediate(id, _) |
+                TicketAction::Clone(id) |
+                TicketAction::UpdateBucket(id) |
+                TicketAction::UpdateAdapter(id) => Some(id),
+                TicketAction::NewInBucket(_) |
+                TicketAction::DropTagImmediate(_) |
+                TicketAction::NewTag(_) |
+                TicketAction::None => None,
+            }
+        }
+    }
 }
 
 #[derive(PartialEq)]
@@ -72,7 +105,8 @@ pub struct BucketPanelFolder {
     pub label: String,
     pub adapter: String,
     pub entries: Vec<BucketPanelEntry>,
-    pub is_open: bool
+    pub is_open: bool,
+    pub status: Option<AdapterStatus>
 }
 
 pub struct UIController {
@@ -80,6 +114,7 @@ pub struct UIController {
     pub show_sidebar: bool,
     pub font_changed: bool,
     pub ticket_view_mode: TicketViewMode,
+    pub ticket_sort: Option<TicketSort>,
     pub configuration: Arc<Mutex<AppConfig>>, 
     pub ticket_provider: Arc<Mutex<TicketProvider>>,
     pub invalidate_cache: bool,
@@ -87,19 +122,25 @@ pub struct UIController {
     open_folders: Rc<RefCell<Vec<String>>>,
     selected_filters: Rc<RefCell<Vec<BucketPanelLocation>>>,
     visible_tickets: Vec<Ticket>,
+    ticket_request: Option<AdapterRequest<Option<Vec<Ticket>>>>,
+    action_request: Option<(AdapterRequest<Option<Ticket>>, TicketAction)>,
+    comment_request: Option<(AdapterRequest<Vec<Comment>>, TicketIdentifier)>,
     overlay: Overlay,
-    pub update_panel_data: Arc<Mutex<bool>>
+    events: Receiver<ProviderEvent>,
+    panel_update_requested: bool,
+    status_checked: Instant
 }
 
 impl UIController {
 
-    pub fn new(configuration: Arc<Mutex<AppConfig>>, ticket_provider: Arc<Mutex<TicketProvider>>, update_trigger: Arc<Mutex<bool>>) -> Self {
+    pub fn new(configuration: Arc<Mutex<AppConfig>>, ticket_provider: Arc<Mutex<TicketProvider>>, events: Receiver<ProviderEvent>) -> Self {
 
         //show wizard, if config does not contain the wizard flag
 
         let mut overlay = Overlay::None;
         let mut ticket_view_mode = TicketViewMode::Regular;
         let mut show_sidebar = true;
+        let mut ticket_sort = None;
 
         match configuration.lock() {
             Ok(mut lock) => {
@@ -119,6 +160,11 @@ impl UIController {
                     _ => ()
                 }
 
+                let sort_text = lock.get_or_default("ticket:sort", "", "").raw().clone();
+                if !sort_text.is_empty() {
+                    ticket_sort = sort_text.parse::<TicketSort>().ok();
+                }
+
                 match lock.get_or_default("sidebar:enabled", true, "").get::<bool>() {
                     Some(sidebar_enabled) => {
                         show_sidebar = sidebar_enabled;
@@ -134,15 +180,21 @@ impl UIController {
             running: true,
             show_sidebar: show_sidebar,
             ticket_view_mode: ticket_view_mode,
+            ticket_sort,
             configuration: configuration, 
             ticket_provider: ticket_provider,
             bucket_panel: vec![],
             selected_filters: Rc::new(RefCell::new(vec![])),
             visible_tickets: vec![],
+            ticket_request: None,
+            action_request: None,
+            comment_request: None,
             open_folders: Rc::new(RefCell::new(vec![])),
             invalidate_cache: true,
             overlay: overlay,
-            update_panel_data: update_trigger,
+            events,
+            panel_update_requested: false,
+            status_checked: Instant::now(),
         };
 
         controller.update_bucket_panel_data();
@@ -303,6 +355,16 @@ impl UIController {
         results
     }
 
+    pub fn get_capabilities(&self) -> BTreeMap<String, AdapterCapabilities> {
+        match self.ticket_provider.lock() {
+            Ok(lock) => lock.list_adapter_capabilities(),
+            Err(err) => {
+                println!("Wasn't able to open ticket provider for getting adapter capabilities, {err}");
+                BTreeMap::new()
+            },
+        }
+    }
+
     pub fn read_adapter_icons(&self, icons: &mut HashMap<String, Option<ColorImage>>) {
 
         match self.ticket_provider.lock() {
@@ -343,27 +405,102 @@ impl UIController {
         )
     }
 
+    pub fn image_data_as_png(image_data: ImageData) -> Option<Vec<u8>> {
+        let mut png_bytes: Vec<u8> = vec![];
+
+        {
+            let mut encoder = png::Encoder::new(&mut png_bytes, image_data.1, image_data.2);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+
+            let result = encoder.write_header().and_then(|mut writer| writer.write_image_data(&image_data.0));
+            if let Err(err) = result {
+                println!("Couldn't encode image as png, due to {err}");
+                return None;
+            }
+        }
+
+        Some(png_bytes)
+    }
+
     pub fn trigger_bucket_panel_update(&mut self) {
-        if let Ok(mut lock) = self.update_panel_data.lock() {
-            *lock = true;
-        };
+        self.panel_update_requested = true;
     }
 
-    pub fn check_bucket_panel_trigger(&mut self, cache: &mut UICache) {
+    /**
+       Handles all events, the ticket provider published since the last
+       frame. The bucket panel is only rebuilt, if something changed, that
+       it shows, and the tickets are only reloaded, if a changed ticket
+       belongs to an adapter, that is currently selected
+     */
+    pub fn check_provider_events(&mut self, cache: &mut UICache) {
+
+        let mut update_panel = self.panel_update_requested;
+        let mut update_tickets = false;
+        self.panel_update_requested = false;
+
+        while let Ok(event) = self.events.try_recv() {
+            match event {
+                ProviderEvent::TicketCreated(ticket) |
+                ProviderEvent::TicketUpdated(ticket) |
+                ProviderEvent::TicketDeleted(ticket) => {
+                    update_tickets |= self.selected_filters.borrow().iter()
+                        .any(|location| location.adapter == ticket.adapter);
+                },
+                ProviderEvent::AdapterFailed(name, error) => {
+                    println!("Adapter \"{name}\" failed: {error}");
+                    update_panel = true;
+                },
+                event => update_panel |= event.affects_bucket_panel(),
+            }
+        }
+
+        if update_panel {
+            self.update_bucket_panel_data();
+            self.invalidate_cache(Some(cache));
+        }
+
+        if update_tickets {
+            self.execute_bucket_panel_selection();
+        }
 
-        let mut update = false;
+        if !update_panel && self.status_checked.elapsed() > STATUS_CHECK_INTERVAL {
+            self.update_bucket_panel_status();
+        }
+    }
 
-        if let Ok(mut lock) = self.update_panel_data.lock() {
-            if *lock {
-                update = *lock;
-                *lock = false;
+    /**
+       Reads the status of every adapter again, without rebuilding the
+       whole bucket panel
+     */
+    fn update_bucket_panel_status(&mut self) {
+        let status = match self.ticket_provider.lock() {
+            Ok(lock) => lock.list_adapter_status(),
+            Err(err) => {
+                println!("Wasn't able to lock TicketProvider for reading the adapter status! Reason is {err}");
+                return;
             }
         };
 
-        if update {
-            self.update_bucket_panel_data();
-            self.invalidate_cache(Some(cache));
+        for folder in &mut self.bucket_panel {
+            folder.status = status.get(&folder.adapter).cloned();
         }
+
+        self.status_checked = Instant::now();
+    }
+
+    /**
+       Lets the adapter try to reconnect. The panel is updated right away,
+       so the adapter shows as loading
+     */
+    fn retry_adapter(&mut self, adapter_name: &str) {
+        self.using_ticket_provider(|_, provider| {
+            if !provider.adapter_retry(adapter_name) {
+                println!("Wasn't able to retry the \"{adapter_name}\" adapter, because it doesn't exist");
+            }
+        });
+
+        self.panel_update_requested = true;
     }
 
     fn update_bucket_panel_data(&mut self) {
@@ -378,7 +515,8 @@ impl UIController {
                     label: "Filters".to_string(),
                     adapter: "_custom_filters".to_string(),
                     is_open: false,
-                    entries: vec![]
+                    entries: vec![],
+                    status: None
                 };
 
                 adapters_index.insert("_custom_filters".to_string(), custom_filters.clone());
@@ -389,7 +527,8 @@ impl UIController {
                         label: adapter.get_fancy_name(),
                         adapter: adapter.get_name(),
                         is_open: false,
-                        entries: vec![]
+                        entries: vec![],
+                        status: Some(adapter.get_status())
                     };
 
                     for filter in adapter.filter_list_all() {
@@ -420,6 +559,8 @@ impl UIController {
                     self.bucket_panel.push(entry.1);
                 }
 
+                self.status_checked = Instant::now();
+
             },
             Err(err) => println!("Wasn't able to lock TicketProvider! Reason is {err}"),
         };
@@ -438,6 +579,21 @@ impl UIController {
 
         let max_width = ui.available_width() - (theme.font_size as f32) * 1.5 ;
 
+        // Tickets and comments arrive in the background, so keep repainting until they're there
+        if self.check_action_request(cache) | self.check_comment_request() {
+            ui.ctx().request_repaint_after(Duration::from_millis(100));
+        }
+
+        // Keep showing the previous tickets, until the new ones arrived
+        if self.check_ticket_request() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Loading Tickets...");
+            });
+            ui.add_space(4.0);
+            ui.ctx().request_repaint_after(Duration::from_millis(100));
+        }
+
         for ticket in &self.visible_tickets {
             let ticket_icon = UserInterface::load_texture(icon_textures, icons, ui, &ticket.adapter);
             
@@ -476,174 +632,166 @@ impl UIController {
 
                 self.open_overlay(self.create_new_ticket_overlay(Some(ticket)));
             },
+            TicketAction::NewTag(id) => {
 
-            TicketAction::Delete(id) => {
-                self.using_ticket_provider_mut(|controller, provider| {
+                let mut tag = Tag::default()
+                    .with_name(id.id)
+                    .with_random_colors();
+                tag.adapter = id.adapter;
 
-                    if let Some(ticket_ref) = provider.ticket_list_unique(id.id, &id.adapter) {
-                        controller.open_overlay(Overlay::DeleteTicket(UpdateTicketData {
-                            ticket: ticket_ref,
-                            ..Default::default()
-                        }));
-                    }
-                });
+                self.open_overlay(self.create_new_tag_overlay(Some(tag)));
             },
-            TicketAction::UpdateDetails(id) => {
-                self.using_ticket_provider_mut(|controller, provider| {
-
-                    if let Some(ticket_ref) = provider.ticket_list_unique(id.id, &id.adapter) {
-                        controller.open_overlay(Overlay::UpdateTicketDetails(UpdateTicketData {
-                            ticket: ticket_ref,
-                            ..Default::default()
-                        }));
-                    }
-                });
+            TicketAction::DropTagImmediate(tag_ref) => {
+                OverlayAction::DeleteTag(tag_ref).execute(self, cache);
             },
-            TicketAction::UpdateAssign(id) => {
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|controller, provider| {
-                     ticket_ref = match provider.ticket_list_unique(id.id, &id.adapter) {
-                        Some(mut ticket_ref) => {
-                            Some(ticket_ref)
-                        },
-                        None => None,
-                    };
-                });
+            TicketAction::None => (),
 
-                if let Some(ticket) = ticket_ref {
-                    self.open_overlay(self.create_ticket_assign_overlay(Some(ticket)));
-                }
-            },
-            TicketAction::UpdateState(id) => {
-                self.using_ticket_provider_mut(|controller, provider| {
+            // Every other action needs the current ticket, which is loaded in the background
+            action => self.request_ticket_action(action),
+        };
 
-                    if let Some(ticket_ref) = provider.ticket_list_unique(id.id, &id.adapter) {
-                        controller.open_overlay(Overlay::UpdateTicketState(UpdateTicketData {
-                            ticket: ticket_ref,
-                            ..Default::default()
-                        }));
-                    }
-                });
-            },
-            TicketAction::AddTagImmediate(id, tag) => {
-
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|controller, provider| {
-                     ticket_ref = match provider.ticket_list_unique(id.id, &id.adapter) {
-                        Some(mut ticket_ref) => {
-                            ticket_ref.tags.push(tag);
-                            Some(ticket_ref)
-                        },
-                        None => None,
-                    };
-                });
+    }
 
-                if let Some(ticket) = ticket_ref {
-                    OverlayAction::UpdateTicket(ticket).execute(self, cache);
-                }
+    /**
+       Loads the ticket of the given action in the background. The action
+       runs, once the ticket arrived, see check_action_request. A newer
+       action replaces the one, that is still waiting
+     */
+    fn request_ticket_action(&mut self, action: TicketAction) {
+        self.using_ticket_provider_mut(|controller, provider| {
+            if let Some(id) = action.get_ticket() {
+                controller.action_request = Some((provider.ticket_list_unique_async(id), action));
+            }
+        });
+    }
 
-            },
-            TicketAction::RemoveTagImmediate(id, tag) => {
-
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|controller, provider| {
-                     ticket_ref = match provider.ticket_list_unique(id.id, &id.adapter) {
-                        Some(mut ticket_ref) => {
-                            ticket_ref.tags.retain(|curr_tag| tag.ne(curr_tag));
-                            Some(ticket_ref)
-                        },
-                        None => None,
-                    };
-                });
+    /**
+       Runs the waiting action, once it's ticket arrived.
+       Returns true, if the ticket is still loading
+     */
+    pub fn check_action_request(&mut self, cache: &mut UICache) -> bool {
+        let Some((request, _)) = &self.action_request else {
+            return false;
+        };
 
-                if let Some(ticket) = ticket_ref {
-                    OverlayAction::UpdateTicket(ticket).execute(self, cache);
-                }
+        let Some(result) = request.poll() else {
+            return true;
+        };
 
-            },
-            TicketAction::NewTag(id) => {
+        let Some((_, action)) = self.action_request.take() else {
+            return false;
+        };
 
-                let mut tag = Tag::default()
-                    .with_name(id.id)
-                    .with_random_colors();
-                tag.adapter = id.adapter;
+        match result {
+            Ok(Some(ticket)) => self.execute_ticket_action(action, ticket, cache),
+            Ok(None) => println!("Wasn't able to find the ticket of {:?}", action),
+            Err(err) => println!("Wasn't able to load the ticket of {:?} due to {}", action, err),
+        }
 
-                self.open_overlay(self.create_new_tag_overlay(Some(tag)));
-            },
-            TicketAction::Clone(id) => {
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|controller, provider| {
-                     ticket_ref = match provider.ticket_list_unique(id.id, &id.adapter) {
-                        Some(mut ticket_ref) => {
-                            ticket_ref.id = 0;
-                            ticket_ref.title += " (Clone)";
-                            Some(ticket_ref)
-                        },
-                        None => None,
-                    };
-                });
+        false
+    }
 
-                if let Some(ticket) = ticket_ref {
-                    self.open_overlay(self.create_new_ticket_overlay(Some(ticket)));
-                }
-                
+    fn execute_ticket_action(&mut self, action: TicketAction, mut ticket: Ticket, cache: &mut UICache) {
+        match action {
+            TicketAction::Delete(_) => {
+                self.open_overlay(Overlay::DeleteTicket(UpdateTicketData {
+                    ticket,
+                    ..Default::default()
+                }));
             },
-            TicketAction::UpdateBucket(id) => {
-                self.using_ticket_provider_mut(|controller, provider| {
-
-                    let buckets: Vec<Bucket> = vec![];
-                    if let Some(ticket_ref) = provider.ticket_list_unique(id.id, &id.adapter) {
-                        controller.open_overlay(Overlay::UpdateTicketBucket(UpdateTicketDataBucket {
-                            buckets: provider.bucket_list_all(),
-                            ticket: ticket_ref,
-                            ..Default::default()
-                        }));
-                    }
-                });
+            TicketAction::UpdateDetails(_) => {
+                self.open_overlay(Overlay::UpdateTicketDetails(UpdateTicketData {
+                    ticket,
+                    ..Default::default()
+                }));
+            },
+            TicketAction::UpdateAssign(_) => {
+                self.open_overlay(self.create_ticket_assign_overlay(Some(ticket)));
+            },
+            TicketAction::UpdateState(_) => {
+                self.open_overlay(Overlay::UpdateTicketState(UpdateTicketData {
+                    ticket,
+                    ..Default::default()
+                }));
             },
-            TicketAction::UpdateAdapter(id) => {
-                let mut ticket_ref: Option<Ticket> = None;
+            TicketAction::AddTagImmediate(_, tag) => {
+                ticket.tags.push(tag);
+                OverlayAction::UpdateTicket(ticket).execute(self, cache);
+            },
+            TicketAction::RemoveTagImmediate(_, tag) => {
+                ticket.tags.retain(|curr_tag| tag.ne(curr_tag));
+                OverlayAction::UpdateTicket(ticket).execute(self, cache);
+            },
+            TicketAction::Clone(_) => {
+                ticket.id = 0;
+                ticket.title += " (Clone)";
+                self.open_overlay(self.create_new_ticket_overlay(Some(ticket)));
+            },
+            TicketAction::UpdateBucket(_) => {
+                let mut buckets: Vec<Bucket> = vec![];
                 self.using_ticket_provider(|_, provider| {
-                     ticket_ref = provider.ticket_list_unique(id.id, &id.adapter);
+                    buckets = provider.bucket_list_all();
                 });
 
-                if let Some(ticket) = ticket_ref {
-                    self.open_overlay(self.create_edit_ticket_adapter_overlay(ticket));
-                }
+                self.open_overlay(Overlay::UpdateTicketBucket(UpdateTicketDataBucket {
+                    buckets,
+                    ticket,
+                    ..Default::default()
+                }));
             },
-            TicketAction::None => (),
-            TicketAction::UpdateStateImmediate(id, state) => {
-
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|controller, provider| {
-                     ticket_ref = match provider.ticket_list_unique(id.id, &id.adapter) {
-                        Some(mut ticket_ref) => {
-                            ticket_ref.state_name = state;
-                            Some(ticket_ref)
-                        },
-                        None => None,
-                    };
-                });
-
-                if let Some(ticket) = ticket_ref {
-                    OverlayAction::UpdateTicket(ticket).execute(self, cache);
-                }
+            TicketAction::UpdateAdapter(_) => {
+                self.open_overlay(self.create_edit_ticket_adapter_overlay(ticket));
             },
-            TicketAction::DropTagImmediate(tag_ref) => {
-                OverlayAction::DeleteTag(tag_ref).execute(self, cache);
+            TicketAction::UpdateStateImmediate(_, state) => {
+                ticket.state_name = state;
+                OverlayAction::UpdateTicket(ticket).execute(self, cache);
             },
-            TicketAction::Edit(id) => {
-                let mut ticket_ref: Option<Ticket> = None;
-                self.using_ticket_provider(|_, provider| {
-                     ticket_ref = provider.ticket_list_unique(id.id, &id.adapter);
+            TicketAction::Edit(_) => {
+                self.using_ticket_provider_mut(|controller, provider| {
+                    controller.request_comments(provider, &ticket);
                 });
-
-                if let Some(ticket) = ticket_ref {
-                    self.open_overlay(self.create_edit_ticket_overlay(Some(ticket)));
-                }
+                self.open_overlay(self.create_edit_ticket_overlay(Some(ticket)));
             },
+            _ => (),
+        }
+    }
+
+    /**
+       Loads the comments of the ticket in the background. They are put
+       into the edit overlay of the ticket, once they arrived
+     */
+    pub fn request_comments(&mut self, provider: &TicketProvider, ticket: &Ticket) {
+        self.comment_request = Some((provider.comment_list_async(ticket), ticket.get_identifier()));
+    }
+
+    /**
+       Applies the comments of the running request, once they arrived.
+       Returns true, if the comments are still loading
+     */
+    pub fn check_comment_request(&mut self) -> bool {
+        let Some((request, _)) = &self.comment_request else {
+            return false;
         };
 
+        let Some(result) = request.poll() else {
+            return true;
+        };
+
+        let Some((_, identifier)) = self.comment_request.take() else {
+            return false;
+        };
+
+        if let Overlay::EditTicket(ticket_data) = self.get_current_overlay() {
+            if ticket_data.ticket.get_identifier() == identifier {
+                match result {
+                    Ok(comments) => ticket_data.comments = comments,
+                    Err(err) => ticket_data.errors = err.get_errors(),
+                }
+                ticket_data.comments_loading = false;
+            }
+        }
+
+        false
     }
 
     pub fn create_ticket_assign_overlay(&self, ticket: Option<Ticket>) -> Overlay {
@@ -682,8 +830,16 @@ impl UIController {
         };
 
         let mut buckets: Vec<Bucket> = vec![];
+        let mut history: Vec<HistoryEntry> = vec![];
+        let mut custom_fields: Vec<CustomField> = vec![];
+        let mut attachments: Vec<Attachment> = vec![];
+        let mut work_logs: Vec<WorkLog> = vec![];
         self.using_ticket_provider(|_, provider| {
             buckets = provider.bucket_list_all();
+            custom_fields = provider.custom_field_list(&ticket.adapter);
+            attachments = provider.attachment_list(&ticket);
+            work_logs = provider.work_log_list(&ticket);
+            history = provider.ticket_history(&ticket.get_identifier());
         });
 
         let due_date = match Utc.timestamp_millis_opt(ticket.due_at) {
@@ -692,13 +848,29 @@ impl UIController {
             chrono::LocalResult::Ambiguous(_, result) => result,
         };
 
-        Overlay::EditTicket(EditTicketData{
+        let mut ticket_data = EditTicketData{
             username,
             buckets,
+            custom_fields,
+            attachments,
+            estimate_text: match ticket.estimate {
+                0 => String::default(),
+                estimate => WorkLog::format_duration(estimate)
+            },
+            work_logs,
+            comments_loading: true,
+            history,
+            recurrence_text: ticket.recurrence.as_ref().map(|recurrence| recurrence.to_text()).unwrap_or_default(),
             ticket,
             due_date,
             ..Default::default()
-        })
+        };
+
+        self.using_ticket_provider(|_, provider| {
+            ticket_data.refresh_links(provider);
+        });
+
+        Overlay::EditTicket(Box::new(ticket_data))
     }
 
     pub fn create_edit_ticket_adapter_overlay(&self, ticket: Ticket) -> Overlay {
@@ -848,6 +1020,7 @@ impl UIController {
         
         let mut found: Option<BucketPanelLocation> = None;
         let mut overlay = Overlay::None;
+        let mut retry: Option<String> = None;
         
         for folder in &self.bucket_panel {
 
@@ -928,6 +1101,7 @@ impl UIController {
                         adapter_name: folder.adapter.clone(),
                     });
                 },
+                SidePanelAction::FolderRetry => retry = Some(folder.adapter.clone()),
                 _ => (),
                 
             }
@@ -990,6 +1164,10 @@ impl UIController {
             self.open_overlay(overlay);
         }
 
+        if let Some(adapter_name) = retry {
+            self.retry_adapter(&adapter_name);
+        }
+
         if UserInterface::update_side_panel_space(ui) {
             found = Some(BucketPanelLocation {
                 entry_type: BucketPanelLocationType::Reset,
@@ -1059,14 +1237,38 @@ impl UIController {
 
     pub fn execute_bucket_panel_selection(&mut self) {
 
+        // A newer selection replaces the running request, so it's result is discarded
         self.using_ticket_provider_mut(|controller, provider| {
-            let tickets = provider.ticket_list_from_selection(&controller.selected_filters.borrow());
-
-            if let Some(mut tickets) = tickets {
-                controller.visible_tickets.clear();
-                controller.visible_tickets.append(&mut tickets);
-            };
+            let locations = controller.selected_filters.borrow().clone();
+            controller.ticket_request = Some(provider.ticket_list_from_selection_async(locations, controller.ticket_sort.clone()));
         });
     }
 
+    /**
+       Applies the tickets of the running request, once they arrived.
+       Returns true, if the request is still loading
+     */
+    pub fn check_ticket_request(&mut self) -> bool {
+        let Some(request) = &self.ticket_request else {
+            return false;
+        };
+
+        match request.poll() {
+            Some(tickets) => {
+                match tickets {
+                    Ok(Some(mut tickets)) => {
+                        self.visible_tickets.clear();
+                        self.visible_tickets.append(&mut tickets);
+                    },
+                    Ok(None) => (),
+                    Err(err) => println!("Wasn't able to load tickets due to {}", err),
+                };
+
+                self.ticket_request = None;
+                false
+            },
+            None => true,
+        }
+    }
+
 }
\ No newline at end of file
@@ -7,6 +7,8 @@ use eframe::egui::{
     menu
 };
 
+use tickets_rs_core::{TicketSort, TicketSortKey};
+
 use crate::{
     UserInterface, 
     ui_controller::TicketViewMode, 
@@ -151,6 +153,41 @@ impl UserInterface {
 
                     ui.close_menu();
                 }
+
+                ui.separator();
+
+                ui.menu_button("Sort Tickets", |ui| {
+
+                    let sort_choices = [
+                        ("As defined by the Filter", None),
+                        ("By Priority", Some(TicketSort::default())),
+                        ("By Due Date", Some(TicketSort::new().ascending(TicketSortKey::DueDate).descending(TicketSortKey::Priority))),
+                        ("By Creation Date", Some(TicketSort::new().descending(TicketSortKey::CreatedDate))),
+                        ("By State", Some(TicketSort::new().ascending(TicketSortKey::State).descending(TicketSortKey::Priority))),
+                        ("By Title", Some(TicketSort::new().ascending(TicketSortKey::Title))),
+                        ("By Assignee", Some(TicketSort::new().ascending(TicketSortKey::Assignee).descending(TicketSortKey::Priority))),
+                        ("By Adapter", Some(TicketSort::new().ascending(TicketSortKey::Adapter).descending(TicketSortKey::Priority))),
+                    ];
+
+                    let mut sort_changed = false;
+                    for (label, sort) in sort_choices {
+                        sort_changed |= ui.radio_value(&mut self.ui_controller.ticket_sort, sort, label).clicked();
+                    }
+
+                    if sort_changed {
+
+                        match self.ui_controller.configuration.lock(){
+                            Ok(mut lock) => {
+                                let sort_text = self.ui_controller.ticket_sort.as_ref().map(|sort| sort.to_string()).unwrap_or_default();
+                                lock.put("ticket:sort", sort_text, "");
+                            },
+                            Err(err) => println!("Wasn't able to lock Config, when choosing a sort order, due to {err}"),
+                        }
+
+                        self.ui_controller.execute_bucket_panel_selection();
+                        ui.close_menu();
+                    }
+                });
             });
         
 
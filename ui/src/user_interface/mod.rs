@@ -36,7 +36,7 @@ use eframe::egui::{
 use crate::{
     UIController, 
     UITheme, 
-    Overlay, overlays::OverlayAction, UICache
+    Overlay, overlays::OverlayAction, UICache, AttachmentLoader
 };
 
 pub use side_panel::SidePanelAction;
@@ -68,7 +68,7 @@ impl UserInterface {
             maximized: false,
             decorated: true,
             fullscreen: false,
-            drag_and_drop_support: false,
+            drag_and_drop_support: true,
             icon_data: icon,
             //icon_data: None,
             initial_window_pos: None,
@@ -97,7 +97,10 @@ impl UserInterface {
         eframe::run_native(
             "tickets.rs - A ticket Management App",
             options,
-            Box::new(|_cc| Box::new(UserInterface::new(ui_controller, ui_theme))),
+            Box::new(|cc| {
+                cc.egui_ctx.add_bytes_loader(Arc::new(AttachmentLoader::new(ui_controller.ticket_provider.clone())));
+                Box::new(UserInterface::new(ui_controller, ui_theme))
+            }),
         );
     }
 
@@ -170,6 +173,7 @@ impl eframe::App for UserInterface {
             self.ui_controller.on_close_ui(&self.ui_theme, frame);
         };
 
+        self.ui_controller.check_provider_events(&mut self.cache);
         self.cache.refresh_cache(&mut self.ui_controller);
 
         let no_color =  Color32::from_rgba_unmultiplied(0, 0, 0, 0);
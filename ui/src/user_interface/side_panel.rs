@@ -5,7 +5,8 @@ use eframe::egui::{
     RichText, 
     Button, 
     ScrollArea};
-use tickets_rs_core::FilterType;
+use chrono::{TimeZone, Utc};
+use tickets_rs_core::{FilterType, AdapterState, AdapterStatus};
 
 use crate::{
     UserInterface, 
@@ -25,6 +26,7 @@ pub enum SidePanelAction {
     FolderNewTicket,
     FolderNewState,
     FolderRemove,
+    FolderRetry,
     EntryClicked,
     EntryRemove,
     EntryEdit,
@@ -47,7 +49,14 @@ impl UserInterface {
 
 
             if selectable {
-                ui.add_sized([4.0, 16.0], Label::new(""));
+                if let Some(status) = &folder.status {
+                    if UserInterface::update_side_panel_status(ui, ui_theme, status) {
+                        action = SidePanelAction::FolderRetry;
+                    }
+                } else {
+                    ui.add_sized([4.0, 16.0], Label::new(""));
+                }
+
                 let button = SelectableLabel::new(is_selected, RichText::new(&folder.label).color(ui_theme.foreground_marker2));
                 let mut response = ui.add(button);
 
@@ -84,6 +93,11 @@ impl UserInterface {
 
                     ui.separator();
 
+                    if ui.button("Retry Connection").clicked() {
+                        ui.close_menu();
+                        action = SidePanelAction::FolderRetry
+                    };
+
                     if ui.button(RichText::new("Remove this Adapter").color(ui_theme.foreground_marker2)).clicked() {
                         ui.close_menu();
                         action = SidePanelAction::FolderRemove
@@ -117,6 +131,41 @@ impl UserInterface {
         }).inner
     }
 
+    /**
+       Shows the status of an adapter as a small symbol, with the details on hover.
+       Returns true, if the user clicked it to retry a failed or outdated adapter
+     */
+    pub(crate) fn update_side_panel_status(ui: &mut Ui, ui_theme: &UITheme, status: &AdapterStatus) -> bool {
+
+        let (symbol, color) = match status.state {
+            AdapterState::Connected => ("●", ui_theme.foreground_tertiary),
+            AdapterState::Loading => ("◌", ui_theme.foreground_secondary),
+            AdapterState::Stale => ("⟳", ui_theme.foreground_marker),
+            AdapterState::Error => ("⚠", ui_theme.background_error),
+        };
+
+        let mut details = status.state.get_text();
+
+        match status.last_sync.and_then(|last_sync| Utc.timestamp_opt(last_sync, 0).single()) {
+            Some(last_sync) => details += format!("\nLast synchronized: {}", last_sync.format("%Y-%m-%d %H:%M")).as_str(),
+            None => details += "\nNot synchronized yet",
+        }
+
+        if let Some(error) = &status.last_error {
+            details += format!("\n{}", error.get_text()).as_str();
+        }
+
+        let can_retry = matches!(status.state, AdapterState::Stale | AdapterState::Error);
+        if can_retry {
+            details += "\nClick to retry.";
+        }
+
+        let response = ui.add_sized([16.0, 16.0], Label::new(RichText::new(symbol).color(color)).sense(egui::Sense::click()))
+            .on_hover_text_at_pointer(details);
+
+        can_retry && response.clicked()
+    }
+
     pub(crate) fn update_side_panel_entry(ui: &mut Ui, ui_theme: &UITheme, is_selected: bool, entry: &BucketPanelEntry) -> SidePanelAction {
 
         let mut action = SidePanelAction::Nothing;
@@ -184,8 +233,6 @@ impl UserInterface {
         let controller = &mut self.ui_controller;
         let ui_theme = &self.ui_theme;
 
-        controller.check_bucket_panel_trigger(&mut self.cache);
-
         ScrollArea::vertical().show(ui, |ui| {
 
             ui.spacing_mut().item_spacing.x = 0.0;
@@ -17,7 +17,7 @@ use egui_commonmark::{
     CommonMarkViewer
 };
 
-use tickets_rs_core::{Ticket, Tag, StateIdentifier};
+use tickets_rs_core::{Ticket, Tag, StateIdentifier, Priority, AdapterCapability};
 
 use crate::{
     UserInterface, 
@@ -39,43 +39,51 @@ impl UserInterface {
         cache: &UICache
     ) {
         let font_size = theme.font_size as f32;
+        let capabilities = cache.get_capabilities(&ticket.adapter);
+        let can_write = capabilities.supports(AdapterCapability::TicketWrite);
+        let can_delete = capabilities.supports(AdapterCapability::TicketDelete);
 
         response.context_menu(|ui| {
 
-            if ui.button("Edit Details...")
+            if capabilities.is_read_only() {
+                ui.label(RichText::new("This Adapter is read only.").color(theme.foreground_secondary));
+                ui.separator();
+            }
+
+            if ui.add_enabled(can_write, Button::new("Edit Details..."))
                 .on_hover_text_at_pointer("Edit the title and the description of the right-clicked Ticket. Note that the description supports some sort of markdown")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::UpdateDetails(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::UpdateDetails(ticket.get_identifier());
                 };
 
-            if ui.button("Assign to...")
+            if ui.add_enabled(can_write, Button::new("Assign to..."))
                 .on_hover_text_at_pointer("Edit the assigned participants for the right-clicked Ticket.")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::UpdateAssign(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::UpdateAssign(ticket.get_identifier());
                 };
             
             ui.separator();
 
-            ui.menu_button("Change State", |ui| {
+            ui.add_enabled_ui(can_write, |ui| ui.menu_button("Change State", |ui| {
                 for state in cache.states.keys() {
                     if ticket.state_name.eq(&state.name) && ticket.adapter.eq(&state.adapter) {
                         ui.button(RichText::new(state.name.clone()).strong().color(theme.foreground_marker2));
                     } else if ui.button(RichText::new(state.name.clone()).color(theme.foreground_secondary)).clicked() {
                         ui.close_menu();
-                        *action = TicketAction::UpdateStateImmediate(Identifier::new(&ticket.adapter, ticket.id), state.name.clone());
+                        *action = TicketAction::UpdateStateImmediate(ticket.get_identifier(), state.name.clone());
                     }
                     
                 };
-            }).response.on_hover_text_at_pointer("Change the state of the right-clicked Ticket.");
+            }).response.on_hover_text_at_pointer("Change the state of the right-clicked Ticket."));
 
-            ui.menu_button("Add tags", |ui| {
+            ui.add_enabled_ui(can_write, |ui| ui.menu_button("Add tags", |ui| {
 
                 for tag in &ticket.tags {
                     if ui.button(RichText::new("🗙 ".to_owned() + tag.as_str()).color(theme.foreground_marker2)).clicked() {
                         ui.close_menu();
-                        *action = TicketAction::RemoveTagImmediate(Identifier::new(&ticket.adapter, ticket.id), tag.clone());
+                        *action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                     };
                 }
 
@@ -88,7 +96,7 @@ impl UserInterface {
                         && ui.button(RichText::new(tag.name.clone()).color(theme.foreground_secondary))
                         .clicked() {
                         ui.close_menu();
-                        *action = TicketAction::AddTagImmediate(Identifier::new(&ticket.adapter, ticket.id), tag.name.clone());
+                        *action = TicketAction::AddTagImmediate(ticket.get_identifier(), tag.name.clone());
                     }
                 };
 
@@ -103,7 +111,7 @@ impl UserInterface {
                         && ui.button(RichText::new(tag.name.clone()).color(theme.foreground_secondary))
                         .clicked() {
                             ui.close_menu();
-                            *action = TicketAction::AddTagImmediate(Identifier::new(&ticket.adapter, ticket.id), tag.name.clone());
+                            *action = TicketAction::AddTagImmediate(ticket.get_identifier(), tag.name.clone());
                         }
                     };
                 });
@@ -111,43 +119,43 @@ impl UserInterface {
                 ui.add_space(font_size / 2.0);
 
                 ui.text_edit_singleline(&mut "custom_tag");
-            }).response.on_hover_text_at_pointer("Add or remove Tags on the right-clicked Ticket. Add a custom Tag in the input at the bottom.");
+            }).response.on_hover_text_at_pointer("Add or remove Tags on the right-clicked Ticket. Add a custom Tag in the input at the bottom."));
 
             ui.separator();
 
-            if ui.button("Move to Bucket...")
+            if ui.add_enabled(can_write, Button::new("Move to Bucket..."))
                 .on_hover_text_at_pointer("Moves the right-clicked Ticket to a different Bucket within the Ticket Adapter.")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::UpdateBucket(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::UpdateBucket(ticket.get_identifier());
                 };
 
-            if ui.button("Move to Adapter...")
+            if ui.add_enabled(can_delete, Button::new("Move to Adapter..."))
                 .on_hover_text_at_pointer("Moves the right-clicked Ticket to an entirely different Ticket Adapter.")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::UpdateAdapter(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::UpdateAdapter(ticket.get_identifier());
                 };
 
             if ui.button("Clone...")
                 .on_hover_text_at_pointer("Clone the right-clicked Ticket, and show edit Window.")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::Clone(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::Clone(ticket.get_identifier());
                 };
 
-            if ui.button("New with Bucket...")
+            if ui.add_enabled(can_write, Button::new("New with Bucket..."))
                 .on_hover_text_at_pointer("Creates a new Ticket with the same Bucket and Adapter as the right-clicked Ticket.")
                 .clicked() {
                     ui.close_menu();
                     *action = TicketAction::NewInBucket(Identifier::new(&ticket.adapter, ticket.bucket_id));
                 };
 
-            if ui.button(RichText::new("Delete Ticket").color(theme.foreground_marker2))
+            if ui.add_enabled(can_delete, Button::new(RichText::new("Delete Ticket").color(theme.foreground_marker2)))
                 .on_hover_text_at_pointer("Delete the right-clicked Ticket.")
                 .clicked() {
                     ui.close_menu();
-                    *action = TicketAction::Delete(Identifier::new(&ticket.adapter, ticket.id));
+                    *action = TicketAction::Delete(ticket.get_identifier());
                 };
         });
     }
@@ -166,6 +174,10 @@ impl UserInterface {
 
         let mut action = TicketAction::None;
 
+        let capabilities = cache.get_capabilities(&ticket.adapter);
+        let can_write = capabilities.supports(AdapterCapability::TicketWrite);
+        let can_change_tags = capabilities.supports(AdapterCapability::Tags);
+
         let half_font = theme.font_size as f32 / 2.0;
         let font_size = theme.font_size as f32;
 
@@ -202,10 +214,10 @@ impl UserInterface {
                     let state_button = Button::new(RichText::new(&ticket.state_name).strong().size(font_size).color(title_color));
 
                     if match cache.states.get(&StateIdentifier::new(&ticket.adapter, &ticket.state_name)) {
-                        Some(description) => ui.add(state_button).on_hover_text_at_pointer(description),
-                        None => ui.add(state_button),
+                        Some(description) => ui.add_enabled(can_write, state_button).on_hover_text_at_pointer(description),
+                        None => ui.add_enabled(can_write, state_button),
                     }.clicked() {
-                        action = TicketAction::UpdateState(Identifier::new(&ticket.adapter, ticket.id));
+                        action = TicketAction::UpdateState(ticket.get_identifier());
                     };
 
                     ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
@@ -213,6 +225,30 @@ impl UserInterface {
                         if let Some(datetime) = datetime {
                             ui.add(Label::new(format!("Due at {}", datetime.date_naive())).wrap(false));
                         };
+
+                        if let Some((done, total)) = ticket.checklist_progress() {
+                            ui.add_space(half_font);
+                            ui.add(Label::new(format!("☑ {}/{}", done, total)).wrap(false))
+                                .on_hover_text_at_pointer("Finished Items of the Checklist.");
+                        };
+
+                        if ticket.priority != Priority::Normal {
+                            let marker = match ticket.priority {
+                                Priority::Low => RichText::new("⬇ low"),
+                                Priority::High => RichText::new("⬆ high").color(theme.foreground_marker2),
+                                _ => RichText::new("⬆ critical").strong().color(theme.foreground_marker2),
+                            };
+
+                            ui.add_space(half_font);
+                            ui.add(Label::new(marker).wrap(false))
+                                .on_hover_text_at_pointer("Priority of the Ticket.");
+                        };
+
+                        if let Some(recurrence) = &ticket.recurrence {
+                            ui.add_space(half_font);
+                            ui.add(Label::new("🔁").wrap(false))
+                                .on_hover_text_at_pointer(format!("Repeats: {}", recurrence.to_text()));
+                        };
                     });
 
                     ui.add_space(half_font);
@@ -224,7 +260,7 @@ impl UserInterface {
                         .frame(false))
                         .on_hover_text_at_pointer("Double-click title to edit Ticket.")
                         .double_clicked() {
-                            action = TicketAction::Edit(Identifier::new(ticket.adapter.clone(), ticket.id));
+                            action = TicketAction::Edit(ticket.get_identifier());
                         };
 
                     for tag in &ticket.tags {
@@ -233,12 +269,12 @@ impl UserInterface {
                                 let mut tag_button = Button::new(RichText::new("⏺").color(tag_colors[1]));
                                 tag_button = tag_button.fill(tag_colors[0]);
                                 ui.add(tag_button).on_hover_text_at_pointer(tag).context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Remove from Adapter").color(theme.foreground_marker2)).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new(RichText::new("Remove from Adapter").color(theme.foreground_marker2))).clicked() {
 
                                         let mut tag_ref = Tag::default().with_name(tag.clone());
                                         tag_ref.adapter = ticket.adapter.clone();
@@ -250,17 +286,17 @@ impl UserInterface {
                             },
                             None => {
                                 let response = ui.button("⏺").on_hover_text_at_pointer(tag.to_string() + "; Click to add to Adapter");
-                                if response.clicked() {
+                                if response.clicked() && can_change_tags {
                                     action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                 };
 
                                 response.context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Add to Adapter")).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new("Add to Adapter")).clicked() {
                                         action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                         ui.close_menu();
                                     };
@@ -296,6 +332,10 @@ impl UserInterface {
 
         let mut action = TicketAction::None;
 
+        let capabilities = cache.get_capabilities(&ticket.adapter);
+        let can_write = capabilities.supports(AdapterCapability::TicketWrite);
+        let can_change_tags = capabilities.supports(AdapterCapability::Tags);
+
         let mut main_group = Frame::group(ui.style());
 
         let mut ticket_background = theme.background_secondary;
@@ -331,10 +371,10 @@ impl UserInterface {
                     let state_button = Button::new(RichText::new(&ticket.state_name).strong().size(font_size).color(title_color));
 
                     if match cache.states.get(&StateIdentifier::new(&ticket.adapter, &ticket.state_name)) {
-                        Some(description) => ui.add(state_button).on_hover_text_at_pointer(description),
-                        None => ui.add(state_button),
+                        Some(description) => ui.add_enabled(can_write, state_button).on_hover_text_at_pointer(description),
+                        None => ui.add_enabled(can_write, state_button),
                     }.clicked() {
-                        action = TicketAction::UpdateState(Identifier::new(&ticket.adapter, ticket.id));
+                        action = TicketAction::UpdateState(ticket.get_identifier());
                     };
 
                     ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
@@ -342,6 +382,30 @@ impl UserInterface {
                         if let Some(datetime) = datetime {
                             ui.label(format!("Due at {}", datetime.date_naive()));
                         };
+
+                        if let Some((done, total)) = ticket.checklist_progress() {
+                            ui.add_space(half_font);
+                            ui.add(Label::new(format!("☑ {}/{}", done, total)).wrap(false))
+                                .on_hover_text_at_pointer("Finished Items of the Checklist.");
+                        };
+
+                        if ticket.priority != Priority::Normal {
+                            let marker = match ticket.priority {
+                                Priority::Low => RichText::new("⬇ low"),
+                                Priority::High => RichText::new("⬆ high").color(theme.foreground_marker2),
+                                _ => RichText::new("⬆ critical").strong().color(theme.foreground_marker2),
+                            };
+
+                            ui.add_space(half_font);
+                            ui.add(Label::new(marker).wrap(false))
+                                .on_hover_text_at_pointer("Priority of the Ticket.");
+                        };
+
+                        if let Some(recurrence) = &ticket.recurrence {
+                            ui.add_space(half_font);
+                            ui.add(Label::new("🔁").wrap(false))
+                                .on_hover_text_at_pointer(format!("Repeats: {}", recurrence.to_text()));
+                        };
                         
                     });
 
@@ -354,7 +418,7 @@ impl UserInterface {
                         .frame(false))
                         .on_hover_text_at_pointer("Double-click title to edit Ticket.")
                         .double_clicked() {
-                            action = TicketAction::Edit(Identifier::new(ticket.adapter.clone(), ticket.id));
+                            action = TicketAction::Edit(ticket.get_identifier());
                         };
                 });
                 
@@ -377,12 +441,12 @@ impl UserInterface {
 
                                 tag_button = tag_button.fill(tag_colors[0]);
                                 ui.add(tag_button).on_hover_text_at_pointer(tag).context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Remove from Adapter").color(theme.foreground_marker2)).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new(RichText::new("Remove from Adapter").color(theme.foreground_marker2))).clicked() {
 
                                         let mut tag_ref = Tag::default().with_name(tag.clone());
                                         tag_ref.adapter = ticket.adapter.clone();
@@ -394,17 +458,17 @@ impl UserInterface {
                             },
                             None => {
                                 let response = ui.button(tag).on_hover_text_at_pointer("Click to add to Adapter");
-                                if response.clicked() {
+                                if response.clicked() && can_change_tags {
                                     action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                 };
 
                                 response.context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Add to Adapter")).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new("Add to Adapter")).clicked() {
                                         action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                         ui.close_menu();
                                     };
@@ -441,6 +505,10 @@ impl UserInterface {
 
         let mut action = TicketAction::None;
 
+        let capabilities = cache.get_capabilities(&ticket.adapter);
+        let can_write = capabilities.supports(AdapterCapability::TicketWrite);
+        let can_change_tags = capabilities.supports(AdapterCapability::Tags);
+
         let mut main_group = Frame::group(ui.style());
         let mut ticket_background = theme.background_secondary;
         let mut title_color = theme.foreground_primary;
@@ -477,10 +545,10 @@ impl UserInterface {
                     let state_button = Button::new(RichText::new(&ticket.state_name).strong().size(heading_size).color(title_color));
 
                     if match cache.states.get(&StateIdentifier::new(&ticket.adapter, &ticket.state_name)) {
-                        Some(description) => ui.add(state_button).on_hover_text_at_pointer(description),
-                        None => ui.add(state_button),
+                        Some(description) => ui.add_enabled(can_write, state_button).on_hover_text_at_pointer(description),
+                        None => ui.add_enabled(can_write, state_button),
                     }.clicked() {
-                        action = TicketAction::UpdateState(Identifier::new(&ticket.adapter, ticket.id));
+                        action = TicketAction::UpdateState(ticket.get_identifier());
                     };
 
                     ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
@@ -488,6 +556,30 @@ impl UserInterface {
                         if let Some(datetime) = datetime {
                             ui.label(format!("Due at {}", datetime.date_naive()));
                         };
+
+                        if let Some((done, total)) = ticket.checklist_progress() {
+                            ui.add_space(half_font);
+                            ui.add(Label::new(format!("☑ {}/{}", done, total)).wrap(false))
+                                .on_hover_text_at_pointer("Finished Items of the Checklist.");
+                        };
+
+                        if ticket.priority != Priority::Normal {
+                            let marker = match ticket.priority {
+                                Priority::Low => RichText::new("⬇ low"),
+                                Priority::High => RichText::new("⬆ high").color(theme.foreground_marker2),
+                                _ => RichText::new("⬆ critical").strong().color(theme.foreground_marker2),
+                            };
+
+                            ui.add_space(half_font);
+                            ui.add(Label::new(marker).wrap(false))
+                                .on_hover_text_at_pointer("Priority of the Ticket.");
+                        };
+
+                        if let Some(recurrence) = &ticket.recurrence {
+                            ui.add_space(half_font);
+                            ui.add(Label::new("🔁").wrap(false))
+                                .on_hover_text_at_pointer(format!("Repeats: {}", recurrence.to_text()));
+                        };
                         
                     });
 
@@ -500,7 +592,7 @@ impl UserInterface {
                         .frame(false))
                         .on_hover_text_at_pointer("Double-click title to edit Ticket.")
                         .double_clicked() {
-                            action = TicketAction::Edit(Identifier::new(ticket.adapter.clone(), ticket.id));
+                            action = TicketAction::Edit(ticket.get_identifier());
                         }; 
                 });
 
@@ -531,12 +623,12 @@ impl UserInterface {
 
                                 tag_button = tag_button.fill(tag_colors[0]);
                                 ui.add(tag_button).on_hover_text_at_pointer(tag).context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Remove from Adapter").color(theme.foreground_marker2)).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new(RichText::new("Remove from Adapter").color(theme.foreground_marker2))).clicked() {
 
                                         let mut tag_ref = Tag::default().with_name(tag.clone());
                                         tag_ref.adapter = ticket.adapter.clone();
@@ -549,17 +641,17 @@ impl UserInterface {
                             None => {
 
                                 let response = ui.button(tag).on_hover_text_at_pointer("Click to add as new Tag");
-                                if response.clicked() {
+                                if response.clicked() && can_change_tags {
                                     action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                 };
 
                                 response.context_menu(|ui| {
-                                    if ui.button(RichText::new("Remove from Ticket").color(theme.foreground_marker2)).clicked() {
-                                        action = TicketAction::RemoveTagImmediate(Identifier::new(ticket.adapter.clone(), ticket.id), tag.clone());
+                                    if ui.add_enabled(can_write, Button::new(RichText::new("Remove from Ticket").color(theme.foreground_marker2))).clicked() {
+                                        action = TicketAction::RemoveTagImmediate(ticket.get_identifier(), tag.clone());
                                         ui.close_menu();
                                     };
 
-                                    if ui.button(RichText::new("Add to Adapter")).clicked() {
+                                    if ui.add_enabled(can_change_tags, Button::new("Add to Adapter")).clicked() {
                                         action = TicketAction::NewTag(Identifier::new(ticket.adapter.clone(), tag.clone()));
                                         ui.close_menu();
                                     };